@@ -15,10 +15,103 @@ use crate::{apis::ResponseContent, models};
 use super::{Error, configuration, ContentType};
 
 
+/// struct for typed errors of method [`api_v2_admin_streams_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2AdminStreamsGetError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_admin_users_user_id_role_put`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2AdminUsersUserIdRolePutError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_sessions_current_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2SessionsCurrentDeleteError {
+    Status403(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_sessions_current_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2SessionsCurrentGetError {
+    Status401(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_sessions_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2SessionsPostError {
+    Status401(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_shared_token_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2SharedTokenGetError {
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_streams_batch_delete_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2StreamsBatchDeletePostError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status422(models::BatchStreamsResponse),
+    Status428(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_streams_batch_get_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2StreamsBatchGetPostError {
+    Status400(models::ValidationError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_streams_batch_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2StreamsBatchPostError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status409(models::ServerError),
+    Status422(models::BatchStreamsResponse),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`api_v2_streams_get`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ApiV2StreamsGetError {
+    Status400(models::ValidationError),
     Status500(models::ServerError),
     UnknownValue(serde_json::Value),
 }
@@ -28,6 +121,9 @@ pub enum ApiV2StreamsGetError {
 #[serde(untagged)]
 pub enum ApiV2StreamsPostError {
     Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status409(models::ServerError),
+    Status422(models::ServerError),
     Status500(models::ServerError),
     UnknownValue(serde_json::Value),
 }
@@ -36,7 +132,11 @@ pub enum ApiV2StreamsPostError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ApiV2StreamsStreamIdDeleteError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
     Status404(),
+    Status412(models::ServerError),
+    Status428(models::ServerError),
     Status500(models::ServerError),
     UnknownValue(serde_json::Value),
 }
@@ -45,23 +145,257 @@ pub enum ApiV2StreamsStreamIdDeleteError {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ApiV2StreamsStreamIdGetError {
+    Status304(),
+    Status400(models::ValidationError),
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_streams_stream_id_patch`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2StreamsStreamIdPatchError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status412(models::ServerError),
+    Status428(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_streams_stream_id_share_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2StreamsStreamIdShareGetError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_streams_stream_id_share_link_id_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2StreamsStreamIdShareLinkIdDeleteError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_streams_stream_id_share_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2StreamsStreamIdSharePostError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersGetError {
+    Status400(models::ValidationError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersPostError {
+    Status400(models::ValidationError),
+    Status409(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_api_keys_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdApiKeysGetError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_api_keys_key_id_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdApiKeysKeyIdDeleteError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_api_keys_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdApiKeysPostError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdDeleteError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_export_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdExportGetError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdGetError {
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_grants_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdGrantsGetError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_grants_grant_id_delete`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdGrantsGrantIdDeleteError {
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_grants_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdGrantsPostError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_import_post`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdImportPostError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status413(),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_patch`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdPatchError {
+    Status400(models::ValidationError),
+    Status401(models::ServerError),
+    Status403(models::ServerError),
+    Status404(),
+    Status409(models::ServerError),
+    Status500(models::ServerError),
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`api_v2_users_user_id_streams_get`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ApiV2UsersUserIdStreamsGetError {
+    Status400(models::ValidationError),
     Status404(),
     Status500(models::ServerError),
     UnknownValue(serde_json::Value),
 }
 
 
-pub async fn api_v2_streams_get(configuration: &configuration::Configuration, category: Option<&str>, limit: Option<i32>, offset: Option<i32>) -> Result<models::StreamListResponse, Error<ApiV2StreamsGetError>> {
+pub async fn api_v2_admin_streams_get(configuration: &configuration::Configuration, category: Option<Vec<String>>, exclude_category: Option<Vec<String>>, visibility: Option<Vec<models::Visibility>>, user_id: Option<&str>, created_from: Option<&str>, created_to: Option<&str>, limit: Option<i32>, offset: Option<i32>, cursor: Option<&str>, sort: Option<&str>, order: Option<&str>, fields: Option<Vec<String>>, include: Option<Vec<String>>) -> Result<models::StreamListResponse, Error<ApiV2AdminStreamsGetError>> {
     // add a prefix to parameters to efficiently prevent name collisions
     let p_query_category = category;
+    let p_query_exclude_category = exclude_category;
+    let p_query_visibility = visibility;
+    let p_query_user_id = user_id;
+    let p_query_created_from = created_from;
+    let p_query_created_to = created_to;
     let p_query_limit = limit;
     let p_query_offset = offset;
+    let p_query_cursor = cursor;
+    let p_query_sort = sort;
+    let p_query_order = order;
+    let p_query_fields = fields;
+    let p_query_include = include;
 
-    let uri_str = format!("{}/api/v2/streams", configuration.base_path);
+    let uri_str = format!("{}/api/v2/admin/streams", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
     if let Some(ref param_value) = p_query_category {
-        req_builder = req_builder.query(&[("category", &param_value.to_string())]);
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("category".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("category", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_exclude_category {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("exclude_category".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("exclude_category", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_visibility {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("visibility".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("visibility", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_user_id {
+        req_builder = req_builder.query(&[("userId", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_created_from {
+        req_builder = req_builder.query(&[("created_from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_created_to {
+        req_builder = req_builder.query(&[("created_to", &param_value.to_string())]);
     }
     if let Some(ref param_value) = p_query_limit {
         req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
@@ -69,9 +403,41 @@ pub async fn api_v2_streams_get(configuration: &configuration::Configuration, ca
     if let Some(ref param_value) = p_query_offset {
         req_builder = req_builder.query(&[("offset", &param_value.to_string())]);
     }
+    if let Some(ref param_value) = p_query_cursor {
+        req_builder = req_builder.query(&[("cursor", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_sort {
+        req_builder = req_builder.query(&[("sort", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_order {
+        req_builder = req_builder.query(&[("order", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_fields {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("fields".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("fields", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_include {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("include".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("include", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -93,22 +459,34 @@ pub async fn api_v2_streams_get(configuration: &configuration::Configuration, ca
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<ApiV2StreamsGetError> = serde_json::from_str(&content).ok();
+        let entity: Option<ApiV2AdminStreamsGetError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent { status, content, entity }))
     }
 }
 
-pub async fn api_v2_streams_post(configuration: &configuration::Configuration, create_stream_request: models::CreateStreamRequest) -> Result<models::Stream, Error<ApiV2StreamsPostError>> {
+pub async fn api_v2_admin_users_user_id_role_put(configuration: &configuration::Configuration, user_id: &str, update_user_role_request: models::UpdateUserRoleRequest) -> Result<models::User, Error<ApiV2AdminUsersUserIdRolePutError>> {
     // add a prefix to parameters to efficiently prevent name collisions
-    let p_body_create_stream_request = create_stream_request;
+    let p_path_user_id = user_id;
+    let p_body_update_user_role_request = update_user_role_request;
 
-    let uri_str = format!("{}/api/v2/streams", configuration.base_path);
-    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+    let uri_str = format!("{}/api/v2/admin/users/{userId}/role", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::PUT, &uri_str);
 
     if let Some(ref user_agent) = configuration.user_agent {
         req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
     }
-    req_builder = req_builder.json(&p_body_create_stream_request);
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_update_user_role_request);
 
     let req = req_builder.build()?;
     let resp = configuration.client.execute(req).await?;
@@ -125,21 +503,20 @@ pub async fn api_v2_streams_post(configuration: &configuration::Configuration, c
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Stream`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Stream`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::User`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::User`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<ApiV2StreamsPostError> = serde_json::from_str(&content).ok();
+        let entity: Option<ApiV2AdminUsersUserIdRolePutError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent { status, content, entity }))
     }
 }
 
-pub async fn api_v2_streams_stream_id_delete(configuration: &configuration::Configuration, stream_id: &str) -> Result<(), Error<ApiV2StreamsStreamIdDeleteError>> {
+pub async fn api_v2_sessions_current_delete(configuration: &configuration::Configuration) -> Result<(), Error<ApiV2SessionsCurrentDeleteError>> {
     // add a prefix to parameters to efficiently prevent name collisions
-    let p_path_stream_id = stream_id;
 
-    let uri_str = format!("{}/api/v2/streams/{streamId}", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id));
+    let uri_str = format!("{}/api/v2/sessions/current", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::DELETE, &uri_str);
 
     if let Some(ref user_agent) = configuration.user_agent {
@@ -155,16 +532,15 @@ pub async fn api_v2_streams_stream_id_delete(configuration: &configuration::Conf
         Ok(())
     } else {
         let content = resp.text().await?;
-        let entity: Option<ApiV2StreamsStreamIdDeleteError> = serde_json::from_str(&content).ok();
+        let entity: Option<ApiV2SessionsCurrentDeleteError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent { status, content, entity }))
     }
 }
 
-pub async fn api_v2_streams_stream_id_get(configuration: &configuration::Configuration, stream_id: &str) -> Result<models::Stream, Error<ApiV2StreamsStreamIdGetError>> {
+pub async fn api_v2_sessions_current_get(configuration: &configuration::Configuration) -> Result<models::Session, Error<ApiV2SessionsCurrentGetError>> {
     // add a prefix to parameters to efficiently prevent name collisions
-    let p_path_stream_id = stream_id;
 
-    let uri_str = format!("{}/api/v2/streams/{streamId}", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id));
+    let uri_str = format!("{}/api/v2/sessions/current", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
     if let Some(ref user_agent) = configuration.user_agent {
@@ -186,12 +562,1383 @@ pub async fn api_v2_streams_stream_id_get(configuration: &configuration::Configu
         let content = resp.text().await?;
         match content_type {
             ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
-            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Stream`"))),
-            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Stream`")))),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Session`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Session`")))),
         }
     } else {
         let content = resp.text().await?;
-        let entity: Option<ApiV2StreamsStreamIdGetError> = serde_json::from_str(&content).ok();
+        let entity: Option<ApiV2SessionsCurrentGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_sessions_post(configuration: &configuration::Configuration) -> Result<models::Session, Error<ApiV2SessionsPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+
+    let uri_str = format!("{}/api/v2/sessions", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Session`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Session`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2SessionsPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_shared_token_get(configuration: &configuration::Configuration, token: &str) -> Result<models::Stream, Error<ApiV2SharedTokenGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_token = token;
+
+    let uri_str = format!("{}/api/v2/shared/{token}", configuration.base_path, token=crate::apis::urlencode(p_path_token));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Stream`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Stream`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2SharedTokenGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+/// mode=atomic（既定）では全件を削除できる場合のみまとめて削除し、1件でも削除できなければ何も削除せずに 422 を返します。
+/// mode=partial では削除できるものだけを削除し、項目ごとの結果を返します。
+/// streamIds の代わりに items で取得時の ETag を指定すると、その後に他のユーザーが更新していた配信は削除せずに項目の status を 412 にします（REQUIRE_IF_MATCH=true の場合は items の etag が必須で、省略した場合は 428 を返します）。
+pub async fn api_v2_streams_batch_delete_post(configuration: &configuration::Configuration, batch_delete_streams_request: models::BatchDeleteStreamsRequest) -> Result<models::BatchStreamsResponse, Error<ApiV2StreamsBatchDeletePostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_batch_delete_streams_request = batch_delete_streams_request;
+
+    let uri_str = format!("{}/api/v2/streams:batchDelete", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_batch_delete_streams_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BatchStreamsResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BatchStreamsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsBatchDeletePostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+/// 見つかった配信をリクエストの streamIds の順に返し、存在しない・削除済み・閲覧できない配信の ID は missingIds に返します。
+/// 同じ ID を複数回指定した場合は1件として扱います。
+pub async fn api_v2_streams_batch_get_post(configuration: &configuration::Configuration, batch_get_streams_request: models::BatchGetStreamsRequest) -> Result<models::BatchGetStreamsResponse, Error<ApiV2StreamsBatchGetPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_batch_get_streams_request = batch_get_streams_request;
+
+    let uri_str = format!("{}/api/v2/streams:batchGet", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_batch_get_streams_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BatchGetStreamsResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BatchGetStreamsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsBatchGetPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+/// mode=atomic（既定）では全件を1つのトランザクションで登録し、1件でも検証エラーがあれば何も登録せずに 422 を返します。
+/// mode=partial では検証を通過したものだけを登録し、項目ごとの結果を返します。
+pub async fn api_v2_streams_batch_post(configuration: &configuration::Configuration, idempotency_key: Option<&str>, batch_create_streams_request: models::BatchCreateStreamsRequest) -> Result<models::BatchStreamsResponse, Error<ApiV2StreamsBatchPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_header_idempotency_key = idempotency_key;
+    let p_body_batch_create_streams_request = batch_create_streams_request;
+
+    let uri_str = format!("{}/api/v2/streams:batch", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(param_value) = p_header_idempotency_key {
+        req_builder = req_builder.header("Idempotency-Key", param_value.to_string());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_batch_create_streams_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::BatchStreamsResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::BatchStreamsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsBatchPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_get(configuration: &configuration::Configuration, category: Option<Vec<String>>, exclude_category: Option<Vec<String>>, visibility: Option<Vec<models::Visibility>>, user_id: Option<&str>, created_from: Option<&str>, created_to: Option<&str>, limit: Option<i32>, offset: Option<i32>, cursor: Option<&str>, sort: Option<&str>, order: Option<&str>, fields: Option<Vec<String>>, include: Option<Vec<String>>) -> Result<models::StreamListResponse, Error<ApiV2StreamsGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_query_category = category;
+    let p_query_exclude_category = exclude_category;
+    let p_query_visibility = visibility;
+    let p_query_user_id = user_id;
+    let p_query_created_from = created_from;
+    let p_query_created_to = created_to;
+    let p_query_limit = limit;
+    let p_query_offset = offset;
+    let p_query_cursor = cursor;
+    let p_query_sort = sort;
+    let p_query_order = order;
+    let p_query_fields = fields;
+    let p_query_include = include;
+
+    let uri_str = format!("{}/api/v2/streams", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_category {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("category".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("category", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_exclude_category {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("exclude_category".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("exclude_category", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_visibility {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("visibility".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("visibility", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_user_id {
+        req_builder = req_builder.query(&[("userId", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_created_from {
+        req_builder = req_builder.query(&[("created_from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_created_to {
+        req_builder = req_builder.query(&[("created_to", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_offset {
+        req_builder = req_builder.query(&[("offset", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_cursor {
+        req_builder = req_builder.query(&[("cursor", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_sort {
+        req_builder = req_builder.query(&[("sort", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_order {
+        req_builder = req_builder.query(&[("order", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_fields {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("fields".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("fields", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_include {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("include".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("include", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::StreamListResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::StreamListResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_post(configuration: &configuration::Configuration, idempotency_key: Option<&str>, create_stream_request: models::CreateStreamRequest) -> Result<models::Stream, Error<ApiV2StreamsPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_header_idempotency_key = idempotency_key;
+    let p_body_create_stream_request = create_stream_request;
+
+    let uri_str = format!("{}/api/v2/streams", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(param_value) = p_header_idempotency_key {
+        req_builder = req_builder.header("Idempotency-Key", param_value.to_string());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_create_stream_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Stream`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Stream`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_stream_id_delete(configuration: &configuration::Configuration, stream_id: &str, if_match: Option<&str>) -> Result<(), Error<ApiV2StreamsStreamIdDeleteError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_stream_id = stream_id;
+    let p_header_if_match = if_match;
+
+    let uri_str = format!("{}/api/v2/streams/{streamId}", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(param_value) = p_header_if_match {
+        req_builder = req_builder.header("If-Match", param_value.to_string());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsStreamIdDeleteError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_stream_id_get(configuration: &configuration::Configuration, stream_id: &str, expand: Option<&str>, if_none_match: Option<&str>) -> Result<models::Stream, Error<ApiV2StreamsStreamIdGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_stream_id = stream_id;
+    let p_query_expand = expand;
+    let p_header_if_none_match = if_none_match;
+
+    let uri_str = format!("{}/api/v2/streams/{streamId}", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_expand {
+        req_builder = req_builder.query(&[("expand", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(param_value) = p_header_if_none_match {
+        req_builder = req_builder.header("If-None-Match", param_value.to_string());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Stream`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Stream`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsStreamIdGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_stream_id_patch(configuration: &configuration::Configuration, stream_id: &str, if_match: Option<&str>, update_stream_request: models::UpdateStreamRequest) -> Result<models::Stream, Error<ApiV2StreamsStreamIdPatchError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_stream_id = stream_id;
+    let p_header_if_match = if_match;
+    let p_body_update_stream_request = update_stream_request;
+
+    let uri_str = format!("{}/api/v2/streams/{streamId}", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::PATCH, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(param_value) = p_header_if_match {
+        req_builder = req_builder.header("If-Match", param_value.to_string());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_update_stream_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Stream`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Stream`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsStreamIdPatchError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_stream_id_share_get(configuration: &configuration::Configuration, stream_id: &str) -> Result<models::ShareLinkListResponse, Error<ApiV2StreamsStreamIdShareGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_stream_id = stream_id;
+
+    let uri_str = format!("{}/api/v2/streams/{streamId}/share", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ShareLinkListResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ShareLinkListResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsStreamIdShareGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_stream_id_share_link_id_delete(configuration: &configuration::Configuration, stream_id: &str, link_id: &str) -> Result<(), Error<ApiV2StreamsStreamIdShareLinkIdDeleteError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_stream_id = stream_id;
+    let p_path_link_id = link_id;
+
+    let uri_str = format!("{}/api/v2/streams/{streamId}/share/{linkId}", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id), linkId=crate::apis::urlencode(p_path_link_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsStreamIdShareLinkIdDeleteError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_streams_stream_id_share_post(configuration: &configuration::Configuration, stream_id: &str, create_share_link_request: Option<models::CreateShareLinkRequest>) -> Result<models::ShareLink, Error<ApiV2StreamsStreamIdSharePostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_stream_id = stream_id;
+    let p_body_create_share_link_request = create_share_link_request;
+
+    let uri_str = format!("{}/api/v2/streams/{streamId}/share", configuration.base_path, streamId=crate::apis::urlencode(p_path_stream_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_create_share_link_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ShareLink`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ShareLink`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2StreamsStreamIdSharePostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_get(configuration: &configuration::Configuration, limit: Option<i32>, offset: Option<i32>) -> Result<models::UserListResponse, Error<ApiV2UsersGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_query_limit = limit;
+    let p_query_offset = offset;
+
+    let uri_str = format!("{}/api/v2/users", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_offset {
+        req_builder = req_builder.query(&[("offset", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::UserListResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::UserListResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_post(configuration: &configuration::Configuration, user_profile_request: Option<models::UserProfileRequest>) -> Result<models::User, Error<ApiV2UsersPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_body_user_profile_request = user_profile_request;
+
+    let uri_str = format!("{}/api/v2/users", configuration.base_path);
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    req_builder = req_builder.json(&p_body_user_profile_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::User`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::User`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_api_keys_get(configuration: &configuration::Configuration, user_id: &str) -> Result<models::ApiKeyListResponse, Error<ApiV2UsersUserIdApiKeysGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/api-keys", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ApiKeyListResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ApiKeyListResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdApiKeysGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_api_keys_key_id_delete(configuration: &configuration::Configuration, user_id: &str, key_id: &str) -> Result<(), Error<ApiV2UsersUserIdApiKeysKeyIdDeleteError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_path_key_id = key_id;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/api-keys/{keyId}", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id), keyId=crate::apis::urlencode(p_path_key_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdApiKeysKeyIdDeleteError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_api_keys_post(configuration: &configuration::Configuration, user_id: &str, issue_api_key_request: Option<models::IssueApiKeyRequest>) -> Result<models::ApiKey, Error<ApiV2UsersUserIdApiKeysPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_body_issue_api_key_request = issue_api_key_request;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/api-keys", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_issue_api_key_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ApiKey`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ApiKey`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdApiKeysPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_delete(configuration: &configuration::Configuration, user_id: &str) -> Result<(), Error<ApiV2UsersUserIdDeleteError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+
+    let uri_str = format!("{}/api/v2/users/{userId}", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdDeleteError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+/// 配信者本人の配信をすべて作成日時の古い順に出力します（下書き・非公開を含みます）。
+/// 件数が多くてもメモリに読み込まずにチャンク転送で返します。各行の項目は ExportedStream を参照してください。
+///
+/// markdown-tar・markdown-zip は 1 件を 1 つの Markdown ファイル（`YYYY/MM/YYYY-MM-DD-{streamId}.md`、日付はユーザーのタイムゾーンでの作成日）にまとめたアーカイブです。
+/// YAML のフロントマター（stream_id・title・category・visibility・created_at・削除済みの場合は deleted_at）の後に概要欄を続けます。
+/// ファイルの順序と更新日時（作成日時）が一定のため、同じ内容からは常に同じアーカイブができます。
+/// markdown-zip は全件をまとめてから返します。
+pub async fn api_v2_users_user_id_export_get(configuration: &configuration::Configuration, user_id: &str, format: Option<&str>, include_deleted: Option<bool>) -> Result<reqwest::Response, Error<ApiV2UsersUserIdExportGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_query_format = format;
+    let p_query_include_deleted = include_deleted;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/export", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_format {
+        req_builder = req_builder.query(&[("format", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_include_deleted {
+        req_builder = req_builder.query(&[("include_deleted", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(resp)
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdExportGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_get(configuration: &configuration::Configuration, user_id: &str) -> Result<models::User, Error<ApiV2UsersUserIdGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+
+    let uri_str = format!("{}/api/v2/users/{userId}", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::User`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::User`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_grants_get(configuration: &configuration::Configuration, user_id: &str) -> Result<models::GrantListResponse, Error<ApiV2UsersUserIdGrantsGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/grants", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::GrantListResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::GrantListResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdGrantsGetError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_grants_grant_id_delete(configuration: &configuration::Configuration, user_id: &str, grant_id: &str) -> Result<(), Error<ApiV2UsersUserIdGrantsGrantIdDeleteError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_path_grant_id = grant_id;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/grants/{grantId}", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id), grantId=crate::apis::urlencode(p_path_grant_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::DELETE, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+
+    if !status.is_client_error() && !status.is_server_error() {
+        Ok(())
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdGrantsGrantIdDeleteError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_grants_post(configuration: &configuration::Configuration, user_id: &str, grant_request: models::GrantRequest) -> Result<models::Grant, Error<ApiV2UsersUserIdGrantsPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_body_grant_request = grant_request;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/grants", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_grant_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::Grant`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::Grant`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdGrantsPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+/// JSON Lines または CSV の各行を配信として登録します（行の項目は ImportStreamRow を参照してください）。
+/// 各行は配信情報の登録と同じルールで検証し、不正な行を除いた行を 1 つのトランザクションで登録します。
+/// タイトル・概要欄・カテゴリ、または youtubeVideoId が登録済みの配信やファイル内の前の行と同じ行は重複として登録しません。
+pub async fn api_v2_users_user_id_import_post(configuration: &configuration::Configuration, user_id: &str, format: Option<&str>, dry_run: Option<bool>, body: String) -> Result<models::ImportStreamsResponse, Error<ApiV2UsersUserIdImportPostError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_query_format = format;
+    let p_query_dry_run = dry_run;
+    let p_body_body = body;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/import", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::POST, &uri_str);
+
+    if let Some(ref param_value) = p_query_format {
+        req_builder = req_builder.query(&[("format", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_dry_run {
+        req_builder = req_builder.query(&[("dry_run", &param_value.to_string())]);
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_body);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::ImportStreamsResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::ImportStreamsResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdImportPostError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_patch(configuration: &configuration::Configuration, user_id: &str, user_profile_request: models::UserProfileRequest) -> Result<models::User, Error<ApiV2UsersUserIdPatchError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_body_user_profile_request = user_profile_request;
+
+    let uri_str = format!("{}/api/v2/users/{userId}", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::PATCH, &uri_str);
+
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+    req_builder = req_builder.json(&p_body_user_profile_request);
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::User`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::User`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdPatchError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent { status, content, entity }))
+    }
+}
+
+pub async fn api_v2_users_user_id_streams_get(configuration: &configuration::Configuration, user_id: &str, category: Option<Vec<String>>, exclude_category: Option<Vec<String>>, visibility: Option<Vec<models::Visibility>>, created_from: Option<&str>, created_to: Option<&str>, limit: Option<i32>, offset: Option<i32>, cursor: Option<&str>, sort: Option<&str>, order: Option<&str>, fields: Option<Vec<String>>, include: Option<Vec<String>>) -> Result<models::StreamListResponse, Error<ApiV2UsersUserIdStreamsGetError>> {
+    // add a prefix to parameters to efficiently prevent name collisions
+    let p_path_user_id = user_id;
+    let p_query_category = category;
+    let p_query_exclude_category = exclude_category;
+    let p_query_visibility = visibility;
+    let p_query_created_from = created_from;
+    let p_query_created_to = created_to;
+    let p_query_limit = limit;
+    let p_query_offset = offset;
+    let p_query_cursor = cursor;
+    let p_query_sort = sort;
+    let p_query_order = order;
+    let p_query_fields = fields;
+    let p_query_include = include;
+
+    let uri_str = format!("{}/api/v2/users/{userId}/streams", configuration.base_path, userId=crate::apis::urlencode(p_path_user_id));
+    let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
+
+    if let Some(ref param_value) = p_query_category {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("category".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("category", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_exclude_category {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("exclude_category".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("exclude_category", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_visibility {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("visibility".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("visibility", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_created_from {
+        req_builder = req_builder.query(&[("created_from", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_created_to {
+        req_builder = req_builder.query(&[("created_to", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_limit {
+        req_builder = req_builder.query(&[("limit", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_offset {
+        req_builder = req_builder.query(&[("offset", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_cursor {
+        req_builder = req_builder.query(&[("cursor", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_sort {
+        req_builder = req_builder.query(&[("sort", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_order {
+        req_builder = req_builder.query(&[("order", &param_value.to_string())]);
+    }
+    if let Some(ref param_value) = p_query_fields {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("fields".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("fields", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref param_value) = p_query_include {
+        req_builder = match "multi" {
+            "multi" => req_builder.query(&param_value.into_iter().map(|p| ("include".to_owned(), p.to_string())).collect::<Vec<(std::string::String, std::string::String)>>()),
+            _ => req_builder.query(&[("include", &param_value.into_iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").to_string())]),
+        };
+    }
+    if let Some(ref user_agent) = configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(ref apikey) = configuration.api_key {
+        let key = apikey.key.clone();
+        let value = match apikey.prefix {
+            Some(ref prefix) => format!("{} {}", prefix, key),
+            None => key,
+        };
+        req_builder = req_builder.header("X-API-Key", value);
+    };
+    if let Some(ref token) = configuration.bearer_access_token {
+        req_builder = req_builder.bearer_auth(token.to_owned());
+    };
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get("content-type")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Json => serde_json::from_str(&content).map_err(Error::from),
+            ContentType::Text => return Err(Error::from(serde_json::Error::custom("Received `text/plain` content type response that cannot be converted to `models::StreamListResponse`"))),
+            ContentType::Unsupported(unknown_type) => return Err(Error::from(serde_json::Error::custom(format!("Received `{unknown_type}` content type response that cannot be converted to `models::StreamListResponse`")))),
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ApiV2UsersUserIdStreamsGetError> = serde_json::from_str(&content).ok();
         Err(Error::ResponseError(ResponseContent { status, content, entity }))
     }
 }
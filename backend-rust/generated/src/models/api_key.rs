@@ -0,0 +1,40 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiKey {
+    /// APIキーID
+    #[serde(rename = "keyId", skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    /// キーの名前
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// APIキー（`X-API-Key` ヘッダーに指定する値。発行時のみ返却されます）
+    #[serde(rename = "apiKey", skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// 発行日時
+    #[serde(rename = "createdAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+impl ApiKey {
+    pub fn new() -> ApiKey {
+        ApiKey {
+            key_id: None,
+            name: None,
+            api_key: None,
+            created_at: None,
+        }
+    }
+}
+
@@ -0,0 +1,27 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ApiKeyListResponse {
+    #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<models::ApiKey>>,
+}
+
+impl ApiKeyListResponse {
+    pub fn new() -> ApiKeyListResponse {
+        ApiKeyListResponse {
+            items: None,
+        }
+    }
+}
+
@@ -0,0 +1,30 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchCreateStreamsRequest {
+    #[serde(rename = "items")]
+    pub items: Vec<models::CreateStreamRequest>,
+    #[serde(rename = "mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<models::BatchMode>,
+}
+
+impl BatchCreateStreamsRequest {
+    pub fn new(items: Vec<models::CreateStreamRequest>) -> BatchCreateStreamsRequest {
+        BatchCreateStreamsRequest {
+            items,
+            mode: None,
+        }
+    }
+}
+
@@ -0,0 +1,31 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchDeleteItem {
+    #[serde(rename = "streamId")]
+    pub stream_id: String,
+    /// 取得時の ETag。他のユーザーが先に更新していた場合は削除しません（REQUIRE_IF_MATCH=true の場合は必須）
+    #[serde(rename = "etag", skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+}
+
+impl BatchDeleteItem {
+    pub fn new(stream_id: String) -> BatchDeleteItem {
+        BatchDeleteItem {
+            stream_id,
+            etag: None,
+        }
+    }
+}
+
@@ -0,0 +1,35 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// BatchDeleteStreamsRequest : streamIds と items のどちらか一方を指定します
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchDeleteStreamsRequest {
+    #[serde(rename = "streamIds", skip_serializing_if = "Option::is_none")]
+    pub stream_ids: Option<Vec<String>>,
+    #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<models::BatchDeleteItem>>,
+    #[serde(rename = "mode", skip_serializing_if = "Option::is_none")]
+    pub mode: Option<models::BatchMode>,
+}
+
+impl BatchDeleteStreamsRequest {
+    /// streamIds と items のどちらか一方を指定します
+    pub fn new() -> BatchDeleteStreamsRequest {
+        BatchDeleteStreamsRequest {
+            stream_ids: None,
+            items: None,
+            mode: None,
+        }
+    }
+}
+
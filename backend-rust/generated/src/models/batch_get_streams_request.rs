@@ -0,0 +1,27 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchGetStreamsRequest {
+    #[serde(rename = "streamIds")]
+    pub stream_ids: Vec<String>,
+}
+
+impl BatchGetStreamsRequest {
+    pub fn new(stream_ids: Vec<String>) -> BatchGetStreamsRequest {
+        BatchGetStreamsRequest {
+            stream_ids,
+        }
+    }
+}
+
@@ -0,0 +1,32 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchGetStreamsResponse {
+    /// 見つかった配信（リクエストの順）
+    #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<models::Stream>>,
+    /// 見つからなかった配信のID（リクエストの順）
+    #[serde(rename = "missingIds", skip_serializing_if = "Option::is_none")]
+    pub missing_ids: Option<Vec<String>>,
+}
+
+impl BatchGetStreamsResponse {
+    pub fn new() -> BatchGetStreamsResponse {
+        BatchGetStreamsResponse {
+            items: None,
+            missing_ids: None,
+        }
+    }
+}
+
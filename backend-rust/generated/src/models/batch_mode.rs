@@ -0,0 +1,39 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// BatchMode : 一括処理の方式（atomic は全件成功した場合のみ反映、partial は成功したものだけを反映。既定値は atomic）
+/// 一括処理の方式（atomic は全件成功した場合のみ反映、partial は成功したものだけを反映。既定値は atomic）
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum BatchMode {
+    #[serde(rename = "atomic")]
+    Atomic,
+    #[serde(rename = "partial")]
+    Partial,
+
+}
+
+impl std::fmt::Display for BatchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Atomic => write!(f, "atomic"),
+            Self::Partial => write!(f, "partial"),
+        }
+    }
+}
+
+impl Default for BatchMode {
+    fn default() -> BatchMode {
+        Self::Atomic
+    }
+}
+
@@ -0,0 +1,42 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchStreamResult {
+    /// リクエストの items / streamIds における位置（0始まり）
+    #[serde(rename = "index", skip_serializing_if = "Option::is_none")]
+    pub index: Option<i32>,
+    /// 項目ごとの結果を表すステータスコード（201=登録、204=削除、4xx=失敗、424=他の項目の失敗により反映しなかった）
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<i32>,
+    /// 登録・削除した配信のID
+    #[serde(rename = "streamId", skip_serializing_if = "Option::is_none")]
+    pub stream_id: Option<String>,
+    #[serde(rename = "stream", skip_serializing_if = "Option::is_none")]
+    pub stream: Option<Box<models::Stream>>,
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<Box<models::ServerError>>,
+}
+
+impl BatchStreamResult {
+    pub fn new() -> BatchStreamResult {
+        BatchStreamResult {
+            index: None,
+            status: None,
+            stream_id: None,
+            stream: None,
+            error: None,
+        }
+    }
+}
+
@@ -0,0 +1,35 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchStreamsResponse {
+    /// 反映した件数
+    #[serde(rename = "succeeded", skip_serializing_if = "Option::is_none")]
+    pub succeeded: Option<i32>,
+    /// 失敗した件数（他の項目の失敗により反映しなかったものは含まない）
+    #[serde(rename = "failed", skip_serializing_if = "Option::is_none")]
+    pub failed: Option<i32>,
+    #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<models::BatchStreamResult>>,
+}
+
+impl BatchStreamsResponse {
+    pub fn new() -> BatchStreamsResponse {
+        BatchStreamsResponse {
+            succeeded: None,
+            failed: None,
+            items: None,
+        }
+    }
+}
+
@@ -0,0 +1,28 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CreateShareLinkRequest {
+    /// 有効期限（RFC 3339 形式、省略した場合は取り消すまで有効）
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl CreateShareLinkRequest {
+    pub fn new() -> CreateShareLinkRequest {
+        CreateShareLinkRequest {
+            expires_at: None,
+        }
+    }
+}
+
@@ -13,10 +13,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CreateStreamRequest {
-    /// ユーザーID
-    #[serde(rename = "userId")]
-    pub user_id: String,
-    /// 配信タイトル
+    /// 配信タイトル（下書きの場合は空文字も可）
     #[serde(rename = "title")]
     pub title: String,
     /// 概要欄の内容
@@ -25,15 +22,21 @@ pub struct CreateStreamRequest {
     /// 配信カテゴリ（任意）
     #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
+    #[serde(rename = "visibility", skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<models::Visibility>,
+    /// 非公開にする場合は true（visibility を使用してください。true は visibility=private と同じで、visibility とは同時に指定できません）
+    #[serde(rename = "isPrivate", skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
 }
 
 impl CreateStreamRequest {
-    pub fn new(user_id: String, title: String, description: String) -> CreateStreamRequest {
+    pub fn new(title: String, description: String) -> CreateStreamRequest {
         CreateStreamRequest {
-            user_id,
             title,
             description,
             category: None,
+            visibility: None,
+            is_private: None,
         }
     }
 }
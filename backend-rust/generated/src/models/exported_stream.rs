@@ -0,0 +1,57 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ExportedStream : エクスポートの 1 件分（CSV の列も同じ順序・名前です）
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ExportedStream {
+    /// 配信ID
+    #[serde(rename = "streamId")]
+    pub stream_id: String,
+    /// ユーザーID
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    /// 配信タイトル
+    #[serde(rename = "title")]
+    pub title: String,
+    /// 概要欄の内容（改行を含む場合があります）
+    #[serde(rename = "description")]
+    pub description: String,
+    /// 配信カテゴリ
+    #[serde(rename = "category")]
+    pub category: String,
+    #[serde(rename = "visibility")]
+    pub visibility: models::Visibility,
+    /// 作成日時
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    /// 削除日時（include_deleted を指定した場合に削除済みの配信のみ含まれます。CSV では空欄）
+    #[serde(rename = "deletedAt", skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+}
+
+impl ExportedStream {
+    /// エクスポートの 1 件分（CSV の列も同じ順序・名前です）
+    pub fn new(stream_id: String, user_id: String, title: String, description: String, category: String, visibility: models::Visibility, created_at: String) -> ExportedStream {
+        ExportedStream {
+            stream_id,
+            user_id,
+            title,
+            description,
+            category,
+            visibility,
+            created_at,
+            deleted_at: None,
+        }
+    }
+}
+
@@ -0,0 +1,64 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Grant {
+    /// 委任ID
+    #[serde(rename = "grantId", skip_serializing_if = "Option::is_none")]
+    pub grant_id: Option<String>,
+    /// 委任した配信者のユーザーID
+    #[serde(rename = "ownerId", skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    /// 委任先のユーザーID
+    #[serde(rename = "granteeId", skip_serializing_if = "Option::is_none")]
+    pub grantee_id: Option<String>,
+    /// 委任した操作の範囲
+    #[serde(rename = "scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Scope>,
+    /// 委任日時
+    #[serde(rename = "createdAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// 有効期限（取り消すまで有効な場合は含まれません）
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl Grant {
+    pub fn new() -> Grant {
+        Grant {
+            grant_id: None,
+            owner_id: None,
+            grantee_id: None,
+            scope: None,
+            created_at: None,
+            expires_at: None,
+        }
+    }
+}
+/// 委任した操作の範囲
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Scope {
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "write")]
+    Write,
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+impl Default for Scope {
+    fn default() -> Scope {
+        Self::Read
+    }
+}
+
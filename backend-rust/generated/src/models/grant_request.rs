@@ -0,0 +1,52 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GrantRequest {
+    /// 委任先のユーザーID
+    #[serde(rename = "granteeId")]
+    pub grantee_id: String,
+    /// 委任する操作の範囲（read は非公開の配信の閲覧、write は閲覧と更新、delete は閲覧・更新・削除）
+    #[serde(rename = "scope")]
+    pub scope: Scope,
+    /// 有効期限（RFC 3339 形式、省略した場合は取り消すまで有効）
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl GrantRequest {
+    pub fn new(grantee_id: String, scope: Scope) -> GrantRequest {
+        GrantRequest {
+            grantee_id,
+            scope,
+            expires_at: None,
+        }
+    }
+}
+/// 委任する操作の範囲（read は非公開の配信の閲覧、write は閲覧と更新、delete は閲覧・更新・削除）
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Scope {
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "write")]
+    Write,
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+impl Default for Scope {
+    fn default() -> Scope {
+        Self::Read
+    }
+}
+
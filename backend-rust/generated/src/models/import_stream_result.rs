@@ -0,0 +1,42 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImportStreamResult {
+    /// ファイル内の行番号（1始まり。CSV はヘッダー行を含めた行番号）
+    #[serde(rename = "line", skip_serializing_if = "Option::is_none")]
+    pub line: Option<i32>,
+    /// 行ごとの結果を表すステータスコード（201=登録、409=重複のため登録しなかった、400=不正な行）
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<i32>,
+    /// 登録した配信のID（重複の場合は同じ内容の配信のID）
+    #[serde(rename = "streamId", skip_serializing_if = "Option::is_none")]
+    pub stream_id: Option<String>,
+    #[serde(rename = "stream", skip_serializing_if = "Option::is_none")]
+    pub stream: Option<Box<models::Stream>>,
+    #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
+    pub error: Option<Box<models::ServerError>>,
+}
+
+impl ImportStreamResult {
+    pub fn new() -> ImportStreamResult {
+        ImportStreamResult {
+            line: None,
+            status: None,
+            stream_id: None,
+            stream: None,
+            error: None,
+        }
+    }
+}
+
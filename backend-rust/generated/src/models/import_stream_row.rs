@@ -0,0 +1,53 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// ImportStreamRow : インポートの 1 行分（エクスポートした ExportedStream をそのまま指定できます。streamId と userId は無視します）
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImportStreamRow {
+    /// 配信タイトル（下書きの場合は空文字も可）
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 概要欄の内容
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// 配信カテゴリ
+    #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(rename = "visibility", skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<models::Visibility>,
+    /// 作成日時（省略した場合は取り込んだ日時）
+    #[serde(rename = "createdAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// 削除日時（指定した行は削除済みの配信として取り込まずにエラーとします）
+    #[serde(rename = "deletedAt", skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    /// 元になった YouTube の動画ID（指定した場合は同じ動画の配信が登録済みの行も重複として扱います）
+    #[serde(rename = "youtubeVideoId", skip_serializing_if = "Option::is_none")]
+    pub youtube_video_id: Option<String>,
+}
+
+impl ImportStreamRow {
+    /// インポートの 1 行分（エクスポートした ExportedStream をそのまま指定できます。streamId と userId は無視します）
+    pub fn new() -> ImportStreamRow {
+        ImportStreamRow {
+            title: None,
+            description: None,
+            category: None,
+            visibility: None,
+            created_at: None,
+            deleted_at: None,
+            youtube_video_id: None,
+        }
+    }
+}
+
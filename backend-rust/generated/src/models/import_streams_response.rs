@@ -0,0 +1,43 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImportStreamsResponse {
+    /// 登録せずに結果だけを返した場合は true
+    #[serde(rename = "dryRun", skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// 登録した（dry_run の場合は登録する予定の）件数
+    #[serde(rename = "created", skip_serializing_if = "Option::is_none")]
+    pub created: Option<i32>,
+    /// 重複のため登録しなかった件数
+    #[serde(rename = "duplicates", skip_serializing_if = "Option::is_none")]
+    pub duplicates: Option<i32>,
+    /// 不正な行の件数
+    #[serde(rename = "failed", skip_serializing_if = "Option::is_none")]
+    pub failed: Option<i32>,
+    #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<models::ImportStreamResult>>,
+}
+
+impl ImportStreamsResponse {
+    pub fn new() -> ImportStreamsResponse {
+        ImportStreamsResponse {
+            dry_run: None,
+            created: None,
+            duplicates: None,
+            failed: None,
+            items: None,
+        }
+    }
+}
+
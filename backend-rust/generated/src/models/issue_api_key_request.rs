@@ -0,0 +1,28 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IssueApiKeyRequest {
+    /// キーの用途を識別するための名前
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl IssueApiKeyRequest {
+    pub fn new() -> IssueApiKeyRequest {
+        IssueApiKeyRequest {
+            name: None,
+        }
+    }
+}
+
@@ -1,14 +1,72 @@
+pub mod api_key;
+pub use self::api_key::ApiKey;
+pub mod api_key_list_response;
+pub use self::api_key_list_response::ApiKeyListResponse;
+pub mod batch_create_streams_request;
+pub use self::batch_create_streams_request::BatchCreateStreamsRequest;
+pub mod batch_delete_item;
+pub use self::batch_delete_item::BatchDeleteItem;
+pub mod batch_delete_streams_request;
+pub use self::batch_delete_streams_request::BatchDeleteStreamsRequest;
+pub mod batch_get_streams_request;
+pub use self::batch_get_streams_request::BatchGetStreamsRequest;
+pub mod batch_get_streams_response;
+pub use self::batch_get_streams_response::BatchGetStreamsResponse;
+pub mod batch_mode;
+pub use self::batch_mode::BatchMode;
+pub mod batch_stream_result;
+pub use self::batch_stream_result::BatchStreamResult;
+pub mod batch_streams_response;
+pub use self::batch_streams_response::BatchStreamsResponse;
+pub mod create_share_link_request;
+pub use self::create_share_link_request::CreateShareLinkRequest;
 pub mod create_stream_request;
 pub use self::create_stream_request::CreateStreamRequest;
+pub mod exported_stream;
+pub use self::exported_stream::ExportedStream;
+pub mod grant;
+pub use self::grant::Grant;
+pub mod grant_list_response;
+pub use self::grant_list_response::GrantListResponse;
+pub mod grant_request;
+pub use self::grant_request::GrantRequest;
+pub mod import_stream_result;
+pub use self::import_stream_result::ImportStreamResult;
+pub mod import_stream_row;
+pub use self::import_stream_row::ImportStreamRow;
+pub mod import_streams_response;
+pub use self::import_streams_response::ImportStreamsResponse;
+pub mod issue_api_key_request;
+pub use self::issue_api_key_request::IssueApiKeyRequest;
+pub mod pagination_links;
+pub use self::pagination_links::PaginationLinks;
 pub mod server_error;
 pub use self::server_error::ServerError;
+pub mod session;
+pub use self::session::Session;
+pub mod share_link;
+pub use self::share_link::ShareLink;
+pub mod share_link_list_response;
+pub use self::share_link_list_response::ShareLinkListResponse;
 pub mod stream;
 pub use self::stream::Stream;
 pub mod stream_list_response;
 pub use self::stream_list_response::StreamListResponse;
 pub mod stream_summary;
 pub use self::stream_summary::StreamSummary;
+pub mod update_stream_request;
+pub use self::update_stream_request::UpdateStreamRequest;
+pub mod update_user_role_request;
+pub use self::update_user_role_request::UpdateUserRoleRequest;
+pub mod user;
+pub use self::user::User;
+pub mod user_list_response;
+pub use self::user_list_response::UserListResponse;
+pub mod user_profile_request;
+pub use self::user_profile_request::UserProfileRequest;
 pub mod validation_error;
 pub use self::validation_error::ValidationError;
 pub mod validation_error_details_inner;
 pub use self::validation_error_details_inner::ValidationErrorDetailsInner;
+pub mod visibility;
+pub use self::visibility::Visibility;
@@ -0,0 +1,42 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// PaginationLinks : ページ移動用のURL（絞り込み条件は引き継がれます）
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaginationLinks {
+    /// 先頭ページのURL
+    #[serde(rename = "first", skip_serializing_if = "Option::is_none")]
+    pub first: Option<String>,
+    /// 前のページのURL（存在しない場合は省略）
+    #[serde(rename = "prev", skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+    /// 次のページのURL（存在しない場合は省略）
+    #[serde(rename = "next", skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    /// 最終ページのURL
+    #[serde(rename = "last", skip_serializing_if = "Option::is_none")]
+    pub last: Option<String>,
+}
+
+impl PaginationLinks {
+    /// ページ移動用のURL（絞り込み条件は引き継がれます）
+    pub fn new() -> PaginationLinks {
+        PaginationLinks {
+            first: None,
+            prev: None,
+            next: None,
+            last: None,
+        }
+    }
+}
+
@@ -13,7 +13,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServerError {
-    /// エラーコード
+    /// エラーコード（`unauthorized`=401, `forbidden`=403, `not_found`=404, `conflict`=409, `internal_server_error`=500）
     #[serde(rename = "error", skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     /// エラーメッセージ
@@ -0,0 +1,36 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    /// ログイン中のユーザーID
+    #[serde(rename = "userId", skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// 更新系のリクエストでX-CSRF-Tokenヘッダーに指定するトークン
+    #[serde(rename = "csrfToken", skip_serializing_if = "Option::is_none")]
+    pub csrf_token: Option<String>,
+    /// セッションの有効期限
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl Session {
+    pub fn new() -> Session {
+        Session {
+            user_id: None,
+            csrf_token: None,
+            expires_at: None,
+        }
+    }
+}
+
@@ -0,0 +1,52 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShareLink {
+    /// 共有リンクID
+    #[serde(rename = "linkId", skip_serializing_if = "Option::is_none")]
+    pub link_id: Option<String>,
+    /// 配信ID
+    #[serde(rename = "streamId", skip_serializing_if = "Option::is_none")]
+    pub stream_id: Option<String>,
+    /// 共有リンクのトークン（`GET /api/v2/shared/{token}` に指定する値。発行時のみ返却されます）
+    #[serde(rename = "token", skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// 発行日時
+    #[serde(rename = "createdAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// 有効期限（取り消すまで有効な場合は含まれません）
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// 共有リンクから取得された回数
+    #[serde(rename = "accessCount", skip_serializing_if = "Option::is_none")]
+    pub access_count: Option<i64>,
+    /// 最後に取得された日時（未取得の場合は含まれません）
+    #[serde(rename = "lastAccessedAt", skip_serializing_if = "Option::is_none")]
+    pub last_accessed_at: Option<String>,
+}
+
+impl ShareLink {
+    pub fn new() -> ShareLink {
+        ShareLink {
+            link_id: None,
+            stream_id: None,
+            token: None,
+            created_at: None,
+            expires_at: None,
+            access_count: None,
+            last_accessed_at: None,
+        }
+    }
+}
+
@@ -31,6 +31,13 @@ pub struct Stream {
     /// 作成日時
     #[serde(rename = "createdAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
+    #[serde(rename = "visibility", skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<models::Visibility>,
+    /// 配信者本人など一部のユーザーのみ閲覧できる配信（visibility が draft または private）かどうか
+    #[serde(rename = "isPrivate", skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    #[serde(rename = "user", skip_serializing_if = "Option::is_none")]
+    pub user: Option<Box<models::User>>,
 }
 
 impl Stream {
@@ -42,6 +49,9 @@ impl Stream {
             description: None,
             category: None,
             created_at: None,
+            visibility: None,
+            is_private: None,
+            user: None,
         }
     }
 }
@@ -22,6 +22,23 @@ pub struct StreamListResponse {
     /// リクエストされた取得開始位置
     #[serde(rename = "offset", skip_serializing_if = "Option::is_none")]
     pub offset: Option<i32>,
+    /// 続きのデータが存在するかどうか
+    #[serde(rename = "hasMore", skip_serializing_if = "Option::is_none")]
+    pub has_more: Option<bool>,
+    /// 続きを取得するためのカーソル（hasMore が false の場合、または sort が created_at 以外の場合は省略）
+    #[serde(rename = "nextCursor", skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    /// 次のページが存在するかどうか
+    #[serde(rename = "hasNext", skip_serializing_if = "Option::is_none")]
+    pub has_next: Option<bool>,
+    /// 前のページが存在するかどうか
+    #[serde(rename = "hasPrev", skip_serializing_if = "Option::is_none")]
+    pub has_prev: Option<bool>,
+    /// 次のページの取得開始位置（offset 指定時かつ hasNext が true の場合のみ）
+    #[serde(rename = "nextOffset", skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<i32>,
+    #[serde(rename = "links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<Box<models::PaginationLinks>>,
     #[serde(rename = "items", skip_serializing_if = "Option::is_none")]
     pub items: Option<Vec<models::StreamSummary>>,
 }
@@ -32,6 +49,12 @@ impl StreamListResponse {
             total: None,
             limit: None,
             offset: None,
+            has_more: None,
+            next_cursor: None,
+            has_next: None,
+            has_prev: None,
+            next_offset: None,
+            links: None,
             items: None,
         }
     }
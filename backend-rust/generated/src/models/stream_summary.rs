@@ -22,12 +22,23 @@ pub struct StreamSummary {
     /// 配信タイトル
     #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// 概要欄の内容（fields または include で指定した場合のみ含まれます）
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /// 配信カテゴリ
     #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
     /// 作成日時
     #[serde(rename = "createdAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
+    #[serde(rename = "visibility", skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<models::Visibility>,
+    /// 配信者本人など一部のユーザーのみ閲覧できる配信（visibility が draft または private）かどうか
+    #[serde(rename = "isPrivate", skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+    /// 削除日時（管理者向けの一覧で削除済みの配信のみ含まれます）
+    #[serde(rename = "deletedAt", skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
 }
 
 impl StreamSummary {
@@ -36,8 +47,12 @@ impl StreamSummary {
             user_id: None,
             stream_id: None,
             title: None,
+            description: None,
             category: None,
             created_at: None,
+            visibility: None,
+            is_private: None,
+            deleted_at: None,
         }
     }
 }
@@ -0,0 +1,43 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateStreamRequest {
+    /// 配信タイトル（更新後の公開状態が下書き以外の場合は必須）
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 概要欄の内容
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// 配信カテゴリ
+    #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(rename = "visibility", skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<models::Visibility>,
+    /// 非公開にする場合は true（visibility を使用してください。true は visibility=private、false は visibility=published と同じで、visibility とは同時に指定できません）
+    #[serde(rename = "isPrivate", skip_serializing_if = "Option::is_none")]
+    pub is_private: Option<bool>,
+}
+
+impl UpdateStreamRequest {
+    pub fn new() -> UpdateStreamRequest {
+        UpdateStreamRequest {
+            title: None,
+            description: None,
+            category: None,
+            visibility: None,
+            is_private: None,
+        }
+    }
+}
+
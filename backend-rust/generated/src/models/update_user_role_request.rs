@@ -0,0 +1,44 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UpdateUserRoleRequest {
+    /// 変更後の役割
+    #[serde(rename = "role")]
+    pub role: Role,
+}
+
+impl UpdateUserRoleRequest {
+    pub fn new(role: Role) -> UpdateUserRoleRequest {
+        UpdateUserRoleRequest {
+            role,
+        }
+    }
+}
+/// 変更後の役割
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Role {
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(rename = "moderator")]
+    Moderator,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl Default for Role {
+    fn default() -> Role {
+        Self::Owner
+    }
+}
+
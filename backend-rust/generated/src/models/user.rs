@@ -0,0 +1,72 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    /// ユーザーID
+    #[serde(rename = "userId", skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// 表示名
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// YouTube のチャンネルハンドル（未設定の場合は省略されます）
+    #[serde(rename = "channelHandle", skip_serializing_if = "Option::is_none")]
+    pub channel_handle: Option<String>,
+    /// タイムゾーン（IANA タイムゾーン名）
+    #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    /// ロケール（BCP 47 の言語タグ）
+    #[serde(rename = "locale", skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// 役割（owner は配信者、moderator は全ユーザーの配信を閲覧・編集できるサポート担当、admin は管理者）
+    #[serde(rename = "role", skip_serializing_if = "Option::is_none")]
+    pub role: Option<Role>,
+    /// 登録日時
+    #[serde(rename = "createdAt", skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    /// 初回のAPIキー（ユーザー登録時のみ返却されます）
+    #[serde(rename = "apiKey", skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+}
+
+impl User {
+    pub fn new() -> User {
+        User {
+            user_id: None,
+            display_name: None,
+            channel_handle: None,
+            time_zone: None,
+            locale: None,
+            role: None,
+            created_at: None,
+            api_key: None,
+        }
+    }
+}
+/// 役割（owner は配信者、moderator は全ユーザーの配信を閲覧・編集できるサポート担当、admin は管理者）
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Role {
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(rename = "moderator")]
+    Moderator,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl Default for Role {
+    fn default() -> Role {
+        Self::Owner
+    }
+}
+
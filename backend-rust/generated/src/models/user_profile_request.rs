@@ -0,0 +1,40 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserProfileRequest {
+    /// 表示名
+    #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// YouTube のチャンネルハンドル（`@` から始まる3〜30文字の英数字・`_`・`-`・`.`）。空文字を指定すると解除されます
+    #[serde(rename = "channelHandle", skip_serializing_if = "Option::is_none")]
+    pub channel_handle: Option<String>,
+    /// タイムゾーン（IANA タイムゾーン名）
+    #[serde(rename = "timeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    /// ロケール（BCP 47 の言語タグ）
+    #[serde(rename = "locale", skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+}
+
+impl UserProfileRequest {
+    pub fn new() -> UserProfileRequest {
+        UserProfileRequest {
+            display_name: None,
+            channel_handle: None,
+            time_zone: None,
+            locale: None,
+        }
+    }
+}
+
@@ -0,0 +1,45 @@
+/*
+ * WEB 100本ノック
+ *
+ * No description provided (generated by Openapi Generator https://github.com/openapitools/openapi-generator)
+ *
+ * The version of the OpenAPI document: 2.0.0
+ * 
+ * Generated by: https://openapi-generator.tech
+ */
+
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// Visibility : 公開状態（draft は下書き、private は配信者本人など一部のユーザーのみ閲覧可、unlisted は一覧には表示せず URL を知っていれば閲覧可、published は公開。登録時の既定値は published）
+/// 公開状態（draft は下書き、private は配信者本人など一部のユーザーのみ閲覧可、unlisted は一覧には表示せず URL を知っていれば閲覧可、published は公開。登録時の既定値は published）
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Visibility {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "unlisted")]
+    Unlisted,
+    #[serde(rename = "published")]
+    Published,
+
+}
+
+impl std::fmt::Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Draft => write!(f, "draft"),
+            Self::Private => write!(f, "private"),
+            Self::Unlisted => write!(f, "unlisted"),
+            Self::Published => write!(f, "published"),
+        }
+    }
+}
+
+impl Default for Visibility {
+    fn default() -> Visibility {
+        Self::Draft
+    }
+}
+
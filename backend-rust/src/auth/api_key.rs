@@ -0,0 +1,107 @@
+use super::constant_time_eq;
+use crate::model::ApiKey;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::Utc;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// 発行するキーの接頭辞（`sk_<key_id>_<secret>` 形式）
+const PREFIX: &str = "sk_";
+const SECRET_BYTES: usize = 32;
+const SALT_BYTES: usize = 16;
+
+/// 新しいAPIキーを生成し、保存用のレコードとクライアントに渡す平文のキーを返す
+pub fn generate(user_id: Uuid, name: String) -> (ApiKey, String) {
+    let mut rng = rand::thread_rng();
+    let mut secret = [0u8; SECRET_BYTES];
+    rng.fill_bytes(&mut secret);
+    let mut salt = [0u8; SALT_BYTES];
+    rng.fill_bytes(&mut salt);
+
+    let key_id = Uuid::new_v4();
+    let secret = URL_SAFE_NO_PAD.encode(secret);
+    let token = format!("{}{}_{}", PREFIX, key_id.simple(), secret);
+
+    let api_key = ApiKey {
+        key_id,
+        user_id,
+        name,
+        key_hash: hash_secret(&salt, &secret),
+        salt: salt.to_vec(),
+        created_at: Utc::now(),
+        revoked_at: None,
+    };
+
+    (api_key, token)
+}
+
+/// 平文のキーをキーIDとシークレットに分解する
+pub fn parse(token: &str) -> Option<(Uuid, &str)> {
+    let rest = token.strip_prefix(PREFIX)?;
+    if rest.len() <= 33 || !rest.is_char_boundary(32) {
+        return None;
+    }
+    let (key_id, secret) = rest.split_at(32);
+    let secret = secret.strip_prefix('_')?;
+    let key_id = Uuid::try_parse(key_id).ok()?;
+
+    Some((key_id, secret))
+}
+
+fn hash_secret(salt: &[u8], secret: &str) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(salt);
+    hasher.update(secret.as_bytes());
+    hasher.finalize().to_vec()
+}
+
+/// シークレットが保存済みのハッシュと一致するか（比較時間が内容に依存しないようにする）
+pub fn verify(api_key: &ApiKey, secret: &str) -> bool {
+    constant_time_eq(&hash_secret(&api_key.salt, secret), &api_key.key_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_and_verify() {
+        let user_id = Uuid::new_v4();
+        let (api_key, token) = generate(user_id, "配信ツール".to_string());
+
+        assert!(token.starts_with("sk_"));
+        assert_eq!(api_key.user_id, user_id);
+        // 平文のシークレットは保存しない
+        assert!(!api_key.key_hash.is_empty());
+        assert_ne!(api_key.key_hash, token.as_bytes());
+
+        let (key_id, secret) = parse(&token).expect("Failed to parse token");
+        assert_eq!(key_id, api_key.key_id);
+        assert!(verify(&api_key, secret));
+        assert!(!verify(&api_key, "wrong-secret"));
+    }
+
+    #[test]
+    fn test_same_secret_different_salt() {
+        let (first, _) = generate(Uuid::new_v4(), String::new());
+        let (second, _) = generate(Uuid::new_v4(), String::new());
+        assert_ne!(first.salt, second.salt);
+        assert_ne!(first.key_hash, second.key_hash);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for token in [
+            "",
+            "sk_",
+            "pk_0b6c6a529f0e4f5e8f3e2f6f1f0f8a11_secret",
+            "sk_0b6c6a529f0e4f5e8f3e2f6f1f0f8a11",
+            "sk_0b6c6a529f0e4f5e8f3e2f6f1f0f8a11-secret",
+            "sk_not-a-uuid-not-a-uuid-not-a-uu_secret",
+            "sk_あいうえおかきくけこさしすせそたちつてと_secret",
+        ] {
+            assert!(parse(token).is_none(), "{:?}", token);
+        }
+    }
+}
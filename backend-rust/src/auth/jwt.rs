@@ -0,0 +1,311 @@
+//! IDプロバイダーが発行したJWT（RS256/ES256）の検証
+
+use crate::{
+    config::{JwksSource, JwtConfig},
+    error::AppError,
+};
+use anyhow::{bail, Context, Result};
+use jsonwebtoken::{
+    decode, decode_header,
+    jwk::{AlgorithmParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm, PublicKeyUse},
+    Algorithm, DecodingKey, Validation,
+};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+/// 検証に使う公開鍵
+struct VerifyingKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: DecodingKey,
+}
+
+/// JWKSの公開鍵でJWTの署名・発行者・対象・有効期限を検証する
+pub struct JwtVerifier {
+    keys: Vec<VerifyingKey>,
+    issuer: String,
+    audience: String,
+    user_id_claim: String,
+}
+
+impl JwtVerifier {
+    /// 設定に従ってJWKSをファイルまたはURLから読み込む（起動時に1回だけ取得する）
+    pub async fn load(config: &JwtConfig) -> Result<Self> {
+        let body = match &config.jwks {
+            JwksSource::Path(path) => tokio::fs::read_to_string(path)
+                .await
+                .with_context(|| format!("Failed to read JWKS from {}", path.display()))?,
+            JwksSource::Url(url) => {
+                reqwest::get(url)
+                    .await
+                    .and_then(|res| res.error_for_status())
+                    .with_context(|| format!("Failed to fetch JWKS from {}", url))?
+                    .text()
+                    .await?
+            }
+        };
+        let jwks: JwkSet = serde_json::from_str(&body).context("Invalid JWKS format")?;
+
+        Self::from_jwks(config, &jwks)
+    }
+
+    /// RS256/ES256 以外の鍵と暗号化用の鍵は無視する
+    pub fn from_jwks(config: &JwtConfig, jwks: &JwkSet) -> Result<Self> {
+        let mut keys = Vec::new();
+        for jwk in &jwks.keys {
+            if jwk.common.public_key_use == Some(PublicKeyUse::Encryption) {
+                continue;
+            }
+            let Some(algorithm) = signing_algorithm(jwk) else {
+                tracing::warn!(kid = ?jwk.common.key_id, "Skipping unsupported JWK");
+                continue;
+            };
+            keys.push(VerifyingKey {
+                kid: jwk.common.key_id.clone(),
+                algorithm,
+                key: DecodingKey::from_jwk(jwk).context("Invalid JWK")?,
+            });
+        }
+
+        if keys.is_empty() {
+            bail!("JWKS has no RS256 or ES256 signing keys");
+        }
+
+        Ok(Self {
+            keys,
+            issuer: config.issuer.clone(),
+            audience: config.audience.clone(),
+            user_id_claim: config.user_id_claim.clone(),
+        })
+    }
+
+    /// トークンを検証し、設定したクレームの値をユーザーIDとして返す
+    pub fn verify(&self, token: &str) -> Result<Uuid, AppError> {
+        let invalid = || AppError::Unauthorized("トークンが無効です".to_string());
+
+        let header = decode_header(token).map_err(|_| invalid())?;
+        // kid がない場合は同じアルゴリズムの最初の鍵を使う
+        let key = self
+            .keys
+            .iter()
+            .find(|k| k.algorithm == header.alg && (header.kid.is_none() || k.kid == header.kid))
+            .ok_or_else(invalid)?;
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud"]);
+
+        let claims = decode::<Map<String, Value>>(token, &key.key, &validation)
+            .map_err(|e| {
+                tracing::debug!("JWT verification failed: {}", e);
+                invalid()
+            })?
+            .claims;
+
+        claims
+            .get(&self.user_id_claim)
+            .and_then(Value::as_str)
+            .and_then(|v| Uuid::parse_str(v).ok())
+            .ok_or_else(invalid)
+    }
+}
+
+/// 鍵の `alg`、未指定の場合は鍵の種類から署名アルゴリズムを決める
+fn signing_algorithm(jwk: &Jwk) -> Option<Algorithm> {
+    match (&jwk.algorithm, jwk.common.key_algorithm) {
+        (AlgorithmParameters::RSA(_), None | Some(KeyAlgorithm::RS256)) => Some(Algorithm::RS256),
+        (AlgorithmParameters::EllipticCurve(ec), None | Some(KeyAlgorithm::ES256))
+            if ec.curve == EllipticCurve::P256 =>
+        {
+            Some(Algorithm::ES256)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+
+    pub(crate) const ISSUER: &str = "https://idp.example.com/";
+    pub(crate) const AUDIENCE: &str = "web100";
+
+    const JWKS: &str = include_str!("../../tests/fixtures/jwt/jwks.json");
+    const RS256_KEY: &[u8] = include_bytes!("../../tests/fixtures/jwt/rs256.pem");
+    const ES256_KEY: &[u8] = include_bytes!("../../tests/fixtures/jwt/es256.pem");
+    const OTHER_KEY: &[u8] = include_bytes!("../../tests/fixtures/jwt/other.pem");
+
+    fn config(jwks: JwksSource) -> JwtConfig {
+        JwtConfig {
+            issuer: ISSUER.to_string(),
+            audience: AUDIENCE.to_string(),
+            jwks,
+            user_id_claim: "sub".to_string(),
+        }
+    }
+
+    pub(crate) fn verifier() -> JwtVerifier {
+        let jwks = serde_json::from_str(JWKS).unwrap();
+        JwtVerifier::from_jwks(&config(JwksSource::Path("unused".into())), &jwks).unwrap()
+    }
+
+    fn claims(sub: &str) -> Value {
+        json!({
+            "iss": ISSUER,
+            "aud": AUDIENCE,
+            "sub": sub,
+            "exp": chrono::Utc::now().timestamp() + 300,
+        })
+    }
+
+    fn sign(alg: Algorithm, kid: Option<&str>, pem: &[u8], claims: &Value) -> String {
+        let key = match alg {
+            Algorithm::ES256 => EncodingKey::from_ec_pem(pem).unwrap(),
+            _ => EncodingKey::from_rsa_pem(pem).unwrap(),
+        };
+        let header = Header {
+            kid: kid.map(str::to_string),
+            ..Header::new(alg)
+        };
+        encode(&header, claims, &key).unwrap()
+    }
+
+    /// テスト用のRS256鍵で署名したトークンを発行する
+    pub(crate) fn issue_token(user_id: Uuid) -> String {
+        sign(
+            Algorithm::RS256,
+            Some("test-rs256"),
+            RS256_KEY,
+            &claims(&user_id.to_string()),
+        )
+    }
+
+    fn assert_invalid(result: Result<Uuid, AppError>) {
+        match result {
+            Err(AppError::Unauthorized(msg)) => assert_eq!(msg, "トークンが無効です"),
+            _ => panic!("Unexpected result"),
+        }
+    }
+
+    #[test]
+    fn test_verify_rs256_and_es256() {
+        let verifier = verifier();
+        let user_id = Uuid::new_v4();
+
+        assert_eq!(verifier.verify(&issue_token(user_id)).unwrap(), user_id);
+
+        let token = sign(
+            Algorithm::ES256,
+            Some("test-es256"),
+            ES256_KEY,
+            &claims(&user_id.to_string()),
+        );
+        assert_eq!(verifier.verify(&token).unwrap(), user_id);
+
+        // kid がなくてもアルゴリズムが一致する鍵で検証する
+        let token = sign(
+            Algorithm::ES256,
+            None,
+            ES256_KEY,
+            &claims(&user_id.to_string()),
+        );
+        assert_eq!(verifier.verify(&token).unwrap(), user_id);
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_tokens() {
+        let verifier = verifier();
+        let sub = Uuid::new_v4().to_string();
+
+        assert_invalid(verifier.verify("not-a-jwt"));
+
+        // JWKSにない鍵で署名
+        let token = sign(
+            Algorithm::RS256,
+            Some("test-rs256"),
+            OTHER_KEY,
+            &claims(&sub),
+        );
+        assert_invalid(verifier.verify(&token));
+
+        // 未知の kid
+        let token = sign(Algorithm::RS256, Some("unknown"), RS256_KEY, &claims(&sub));
+        assert_invalid(verifier.verify(&token));
+
+        // 発行者・対象の不一致
+        let mut c = claims(&sub);
+        c["iss"] = json!("https://evil.example.com/");
+        assert_invalid(verifier.verify(&sign(Algorithm::RS256, None, RS256_KEY, &c)));
+        let mut c = claims(&sub);
+        c["aud"] = json!("other-app");
+        assert_invalid(verifier.verify(&sign(Algorithm::RS256, None, RS256_KEY, &c)));
+
+        // 有効期限切れ・有効期限なし
+        let mut c = claims(&sub);
+        c["exp"] = json!(chrono::Utc::now().timestamp() - 3600);
+        assert_invalid(verifier.verify(&sign(Algorithm::RS256, None, RS256_KEY, &c)));
+        let mut c = claims(&sub);
+        c.as_object_mut().unwrap().remove("exp");
+        assert_invalid(verifier.verify(&sign(Algorithm::RS256, None, RS256_KEY, &c)));
+
+        // ユーザーIDがUUIDではない
+        let token = sign(Algorithm::RS256, None, RS256_KEY, &claims("auth0|12345"));
+        assert_invalid(verifier.verify(&token));
+    }
+
+    #[test]
+    fn test_verify_custom_user_id_claim() {
+        let jwks = serde_json::from_str(JWKS).unwrap();
+        let config = JwtConfig {
+            user_id_claim: "https://example.com/user_id".to_string(),
+            ..config(JwksSource::Path("unused".into()))
+        };
+        let verifier = JwtVerifier::from_jwks(&config, &jwks).unwrap();
+        let user_id = Uuid::new_v4();
+
+        let mut c = claims("auth0|12345");
+        c["https://example.com/user_id"] = json!(user_id.to_string());
+        let token = sign(Algorithm::ES256, None, ES256_KEY, &c);
+        assert_eq!(verifier.verify(&token).unwrap(), user_id);
+    }
+
+    #[test]
+    fn test_from_jwks_requires_signing_keys() {
+        let jwks = serde_json::from_value(json!({
+            "keys": [{"kty": "oct", "kid": "hmac", "alg": "HS256", "k": "c2VjcmV0"}]
+        }))
+        .unwrap();
+        assert!(JwtVerifier::from_jwks(&config(JwksSource::Path("unused".into())), &jwks).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_load_from_path_and_url() {
+        let user_id = Uuid::new_v4();
+
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/jwt/jwks.json");
+        let verifier = JwtVerifier::load(&config(JwksSource::Path(path.into())))
+            .await
+            .unwrap();
+        assert_eq!(verifier.verify(&issue_token(user_id)).unwrap(), user_id);
+
+        // ローカルで起動したサーバーから取得する
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = axum::Router::new().route("/jwks.json", axum::routing::get(|| async { JWKS }));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let url = format!("http://{}/jwks.json", addr);
+        let verifier = JwtVerifier::load(&config(JwksSource::Url(url)))
+            .await
+            .unwrap();
+        assert_eq!(verifier.verify(&issue_token(user_id)).unwrap(), user_id);
+
+        let url = format!("http://{}/missing.json", addr);
+        assert!(JwtVerifier::load(&config(JwksSource::Url(url)))
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,362 @@
+pub mod api_key;
+pub mod jwt;
+pub mod permission;
+pub mod session;
+pub mod share;
+
+use crate::{
+    error::AppError,
+    model::{Role, Session},
+    repository::{ApiKeyRepository, SessionRepository, UserRepository},
+};
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Request, State},
+    http::{header, request::Parts, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use axum_extra::extract::cookie::CookieJar;
+use jwt::JwtVerifier;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// APIキーを指定するリクエストヘッダー
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// 認証済みのユーザー（`authenticate` ミドルウェアがリクエストの extensions に格納する）
+///
+/// ハンドラーの引数に指定すると認証必須になり、未認証の場合は 401 を返す。
+/// 認証が任意のハンドラーでは `Option<AuthUser>` を使う。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthUser {
+    pub user_id: Uuid,
+    pub role: Role,
+}
+
+impl AuthUser {
+    /// 自分自身のリソースに対する操作かどうかを確認する
+    pub fn ensure_user(&self, user_id: Uuid) -> Result<(), AppError> {
+        if self.user_id != user_id {
+            return Err(AppError::Forbidden(
+                "他のユーザーのリソースは操作できません".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<AuthUser>()
+            .copied()
+            .ok_or_else(|| AppError::Unauthorized("認証が必要です".to_string()))
+    }
+}
+
+/// `X-API-Key` ヘッダーのAPIキー、`Authorization: Bearer` のJWT、セッション Cookie の順に検証し、
+/// 認証済みユーザーを extensions に格納する
+///
+/// いずれもない場合はそのまま通し、認証の要否は各ハンドラーの `AuthUser` に任せる。
+/// 無効なキー・トークンが指定された場合は 401 を返す。
+/// 無効なセッション Cookie は未認証として扱う（ブラウザが古い Cookie を送り続けるため）。
+/// Cookie で認証した更新系のリクエストは `X-CSRF-Token` ヘッダーがセッションと一致しない場合 403 を返す。
+pub async fn authenticate(
+    State(repo): State<Arc<dyn ApiKeyRepository>>,
+    State(user_repo): State<Arc<dyn UserRepository>>,
+    State(session_repo): State<Arc<dyn SessionRepository>>,
+    State(jwt): State<Option<Arc<JwtVerifier>>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let user_id = if let Some(value) = req.headers().get(API_KEY_HEADER) {
+        let token = value.to_str().unwrap_or_default();
+        Some(verify_api_key(repo.as_ref(), token).await?)
+    } else if let Some(token) = bearer_token(req.headers()) {
+        let jwt = jwt.ok_or_else(|| {
+            AppError::Unauthorized("JWTによる認証は有効になっていません".to_string())
+        })?;
+        Some(jwt.verify(token)?)
+    } else if let Some(session) = find_session(session_repo.as_ref(), req.headers()).await? {
+        if !req.method().is_safe() {
+            let csrf_token = req
+                .headers()
+                .get(session::CSRF_HEADER)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default();
+            if !session::verify_csrf(&session, csrf_token) {
+                return Err(AppError::Forbidden("CSRFトークンが無効です".to_string()));
+            }
+        }
+        let user_id = session.user_id;
+        req.extensions_mut().insert(session);
+        Some(user_id)
+    } else {
+        None
+    };
+
+    if let Some(user_id) = user_id {
+        let user = user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized("ユーザーが存在しません".to_string()))?;
+        req.extensions_mut().insert(AuthUser {
+            user_id,
+            role: user.role,
+        });
+    }
+
+    Ok(next.run(req).await)
+}
+
+/// `Authorization: Bearer <token>` のトークン部分（それ以外の形式は無視する）
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+    scheme
+        .eq_ignore_ascii_case("bearer")
+        .then(|| token.trim())
+        .filter(|t| !t.is_empty())
+}
+
+/// セッション Cookie に対応する有効なセッション
+async fn find_session(
+    repo: &dyn SessionRepository,
+    headers: &HeaderMap,
+) -> Result<Option<Session>, AppError> {
+    let jar = CookieJar::from_headers(headers);
+    let Some(cookie) = jar.get(session::SESSION_COOKIE) else {
+        return Ok(None);
+    };
+
+    Ok(repo
+        .find_active_by_token_hash(&session::hash_token(cookie.value()))
+        .await?)
+}
+
+/// 比較時間が内容に依存しないようにバイト列を比較する
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+async fn verify_api_key(repo: &dyn ApiKeyRepository, token: &str) -> Result<Uuid, AppError> {
+    let invalid = || AppError::Unauthorized("APIキーが無効です".to_string());
+
+    let (key_id, secret) = api_key::parse(token).ok_or_else(invalid)?;
+    let api_key = repo.find_active_by_id(key_id).await?.ok_or_else(invalid)?;
+    if !api_key::verify(&api_key, secret) {
+        return Err(invalid());
+    }
+
+    Ok(api_key.user_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::User;
+    use crate::repository::mock::MockRepositories;
+    use crate::state::AppState;
+    use axum::{body::Body, http::StatusCode, routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn whoami(auth: Option<AuthUser>) -> String {
+        auth.map(|a| format!("{}:{}", a.user_id, a.role.as_str()))
+            .unwrap_or_default()
+    }
+
+    fn app(repos: &MockRepositories) -> Router {
+        router(repos.state())
+    }
+
+    fn router(state: AppState) -> Router {
+        Router::new()
+            .route("/", get(whoami).post(whoami))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                authenticate,
+            ))
+            .with_state(state)
+    }
+
+    async fn call(app: Router, api_key: Option<&str>) -> (StatusCode, String) {
+        let mut req = Request::builder().uri("/");
+        if let Some(api_key) = api_key {
+            req = req.header(API_KEY_HEADER, api_key);
+        }
+        send(app, req.body(Body::empty()).unwrap()).await
+    }
+
+    async fn call_bearer(app: Router, authorization: &str) -> (StatusCode, String) {
+        let req = Request::builder()
+            .uri("/")
+            .header(header::AUTHORIZATION, authorization)
+            .body(Body::empty())
+            .unwrap();
+        send(app, req).await
+    }
+
+    async fn send(app: Router, req: Request) -> (StatusCode, String) {
+        let response = app.oneshot(req).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_authenticate() {
+        let repos = MockRepositories::default();
+        let user_id = Uuid::new_v4();
+        repos.users.create(&User::new(user_id)).await.unwrap();
+        let (key, token) = api_key::generate(user_id, String::new());
+        repos.api_keys.create(&key).await.unwrap();
+
+        // 正しいキー
+        let (status, body) = call(app(&repos), Some(&token)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{}:owner", user_id));
+
+        // ヘッダーなしは未認証のまま通す
+        let (status, body) = call(app(&repos), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "");
+
+        // シークレットの誤り
+        let (status, _) = call(app(&repos), Some(&format!("{}x", token))).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // 失効済みのキー
+        repos.api_keys.revoke(user_id, key.key_id).await.unwrap();
+        let (status, _) = call(app(&repos), Some(&token)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_admin() {
+        let repos = MockRepositories::default();
+        let user_id = Uuid::new_v4();
+        let mut user = User::new(user_id);
+        user.role = Role::Admin;
+        repos.users.create(&user).await.unwrap();
+        let (key, token) = api_key::generate(user_id, String::new());
+        repos.api_keys.create(&key).await.unwrap();
+
+        let (status, body) = call(app(&repos), Some(&token)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{}:admin", user_id));
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_bearer() {
+        let repos = MockRepositories::default();
+        let user_id = Uuid::new_v4();
+        repos.users.create(&User::new(user_id)).await.unwrap();
+        let token = jwt::tests::issue_token(user_id);
+        let state = AppState {
+            jwt: Some(Arc::new(jwt::tests::verifier())),
+            ..repos.state()
+        };
+
+        let (status, body) = call_bearer(router(state.clone()), &format!("Bearer {}", token)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{}:owner", user_id));
+
+        // Bearer 以外の形式は無視する
+        let (status, body) = call_bearer(router(state.clone()), "Basic dXNlcjpwYXNz").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "");
+
+        let (status, _) = call_bearer(router(state.clone()), "Bearer invalid").await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // 登録されていないユーザーのトークン
+        let token = jwt::tests::issue_token(Uuid::new_v4());
+        let (status, _) = call_bearer(router(state), &format!("Bearer {}", token)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // JWT認証を設定していない場合
+        let token = jwt::tests::issue_token(user_id);
+        let (status, _) = call_bearer(app(&repos), &format!("Bearer {}", token)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_authenticate_session_cookie() {
+        let repos = MockRepositories::default();
+        let user_id = Uuid::new_v4();
+        repos.users.create(&User::new(user_id)).await.unwrap();
+        let (new_session, token) = session::generate(user_id, &Default::default());
+        repos.sessions.create(&new_session).await.unwrap();
+        let cookie = format!("{}={}", session::SESSION_COOKIE, token);
+
+        let request = |method: &str, csrf_token: Option<&str>| {
+            let mut req = Request::builder()
+                .method(method)
+                .uri("/")
+                .header(header::COOKIE, &cookie);
+            if let Some(csrf_token) = csrf_token {
+                req = req.header(session::CSRF_HEADER, csrf_token);
+            }
+            req.body(Body::empty()).unwrap()
+        };
+
+        // 参照系は CSRF トークン不要
+        let (status, body) = send(app(&repos), request("GET", None)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{}:owner", user_id));
+
+        // 更新系は CSRF トークンが必要
+        let (status, _) = send(app(&repos), request("POST", None)).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send(app(&repos), request("POST", Some("wrong"))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, body) = send(
+            app(&repos),
+            request("POST", Some(&new_session.csrf_token)),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("{}:owner", user_id));
+
+        // 無効な Cookie は未認証として扱う
+        let req = Request::builder()
+            .uri("/")
+            .header(header::COOKIE, format!("{}=unknown", session::SESSION_COOKIE))
+            .body(Body::empty())
+            .unwrap();
+        let (status, body) = send(app(&repos), req).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "");
+    }
+
+    #[tokio::test]
+    async fn test_auth_user_extractor() {
+        let user_id = Uuid::new_v4();
+        let (mut parts, _) = Request::builder().body(()).unwrap().into_parts();
+
+        match AuthUser::from_request_parts(&mut parts, &()).await {
+            Err(AppError::Unauthorized(msg)) => assert_eq!(msg, "認証が必要です"),
+            _ => panic!("Unexpected result"),
+        }
+
+        parts.extensions.insert(AuthUser {
+            user_id,
+            role: Role::Owner,
+        });
+        let auth = AuthUser::from_request_parts(&mut parts, &()).await.unwrap();
+        assert!(auth.ensure_user(user_id).is_ok());
+        match auth.ensure_user(Uuid::new_v4()) {
+            Err(AppError::Forbidden(msg)) => {
+                assert_eq!(msg, "他のユーザーのリソースは操作できません")
+            }
+            _ => panic!("Unexpected result"),
+        }
+    }
+}
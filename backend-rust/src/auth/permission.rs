@@ -0,0 +1,359 @@
+//! 配信情報に対する操作の権限判定
+//!
+//! | 操作                       | 未認証 | 配信者本人 | 他の配信者 | モデレーター | 管理者 |
+//! |----------------------------|--------|------------|------------|--------------|--------|
+//! | 公開・限定公開の配信の閲覧 | ○      | ○          | ○          | ○            | ○      |
+//! | 非公開・下書きの配信の閲覧 | ×      | ○          | ×          | ○            | ○      |
+//! | 登録                       | ×      | ○          | ○          | ○            | ○      |
+//! | 更新                       | ×      | ○          | ×          | ○            | ○      |
+//! | 削除                       | ×      | ○          | ×          | ×            | ○      |
+//! | 共有リンクの管理           | ×      | ○          | ×          | ○            | ○      |
+//! | 削除済みを含む一覧         | ×      | ×          | ×          | ×            | ○      |
+//!
+//! 配信者から委任されたユーザーは、委任の範囲に応じて配信者本人と同じ操作ができる
+//! （`read` は非公開・下書きの配信の閲覧、`write` はさらに更新と共有リンクの管理、`delete` はさらに削除）。
+
+use super::AuthUser;
+use crate::{
+    error::AppError,
+    model::{Grant, GrantScope, PrivateScope, Role, Stream},
+};
+use chrono::Utc;
+
+/// 配信に対する操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamAction {
+    View,
+    Create,
+    Update,
+    Delete,
+    /// 共有リンクの発行・一覧・取り消し（未ログインの相手に非公開の配信を見せられるため更新と同じ権限とする）
+    Share,
+    /// 全ユーザーの配信を削除済みのものも含めて一覧する
+    ListAll,
+}
+
+/// 委任が操作する本人に対するこの配信の有効なものであれば、その範囲を返す
+fn granted_scope(
+    auth: Option<&AuthUser>,
+    stream: Option<&Stream>,
+    grant: Option<&Grant>,
+) -> Option<GrantScope> {
+    match (auth, stream, grant) {
+        (Some(a), Some(s), Some(g))
+            if g.grantee_id == a.user_id && g.owner_id == s.user_id && g.is_active(Utc::now()) =>
+        {
+            Some(g.scope)
+        }
+        _ => None,
+    }
+}
+
+fn allows(
+    auth: Option<&AuthUser>,
+    action: StreamAction,
+    stream: Option<&Stream>,
+    grant: Option<&Grant>,
+) -> bool {
+    let role = auth.map(|a| a.role);
+    let is_owner = matches!((auth, stream), (Some(a), Some(s)) if a.user_id == s.user_id);
+    let is_staff = matches!(role, Some(Role::Moderator | Role::Admin));
+    let granted = |scope: GrantScope| granted_scope(auth, stream, grant) >= Some(scope);
+
+    match action {
+        StreamAction::View => {
+            stream.is_some_and(|s| s.visibility.is_viewable_by_anyone())
+                || is_owner
+                || is_staff
+                || granted(GrantScope::Read)
+        }
+        StreamAction::Create => auth.is_some(),
+        StreamAction::Update | StreamAction::Share => {
+            is_owner || is_staff || granted(GrantScope::Write)
+        }
+        StreamAction::Delete => {
+            is_owner || role == Some(Role::Admin) || granted(GrantScope::Delete)
+        }
+        StreamAction::ListAll => role == Some(Role::Admin),
+    }
+}
+
+/// 操作できるか確認する（ハンドラーは配信を操作する前に必ず呼び出す）
+///
+/// 閲覧できない配信は存在を明かさないよう 404、閲覧はできるが操作できない場合は 403 を返す。
+/// `grant` には配信者から操作する本人への委任を渡す（ない場合は `None`）。
+pub fn authorize(
+    auth: Option<&AuthUser>,
+    action: StreamAction,
+    stream: Option<&Stream>,
+    grant: Option<&Grant>,
+) -> Result<(), AppError> {
+    if allows(auth, action, stream, grant) {
+        return Ok(());
+    }
+
+    let not_found = || AppError::NotFound("Stream not found".to_string());
+    match action {
+        StreamAction::View => Err(not_found()),
+        _ if auth.is_none() => Err(AppError::Unauthorized("認証が必要です".to_string())),
+        StreamAction::Update | StreamAction::Delete | StreamAction::Share
+            if !allows(auth, StreamAction::View, stream, grant) =>
+        {
+            Err(not_found())
+        }
+        StreamAction::ListAll => Err(AppError::Forbidden("管理者のみ操作できます".to_string())),
+        _ => Err(AppError::Forbidden(
+            "この配信を操作する権限がありません".to_string(),
+        )),
+    }
+}
+
+/// 一覧に含める公開以外の配信の範囲（委任による閲覧はリポジトリが一覧の取得時に反映する）
+pub fn private_scope(auth: Option<&AuthUser>) -> PrivateScope {
+    match auth {
+        Some(auth) if matches!(auth.role, Role::Moderator | Role::Admin) => PrivateScope::All,
+        Some(auth) => PrivateScope::OwnedBy(auth.user_id),
+        None => PrivateScope::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Visibility;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn stream(user_id: Uuid, visibility: Visibility) -> Stream {
+        Stream {
+            stream_id: Uuid::new_v4(),
+            user_id,
+            title: "title".to_string(),
+            description: "desc".to_string(),
+            category: "".to_string(),
+            visibility,
+            version: 1,
+            created_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    fn user(role: Role) -> AuthUser {
+        AuthUser {
+            user_id: Uuid::new_v4(),
+            role,
+        }
+    }
+
+    /// 結果を `o`（許可）・`401`・`403`・`404` で表す
+    fn outcome(
+        auth: Option<&AuthUser>,
+        action: StreamAction,
+        stream: Option<&Stream>,
+    ) -> &'static str {
+        match authorize(auth, action, stream, None) {
+            Ok(()) => "o",
+            Err(AppError::Unauthorized(_)) => "401",
+            Err(AppError::Forbidden(_)) => "403",
+            Err(AppError::NotFound(_)) => "404",
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_role_matrix() {
+        let owner = user(Role::Owner);
+        let other = user(Role::Owner);
+        let moderator = user(Role::Moderator);
+        let admin = user(Role::Admin);
+        let public = stream(owner.user_id, Visibility::Published);
+        let private = stream(owner.user_id, Visibility::Private);
+
+        // 列: 未認証, 配信者本人, 他の配信者, モデレーター, 管理者
+        let callers = [
+            None,
+            Some(&owner),
+            Some(&other),
+            Some(&moderator),
+            Some(&admin),
+        ];
+        let cases = [
+            (StreamAction::View, Some(&public), ["o", "o", "o", "o", "o"]),
+            (
+                StreamAction::View,
+                Some(&private),
+                ["404", "o", "404", "o", "o"],
+            ),
+            (StreamAction::Create, None, ["401", "o", "o", "o", "o"]),
+            (
+                StreamAction::Update,
+                Some(&public),
+                ["401", "o", "403", "o", "o"],
+            ),
+            (
+                StreamAction::Update,
+                Some(&private),
+                ["401", "o", "404", "o", "o"],
+            ),
+            (
+                StreamAction::Delete,
+                Some(&public),
+                ["401", "o", "403", "403", "o"],
+            ),
+            (
+                StreamAction::Delete,
+                Some(&private),
+                ["401", "o", "404", "403", "o"],
+            ),
+            (
+                StreamAction::Share,
+                Some(&public),
+                ["401", "o", "403", "o", "o"],
+            ),
+            (
+                StreamAction::Share,
+                Some(&private),
+                ["401", "o", "404", "o", "o"],
+            ),
+            (
+                StreamAction::ListAll,
+                None,
+                ["401", "403", "403", "403", "o"],
+            ),
+        ];
+
+        for (action, stream, expected) in cases {
+            for (auth, expected) in callers.iter().zip(expected) {
+                assert_eq!(
+                    outcome(*auth, action, stream),
+                    expected,
+                    "{:?} by {:?} ({:?})",
+                    action,
+                    auth.map(|a| a.role),
+                    stream.map(|s| s.visibility),
+                );
+            }
+        }
+    }
+
+    fn grant(owner: &AuthUser, grantee: &AuthUser, scope: GrantScope) -> Grant {
+        Grant {
+            grant_id: Uuid::new_v4(),
+            owner_id: owner.user_id,
+            grantee_id: grantee.user_id,
+            scope,
+            created_at: Utc::now(),
+            expires_at: None,
+            revoked_at: None,
+        }
+    }
+
+    #[test]
+    fn test_grant_scopes() {
+        let owner = user(Role::Owner);
+        let grantee = user(Role::Owner);
+        let private = stream(owner.user_id, Visibility::Private);
+        let outcome = |grant: &Grant, action| match authorize(
+            Some(&grantee),
+            action,
+            Some(&private),
+            Some(grant),
+        ) {
+            Ok(()) => "o",
+            Err(AppError::Forbidden(_)) => "403",
+            Err(AppError::NotFound(_)) => "404",
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        };
+
+        // 列: 閲覧, 更新, 削除, 共有リンクの管理
+        let actions = [
+            StreamAction::View,
+            StreamAction::Update,
+            StreamAction::Delete,
+            StreamAction::Share,
+        ];
+        let cases = [
+            (GrantScope::Read, ["o", "403", "403", "403"]),
+            (GrantScope::Write, ["o", "o", "403", "o"]),
+            (GrantScope::Delete, ["o", "o", "o", "o"]),
+        ];
+        for (scope, expected) in cases {
+            let g = grant(&owner, &grantee, scope);
+            for (action, expected) in actions.into_iter().zip(expected) {
+                assert_eq!(
+                    outcome(&g, action),
+                    expected,
+                    "{:?} with {:?}",
+                    action,
+                    scope
+                );
+            }
+        }
+
+        // 期限切れ・取り消し済み・他の配信者からの委任・他のユーザーへの委任は無視する
+        let ignored = [
+            Grant {
+                expires_at: Some(Utc::now() - chrono::Duration::minutes(1)),
+                ..grant(&owner, &grantee, GrantScope::Delete)
+            },
+            Grant {
+                revoked_at: Some(Utc::now()),
+                ..grant(&owner, &grantee, GrantScope::Delete)
+            },
+            grant(&user(Role::Owner), &grantee, GrantScope::Delete),
+            grant(&owner, &user(Role::Owner), GrantScope::Delete),
+        ];
+        for g in &ignored {
+            for action in actions {
+                assert_eq!(outcome(g, action), "404", "{:?} with {:?}", action, g);
+            }
+        }
+    }
+
+    #[test]
+    fn test_view_by_visibility() {
+        let owner = user(Role::Owner);
+        let other = user(Role::Owner);
+
+        // 列: 未認証, 配信者本人, 他の配信者, モデレーター
+        let callers = [
+            None,
+            Some(&owner),
+            Some(&other),
+            Some(&user(Role::Moderator)),
+        ];
+        let cases = [
+            (Visibility::Draft, ["404", "o", "404", "o"]),
+            (Visibility::Private, ["404", "o", "404", "o"]),
+            (Visibility::Unlisted, ["o", "o", "o", "o"]),
+            (Visibility::Published, ["o", "o", "o", "o"]),
+        ];
+        for (visibility, expected) in cases {
+            let s = stream(owner.user_id, visibility);
+            for (auth, expected) in callers.iter().zip(expected) {
+                assert_eq!(
+                    outcome(*auth, StreamAction::View, Some(&s)),
+                    expected,
+                    "{:?} by {:?}",
+                    visibility,
+                    auth.map(|a| a.role),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_private_scope() {
+        let owner = user(Role::Owner);
+        assert_eq!(private_scope(None), PrivateScope::None);
+        assert_eq!(
+            private_scope(Some(&owner)),
+            PrivateScope::OwnedBy(owner.user_id)
+        );
+        assert_eq!(
+            private_scope(Some(&user(Role::Moderator))),
+            PrivateScope::All
+        );
+        assert_eq!(private_scope(Some(&user(Role::Admin))), PrivateScope::All);
+    }
+}
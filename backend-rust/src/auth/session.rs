@@ -0,0 +1,110 @@
+use super::constant_time_eq;
+use crate::{config::SessionConfig, model::Session};
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{Duration, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// セッショントークンを格納する Cookie の名前
+pub const SESSION_COOKIE: &str = "session_id";
+/// Cookie で認証した更新系リクエストに付与する CSRF トークンのヘッダー
+pub const CSRF_HEADER: &str = "x-csrf-token";
+
+const TOKEN_BYTES: usize = 32;
+
+pub(crate) fn random_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// 新しいセッションを生成し、保存用のレコードと Cookie に格納するトークンを返す
+pub fn generate(user_id: Uuid, config: &SessionConfig) -> (Session, String) {
+    let token = random_token();
+    let now = Utc::now();
+
+    let session = Session {
+        session_id: Uuid::new_v4(),
+        user_id,
+        token_hash: hash_token(&token),
+        csrf_token: random_token(),
+        created_at: now,
+        expires_at: now + Duration::hours(config.ttl_hours),
+    };
+
+    (session, token)
+}
+
+/// トークン自体が十分な長さの乱数なので、ソルトは付けずにハッシュ化する
+pub fn hash_token(token: &str) -> Vec<u8> {
+    Sha256::digest(token.as_bytes()).to_vec()
+}
+
+/// リクエストの CSRF トークンがセッションのものと一致するか
+pub fn verify_csrf(session: &Session, token: &str) -> bool {
+    constant_time_eq(session.csrf_token.as_bytes(), token.as_bytes())
+}
+
+/// ログイン時に設定する Cookie（JavaScript からは参照できない）
+pub fn session_cookie(token: String, config: &SessionConfig) -> Cookie<'static> {
+    Cookie::build((SESSION_COOKIE, token))
+        .path("/")
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .secure(config.cookie_secure)
+        .max_age(time::Duration::hours(config.ttl_hours))
+        .build()
+}
+
+/// ログアウト時に Cookie を削除するための Cookie（属性は設定時と揃える）
+pub fn removal_cookie(config: &SessionConfig) -> Cookie<'static> {
+    let mut cookie = session_cookie(String::new(), config);
+    cookie.make_removal();
+    cookie
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let user_id = Uuid::new_v4();
+        let config = SessionConfig::default();
+        let (session, token) = generate(user_id, &config);
+
+        assert_eq!(session.user_id, user_id);
+        assert_eq!(session.token_hash, hash_token(&token));
+        assert_ne!(session.csrf_token, token);
+        assert_eq!(
+            session.expires_at - session.created_at,
+            Duration::hours(24 * 7)
+        );
+
+        assert!(verify_csrf(&session, &session.csrf_token.clone()));
+        assert!(!verify_csrf(&session, "wrong-token"));
+        assert!(!verify_csrf(&session, ""));
+    }
+
+    #[test]
+    fn test_cookies() {
+        let config = SessionConfig {
+            ttl_hours: 1,
+            cookie_secure: false,
+        };
+
+        let cookie = session_cookie("token".to_string(), &config).to_string();
+        assert!(cookie.starts_with("session_id=token;"));
+        assert!(cookie.contains("HttpOnly"));
+        assert!(cookie.contains("SameSite=Lax"));
+        assert!(cookie.contains("Max-Age=3600"));
+        assert!(!cookie.contains("Secure"));
+
+        let cookie = removal_cookie(&SessionConfig::default()).to_string();
+        assert!(cookie.starts_with("session_id=;"));
+        assert!(cookie.contains("Max-Age=0"));
+        assert!(cookie.contains("Secure"));
+    }
+}
@@ -0,0 +1,46 @@
+//! 配信の共有リンクのトークン
+
+use super::session::{hash_token, random_token};
+use crate::model::ShareLink;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 新しい共有リンクを生成し、保存用のレコードと URL に含めるトークンを返す
+pub fn generate(
+    stream_id: Uuid,
+    created_by: Uuid,
+    expires_at: Option<DateTime<Utc>>,
+) -> (ShareLink, String) {
+    let token = random_token();
+
+    let link = ShareLink {
+        link_id: Uuid::new_v4(),
+        stream_id,
+        created_by,
+        token_hash: hash_token(&token),
+        created_at: Utc::now(),
+        expires_at,
+        revoked_at: None,
+        access_count: 0,
+        last_accessed_at: None,
+    };
+
+    (link, token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let (link, token) = generate(Uuid::new_v4(), Uuid::new_v4(), None);
+        assert_eq!(link.token_hash, hash_token(&token));
+        assert_eq!(link.access_count, 0);
+
+        // 推測できない長さの乱数で、毎回異なる
+        assert_eq!(token.len(), 43);
+        let (_, other) = generate(link.stream_id, link.created_by, None);
+        assert_ne!(token, other);
+    }
+}
@@ -0,0 +1,173 @@
+//! フロントマター付きの Markdown・テキストファイルから配信情報を取り込むコマンド
+//!
+//! 1 ファイルを 1 件の配信として、フロントマターの `title`・`category`・`date` と本文（概要欄）を登録する。
+
+use super::{connect, import, parse_import_args, read_files};
+use crate::{
+    handler::import::ParsedRow,
+    markdown::{self, Document},
+    schema::ImportStreamRow,
+};
+use anyhow::{Context, Result};
+use chrono::SecondsFormat;
+use chrono_tz::Tz;
+
+/// サブコマンド名
+pub const COMMAND: &str = "import-markdown";
+
+/// 取り込むファイルの拡張子
+const EXTENSIONS: [&str; 3] = [".md", ".markdown", ".txt"];
+
+fn is_candidate(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    // 隠しファイル（エディタの一時ファイルなど）は取り込まない
+    let hidden = name.rsplit('/').next().is_some_and(|n| n.starts_with('.'));
+    !hidden && EXTENSIONS.iter().any(|ext| name.ends_with(ext))
+}
+
+/// フロントマターにタイトルがない場合は、拡張子を除いたファイル名をタイトルにする
+fn file_title(name: &str) -> &str {
+    let name = name.rsplit('/').next().unwrap_or(name);
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+/// ファイル1件分を取り込む行にする（日付だけの `date` は `tz` の日時として扱う）
+fn to_row(name: &str, body: &[u8], tz: Tz) -> Result<ImportStreamRow, String> {
+    let text = std::str::from_utf8(body).map_err(|_| "ファイルはUTF-8で指定してください")?;
+    let Document { front_matter, body } = markdown::parse(text)?;
+    let created_at = front_matter
+        .date
+        .map(|date| markdown::parse_date(&date, tz))
+        .transpose()?;
+    Ok(ImportStreamRow {
+        title: Some(
+            front_matter
+                .title
+                .unwrap_or_else(|| file_title(name).to_string()),
+        ),
+        description: Some(body),
+        category: front_matter.category,
+        created_at: created_at.map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        ..ImportStreamRow::new()
+    })
+}
+
+/// コマンドライン引数（サブコマンド名より後ろ）を受け取って取り込みを実行する
+pub async fn run(args: &[String]) -> Result<()> {
+    let args = parse_import_args(COMMAND, args)?;
+    let files = read_files(&args.path, is_candidate)?;
+
+    let (repo, user) = connect(args.user_id).await?;
+    let tz: Tz = user
+        .time_zone
+        .parse()
+        .map_err(anyhow::Error::msg)
+        .with_context(|| format!("invalid time zone: {}", user.time_zone))?;
+    let files = files
+        .into_iter()
+        .map(|(name, body)| {
+            let row = to_row(&name, &body, tz);
+            (name, vec![ParsedRow { line: 1, row }])
+        })
+        .collect();
+    import(&repo, &user, &args, files).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::temp_dir;
+    use super::*;
+    use crate::{
+        auth::AuthUser, handler::import::import_rows, model::Role,
+        repository::mock::MockStreamRepository,
+    };
+    use chrono::{DateTime, Utc};
+    use std::fs;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_is_candidate() {
+        for name in ["a.md", "notes/2025/B.MARKDOWN", "c.txt"] {
+            assert!(is_candidate(name), "{}", name);
+        }
+        for name in ["a.csv", "notes/.a.md", ".txt", "md"] {
+            assert!(!is_candidate(name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_to_row() {
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+
+        let row = to_row(
+            "2025/01/a.md",
+            "---\ntitle: 今日の配信\ncategory: game\ndate: 2025-01-02\n---\n\n本文\n".as_bytes(),
+            tokyo,
+        )
+        .unwrap();
+        assert_eq!(row.title.as_deref(), Some("今日の配信"));
+        assert_eq!(row.description.as_deref(), Some("本文"));
+        assert_eq!(row.category.as_deref(), Some("game"));
+        assert_eq!(row.created_at.as_deref(), Some("2025-01-01T15:00:00Z"));
+
+        // フロントマターがなければファイル名をタイトルにし、作成日時は取り込んだ日時になる
+        let row = to_row("notes/雑談 回.txt", "本文".as_bytes(), tokyo).unwrap();
+        assert_eq!(row.title.as_deref(), Some("雑談 回"));
+        assert_eq!(row.description.as_deref(), Some("本文"));
+        assert_eq!(row.category, None);
+        assert_eq!(row.created_at, None);
+
+        assert_eq!(
+            to_row("a.md", b"\xff\xfe", tokyo).unwrap_err(),
+            "ファイルはUTF-8で指定してください"
+        );
+        assert_eq!(
+            to_row("a.md", b"---\ndate: someday\n---\n", tokyo).unwrap_err(),
+            "dateはRFC 3339形式またはYYYY-MM-DD形式で指定してください"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_markdown_files() {
+        let dir = temp_dir();
+        fs::create_dir_all(dir.join("2025")).unwrap();
+        fs::write(
+            dir.join("2025/a.md"),
+            "+++\ntitle = \"初配信\"\ndate = 2025-01-02T19:00:00+09:00\n+++\n本文",
+        )
+        .unwrap();
+        fs::write(dir.join("2025/b.txt"), "本文").unwrap();
+        fs::write(dir.join("2025/copy.md"), "---\ntitle: 初配信\n---\n本文\n").unwrap();
+        fs::write(dir.join("broken.md"), "---\ntitle: a\n").unwrap();
+        fs::write(dir.join("image.png"), b"binary").unwrap();
+
+        let rows = read_files(&dir, is_candidate)
+            .unwrap()
+            .into_iter()
+            .map(|(name, body)| ParsedRow {
+                line: 1,
+                row: to_row(&name, &body, Tz::UTC),
+            })
+            .collect();
+        let repo = MockStreamRepository::default();
+        let auth = AuthUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Owner,
+        };
+
+        let response = import_rows(&repo, &auth, rows, false).await.unwrap();
+        assert_eq!(response.created, Some(2));
+        assert_eq!(response.duplicates, Some(1));
+        assert_eq!(response.failed, Some(1));
+
+        let streams = repo.streams.lock().unwrap().clone();
+        assert_eq!(streams[0].title, "初配信");
+        assert_eq!(
+            streams[0].created_at,
+            "2025-01-02T10:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(streams[1].title, "b");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,394 @@
+//! サーバーを起動せずに実行する管理用のコマンド
+//!
+//! ```text
+//! backend-rust <COMMAND> --user-id <USER_ID> [--dry-run] <PATH>
+//! ```
+//!
+//! `PATH` には zip ファイルかディレクトリを指定する。読み込んだ行は HTTP のインポート
+//! （`handler::import`）と同じ規則で検証・重複の判定をして登録する。HTTP のインポートと異なり
+//! 行数の上限はなく、`MAX_IMPORT_ROWS` 行ごとに分けて登録する。
+
+mod markdown;
+mod takeout;
+
+use crate::{
+    auth::AuthUser,
+    config::Config,
+    handler::import::{import_chunk, summarize, KnownStreams, ParsedRow, MAX_IMPORT_ROWS},
+    model::User,
+    repository::{
+        self, StreamRepository, StreamRepositoryImpl, UserRepository, UserRepositoryImpl,
+    },
+    schema::ImportStreamsResponse,
+};
+use anyhow::{bail, Context, Result};
+use std::{
+    fs,
+    io::Read,
+    path::{Path, PathBuf},
+};
+use uuid::Uuid;
+
+/// サブコマンドを実行する（サブコマンドでなければ `None` を返し、サーバーを起動する）
+pub async fn run(args: &[String]) -> Option<Result<()>> {
+    let (command, args) = args.split_first()?;
+    let result = match command.as_str() {
+        takeout::COMMAND => takeout::run(args).await,
+        markdown::COMMAND => markdown::run(args).await,
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// 取り込みコマンドに共通の引数
+#[derive(Debug, PartialEq, Eq)]
+struct ImportArgs {
+    user_id: Uuid,
+    dry_run: bool,
+    path: PathBuf,
+}
+
+fn parse_import_args(command: &str, args: &[String]) -> Result<ImportArgs> {
+    let usage = || {
+        format!(
+            "usage: backend-rust {} --user-id <USER_ID> [--dry-run] <PATH>",
+            command
+        )
+    };
+    let mut user_id = None;
+    let mut dry_run = false;
+    let mut path = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--user-id" => {
+                let value = args.next().with_context(usage)?;
+                user_id = Some(
+                    Uuid::parse_str(value)
+                        .with_context(|| format!("invalid user id: {}", value))?,
+                );
+            }
+            "--dry-run" => dry_run = true,
+            value if value.starts_with("--") || path.is_some() => bail!(usage()),
+            value => path = Some(PathBuf::from(value)),
+        }
+    }
+    match (user_id, path) {
+        (Some(user_id), Some(path)) => Ok(ImportArgs {
+            user_id,
+            dry_run,
+            path,
+        }),
+        _ => bail!(usage()),
+    }
+}
+
+/// zip ファイルまたはディレクトリから、`is_candidate` に合う名前のファイルを名前順に読み込む
+///
+/// ファイル名は zip・ディレクトリの中での相対パス（区切りは `/`）。
+fn read_files(path: &Path, is_candidate: fn(&str) -> bool) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut files = if path.is_dir() {
+        let mut files = Vec::new();
+        read_dir(path, path, is_candidate, &mut files)?;
+        files
+    } else {
+        let file =
+            fs::File::open(path).with_context(|| format!("cannot open {}", path.display()))?;
+        read_zip(file, is_candidate)?
+    };
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
+fn read_dir(
+    root: &Path,
+    dir: &Path,
+    is_candidate: fn(&str) -> bool,
+    files: &mut Vec<(String, Vec<u8>)>,
+) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("cannot read {}", dir.display()))? {
+        let path = entry?.path();
+        if path.is_dir() {
+            read_dir(root, &path, is_candidate, files)?;
+            continue;
+        }
+        let name = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        if is_candidate(&name) {
+            let body =
+                fs::read(&path).with_context(|| format!("cannot read {}", path.display()))?;
+            files.push((name, body));
+        }
+    }
+    Ok(())
+}
+
+fn read_zip<R: std::io::Read + std::io::Seek>(
+    reader: R,
+    is_candidate: fn(&str) -> bool,
+) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut archive = zip::ZipArchive::new(reader).context("not a zip file or directory")?;
+    let mut files = Vec::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        if !file.is_file() || !is_candidate(file.name()) {
+            continue;
+        }
+        let name = file.name().to_string();
+        let mut body = Vec::new();
+        file.read_to_end(&mut body)
+            .with_context(|| format!("cannot read {}", name))?;
+        files.push((name, body));
+    }
+    Ok(files)
+}
+
+/// 取り込み先のユーザー（削除済みのユーザーには取り込めない）とリポジトリを用意する
+async fn connect(user_id: Uuid) -> Result<(StreamRepositoryImpl, User)> {
+    let config = Config::from_env()?;
+    let pool = repository::db::create_pool(&config.database_url).await?;
+    let user = UserRepositoryImpl::new(pool.clone())
+        .find_by_id(user_id)
+        .await?
+        .filter(|user| user.deleted_at.is_none())
+        .with_context(|| format!("user not found: {}", user_id))?;
+    Ok((StreamRepositoryImpl::new(pool), user))
+}
+
+/// ファイルごとに読み込んだ行をまとめて取り込み、結果を表示する
+async fn import(
+    repo: &dyn StreamRepository,
+    user: &User,
+    args: &ImportArgs,
+    files: Vec<(String, Vec<ParsedRow>)>,
+) -> Result<ImportStreamsResponse> {
+    // 結果は行の順に並ぶため、元のファイル・行番号と突き合わせて表示する
+    let mut rows = Vec::new();
+    let mut sources = Vec::new();
+    for (name, parsed) in files {
+        for ParsedRow { line, row } in parsed {
+            sources.push((name.clone(), line));
+            rows.push(ParsedRow {
+                line: rows.len() + 1,
+                row,
+            });
+        }
+    }
+    if rows.is_empty() {
+        bail!("nothing to import in {}", args.path.display());
+    }
+
+    let auth = AuthUser {
+        user_id: user.user_id,
+        role: user.role,
+    };
+    let error = |e: crate::error::AppError| anyhow::anyhow!(e.into_parts().2);
+
+    // 1回に登録する行数を抑えるため分けて登録する（重複はすべての行を通して判定する）
+    let mut known = KnownStreams::load(repo, user.user_id)
+        .await
+        .map_err(error)?;
+    let mut items = Vec::with_capacity(rows.len());
+    let mut rows = rows.into_iter().peekable();
+    while rows.peek().is_some() {
+        let chunk = rows.by_ref().take(MAX_IMPORT_ROWS).collect();
+        let results = import_chunk(repo, &auth, chunk, args.dry_run, &mut known)
+            .await
+            .map_err(error)?;
+        items.extend(results);
+    }
+    let response = summarize(args.dry_run, items);
+    print_report(args, &sources, &response);
+
+    Ok(response)
+}
+
+/// 取り込み結果をファイル・行ごとに表示する（登録した行は件数のみ）
+fn print_report(args: &ImportArgs, sources: &[(String, usize)], response: &ImportStreamsResponse) {
+    for ((file, line), item) in sources.iter().zip(response.items.iter().flatten()) {
+        if item.status == Some(201) {
+            continue;
+        }
+        let message = item
+            .error
+            .as_ref()
+            .and_then(|e| e.message.as_deref())
+            .unwrap_or_default();
+        match &item.stream_id {
+            Some(stream_id) => println!(
+                "{}:{}: {} {} (stream {})",
+                file,
+                line,
+                item.status.unwrap_or_default(),
+                message,
+                stream_id
+            ),
+            None => println!(
+                "{}:{}: {} {}",
+                file,
+                line,
+                item.status.unwrap_or_default(),
+                message
+            ),
+        }
+    }
+    println!(
+        "{}: created {}, duplicates {}, failed {}{}",
+        args.path.display(),
+        response.created.unwrap_or_default(),
+        response.duplicates.unwrap_or_default(),
+        response.failed.unwrap_or_default(),
+        if args.dry_run { " (dry run)" } else { "" }
+    );
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// テストごとに空の一時ディレクトリを作る
+    pub(crate) fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cli-test-{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_parse_import_args() {
+        let user_id = Uuid::new_v4();
+        let args = |values: &[&str]| {
+            parse_import_args(
+                "import",
+                &values.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+            )
+        };
+
+        assert_eq!(
+            args(&[
+                "--user-id",
+                &user_id.to_string(),
+                "--dry-run",
+                "takeout.zip"
+            ])
+            .unwrap(),
+            ImportArgs {
+                user_id,
+                dry_run: true,
+                path: PathBuf::from("takeout.zip"),
+            }
+        );
+        assert!(
+            !args(&["takeout", "--user-id", &user_id.to_string()])
+                .unwrap()
+                .dry_run
+        );
+
+        assert_eq!(
+            args(&["takeout.zip"]).unwrap_err().to_string(),
+            "usage: backend-rust import --user-id <USER_ID> [--dry-run] <PATH>"
+        );
+        assert!(args(&["--user-id", "invalid", "takeout.zip"]).is_err());
+        assert!(args(&["--user-id", &user_id.to_string()]).is_err());
+        assert!(args(&["--user-id", &user_id.to_string(), "a", "b"]).is_err());
+        assert!(args(&["--user-id", &user_id.to_string(), "--force", "a"]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_unknown_command() {
+        assert!(run(&[]).await.is_none());
+        assert!(run(&["serve".to_string()]).await.is_none());
+        assert!(run(&[takeout::COMMAND.to_string()]).await.unwrap().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_import_more_rows_than_http_limit() {
+        use crate::{model::Role, repository::mock::MockStreamRepository, schema::ImportStreamRow};
+
+        let row = |i: usize| ParsedRow {
+            line: i + 1,
+            row: Ok(ImportStreamRow {
+                title: Some(format!("配信 {}", i)),
+                ..ImportStreamRow::new()
+            }),
+        };
+        // 最後の行は最初の行と同じ内容（別の分割で登録する行とも重複を判定する）
+        let files = || {
+            let mut rows: Vec<ParsedRow> = (0..MAX_IMPORT_ROWS + 1).map(row).collect();
+            rows.push(row(0));
+            vec![("a.jsonl".to_string(), rows)]
+        };
+        let user = User {
+            role: Role::Owner,
+            ..User::new(Uuid::new_v4())
+        };
+        let args = |dry_run| ImportArgs {
+            user_id: user.user_id,
+            dry_run,
+            path: PathBuf::from("a.jsonl"),
+        };
+
+        let repo = MockStreamRepository::default();
+        let response = import(&repo, &user, &args(true), files()).await.unwrap();
+        assert_eq!(response.created, Some(MAX_IMPORT_ROWS as i32 + 1));
+        assert_eq!(response.duplicates, Some(1));
+        assert!(repo.streams.lock().unwrap().is_empty());
+
+        let response = import(&repo, &user, &args(false), files()).await.unwrap();
+        assert_eq!(response.created, Some(MAX_IMPORT_ROWS as i32 + 1));
+        assert_eq!(response.duplicates, Some(1));
+        assert_eq!(repo.streams.lock().unwrap().len(), MAX_IMPORT_ROWS + 1);
+    }
+
+    #[test]
+    fn test_read_files() {
+        fn is_csv(name: &str) -> bool {
+            name.ends_with(".csv")
+        }
+
+        let dir = temp_dir();
+        fs::create_dir_all(dir.join("b/c")).unwrap();
+        fs::write(dir.join("b/c/videos.csv"), "a,b\n").unwrap();
+        fs::write(dir.join("a.csv"), "c,d\n").unwrap();
+        fs::write(dir.join("b/video.mp4"), b"binary").unwrap();
+
+        let files = read_files(&dir, is_csv).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                ("a.csv".to_string(), b"c,d\n".to_vec()),
+                ("b/c/videos.csv".to_string(), b"a,b\n".to_vec()),
+            ]
+        );
+
+        let zip_path = dir.join("archive.zip");
+        let mut writer = zip::ZipWriter::new(fs::File::create(&zip_path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, body) in [
+            ("b/video.mp4", "binary"),
+            ("b/videos.csv", "a,b\n"),
+            ("a.csv", "c,d\n"),
+        ] {
+            writer.start_file(name, options).unwrap();
+            writer.write_all(body.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let files = read_files(&zip_path, is_csv).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                ("a.csv".to_string(), b"c,d\n".to_vec()),
+                ("b/videos.csv".to_string(), b"a,b\n".to_vec()),
+            ]
+        );
+
+        // zip でもディレクトリでもないパスはエラー
+        assert!(read_files(&dir.join("a.csv"), is_csv).is_err());
+        assert!(read_files(&dir.join("missing"), is_csv).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,294 @@
+//! Google Takeout（YouTube）の動画メタデータから配信情報を取り込むコマンド
+//!
+//! Takeout の zip ファイルか展開したディレクトリから、動画メタデータの CSV（`videos.csv`）と
+//! API 形式の JSON（`*videos.json`）を読み込む。
+
+use super::{connect, import, parse_import_args, read_files};
+use crate::{
+    handler::import::ParsedRow,
+    schema::{ImportStreamRow, VisibilitySchema as Visibility},
+};
+use anyhow::Result;
+use chrono::{DateTime, NaiveDateTime, SecondsFormat, Utc};
+use serde_json::Value;
+
+/// サブコマンド名
+pub const COMMAND: &str = "import-youtube-takeout";
+
+/// 英語で書き出した Takeout の動画メタデータ CSV の列名
+const COLUMN_VIDEO_ID: &str = "Video ID";
+const COLUMN_TITLE: &str = "Video Title (Original)";
+const COLUMN_DESCRIPTION: &str = "Video Description (Original)";
+const COLUMN_PRIVACY: &str = "Privacy";
+const COLUMN_PUBLISHED_AT: &str = "Video Publish Timestamp";
+const COLUMN_CREATED_AT: &str = "Video Create Timestamp";
+
+/// 取り込み対象になりうるファイル（Takeout には動画そのものも含まれるため、名前で絞り込む）
+fn is_candidate(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.ends_with(".csv") || name.ends_with("videos.json")
+}
+
+/// Takeout の日時（RFC 3339、または `2021-01-02 03:04:05 UTC`）を RFC 3339 に揃える
+fn parse_timestamp(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&Utc))
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S UTC").map(|t| t.and_utc())
+        })
+        .map(|t| t.to_rfc3339_opts(SecondsFormat::Secs, true))
+        .map_err(|_| format!("公開日時を読み込めません（{}）", value))
+}
+
+/// YouTube の公開設定を配信の公開範囲に対応付ける
+fn parse_privacy(value: &str) -> Result<Visibility, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "public" => Ok(Visibility::Published),
+        "unlisted" => Ok(Visibility::Unlisted),
+        "private" => Ok(Visibility::Private),
+        _ => Err(format!("公開設定を読み込めません（{}）", value)),
+    }
+}
+
+/// 動画1件分の値から取り込む行を組み立てる（公開前の動画は作成日時を使う）
+fn to_row(
+    video_id: &str,
+    title: &str,
+    description: &str,
+    privacy: Option<&str>,
+    published_at: Option<&str>,
+    created_at: Option<&str>,
+) -> Result<ImportStreamRow, String> {
+    let timestamp = published_at
+        .filter(|v| !v.trim().is_empty())
+        .or(created_at.filter(|v| !v.trim().is_empty()))
+        .ok_or_else(|| "公開日時がありません".to_string())?;
+    Ok(ImportStreamRow {
+        title: Some(title.to_string()),
+        description: Some(description.to_string()),
+        visibility: privacy
+            .filter(|v| !v.trim().is_empty())
+            .map(parse_privacy)
+            .transpose()?,
+        created_at: Some(parse_timestamp(timestamp)?),
+        youtube_video_id: Some(video_id.to_string()),
+        ..ImportStreamRow::new()
+    })
+}
+
+/// 動画メタデータの CSV を読み込む（動画ID・タイトルの列がない CSV は対象外として `None` を返す）
+fn parse_videos_csv(body: &[u8]) -> Result<Option<Vec<ParsedRow>>, String> {
+    let body = body.strip_prefix("\u{feff}".as_bytes()).unwrap_or(body);
+    let mut reader = csv::Reader::from_reader(body);
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("ヘッダー行を読み込めません（{}）", e))?
+        .clone();
+    let column = |name: &str| headers.iter().position(|h| h.trim() == name);
+    let (Some(video_id), Some(title)) = (column(COLUMN_VIDEO_ID), column(COLUMN_TITLE)) else {
+        return Ok(None);
+    };
+    let description = column(COLUMN_DESCRIPTION);
+    let privacy = column(COLUMN_PRIVACY);
+    let published_at = column(COLUMN_PUBLISHED_AT);
+    let created_at = column(COLUMN_CREATED_AT);
+
+    let mut rows = Vec::new();
+    let mut record = csv::StringRecord::new();
+    loop {
+        let line = reader.position().line() as usize;
+        let row = match reader.read_record(&mut record) {
+            Ok(false) => break,
+            Ok(true) => {
+                let get = |i: Option<usize>| i.and_then(|i| record.get(i));
+                to_row(
+                    get(Some(video_id)).unwrap_or_default(),
+                    get(Some(title)).unwrap_or_default(),
+                    get(description).unwrap_or_default(),
+                    get(privacy),
+                    get(published_at),
+                    get(created_at),
+                )
+            }
+            Err(e) => Err(format!("CSVの行を読み込めません（{}）", e)),
+        };
+        rows.push(ParsedRow { line, row });
+    }
+    Ok(Some(rows))
+}
+
+/// API 形式（`videos.list` のリソースの配列）の JSON を読み込む（行番号は配列の何件目か）
+fn parse_videos_json(body: &[u8]) -> Result<Vec<ParsedRow>, String> {
+    let value: Value =
+        serde_json::from_slice(body).map_err(|e| format!("JSONを読み込めません（{}）", e))?;
+    let videos = match value {
+        Value::Array(videos) => videos,
+        Value::Object(mut object) => match object.remove("items") {
+            Some(Value::Array(videos)) => videos,
+            _ => return Err("動画の一覧がありません".to_string()),
+        },
+        _ => return Err("動画の一覧がありません".to_string()),
+    };
+
+    Ok(videos
+        .iter()
+        .enumerate()
+        .map(|(i, video)| {
+            let text = |pointer: &str| video.pointer(pointer).and_then(Value::as_str);
+            let row = match text("/contentDetails/videoId").or(text("/id")) {
+                Some(video_id) => to_row(
+                    video_id,
+                    text("/snippet/title").unwrap_or_default(),
+                    text("/snippet/description").unwrap_or_default(),
+                    text("/status/privacyStatus"),
+                    text("/snippet/publishedAt"),
+                    None,
+                ),
+                None => Err("動画IDがありません".to_string()),
+            };
+            ParsedRow { line: i + 1, row }
+        })
+        .collect())
+}
+
+/// ファイルの中身から取り込む行を読み込む（動画メタデータでないファイルは `None`）
+fn parse_file(name: &str, body: &[u8]) -> Result<Option<Vec<ParsedRow>>, String> {
+    if name.to_ascii_lowercase().ends_with(".csv") {
+        parse_videos_csv(body)
+    } else {
+        parse_videos_json(body).map(Some)
+    }
+}
+
+/// コマンドライン引数（サブコマンド名より後ろ）を受け取って取り込みを実行する
+pub async fn run(args: &[String]) -> Result<()> {
+    let args = parse_import_args(COMMAND, args)?;
+
+    // 読めないファイルはその場で報告し、残りのファイルは取り込む
+    let mut files = Vec::new();
+    for (name, body) in read_files(&args.path, is_candidate)? {
+        match parse_file(&name, &body) {
+            Ok(Some(rows)) => files.push((name, rows)),
+            Ok(None) => {}
+            Err(message) => println!("{}: {}", name, message),
+        }
+    }
+
+    let (repo, user) = connect(args.user_id).await?;
+    import(&repo, &user, &args, files).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        auth::AuthUser,
+        handler::import::import_rows,
+        model::Role,
+        repository::{mock::MockStreamRepository, StreamRepository},
+    };
+    use uuid::Uuid;
+
+    const VIDEOS_CSV: &str = "Video ID,Approx Duration (ms),Video Audio Language,Video Category,Video Description (Original),Channel ID,Video Title (Original),Privacy,Video State,Video Create Timestamp,Video Publish Timestamp
+dQw4w9WgXcQ,3600000,ja,Gaming,\"説明
+2行目\",UC000,初配信,Public,Processed,2021-01-01T00:00:00+00:00,2021-01-02T03:04:05+09:00
+aaaaaaaaaaa,60000,ja,Gaming,,UC000,限定公開,Unlisted,Processed,2021-02-01 10:00:00 UTC,
+bbbbbbbbbbb,60000,ja,Gaming,,UC000,日時なし,Private,Processed,,
+ccccccccccc,60000,ja,Gaming,,UC000,不明,Members,Processed,2021-03-01T00:00:00+00:00,
+";
+
+    const VIDEOS_JSON: &str = r#"[
+  {"id": "dQw4w9WgXcQ", "snippet": {"title": "初配信", "description": "説明", "publishedAt": "2021-01-01T18:04:05Z"}, "status": {"privacyStatus": "public"}},
+  {"contentDetails": {"videoId": "ddddddddddd"}, "snippet": {"title": "JSON", "publishedAt": "2022-05-06T07:08:09Z"}},
+  {"snippet": {"title": "IDなし"}}
+]"#;
+
+    fn rows(parsed: Vec<ParsedRow>) -> Vec<(usize, Result<ImportStreamRow, String>)> {
+        parsed.into_iter().map(|r| (r.line, r.row)).collect()
+    }
+
+    #[test]
+    fn test_parse_videos_csv() {
+        let parsed = rows(parse_videos_csv(VIDEOS_CSV.as_bytes()).unwrap().unwrap());
+
+        assert_eq!(
+            parsed.iter().map(|(line, _)| *line).collect::<Vec<_>>(),
+            vec![2, 4, 5, 6]
+        );
+        let first = parsed[0].1.as_ref().unwrap();
+        assert_eq!(first.title.as_deref(), Some("初配信"));
+        assert_eq!(first.description.as_deref(), Some("説明\n2行目"));
+        assert_eq!(first.visibility, Some(Visibility::Published));
+        assert_eq!(first.created_at.as_deref(), Some("2021-01-01T18:04:05Z"));
+        assert_eq!(first.youtube_video_id.as_deref(), Some("dQw4w9WgXcQ"));
+
+        // 公開前の動画は作成日時を使う
+        let second = parsed[1].1.as_ref().unwrap();
+        assert_eq!(second.visibility, Some(Visibility::Unlisted));
+        assert_eq!(second.created_at.as_deref(), Some("2021-02-01T10:00:00Z"));
+
+        assert_eq!(parsed[2].1, Err("公開日時がありません".to_string()));
+        assert_eq!(
+            parsed[3].1,
+            Err("公開設定を読み込めません（Members）".to_string())
+        );
+
+        // 動画メタデータ以外の CSV は対象外
+        assert!(parse_videos_csv("Playlist ID,Title\nPL1,a\n".as_bytes())
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_videos_json() {
+        let parsed = rows(parse_videos_json(VIDEOS_JSON.as_bytes()).unwrap());
+
+        let first = parsed[0].1.as_ref().unwrap();
+        assert_eq!(first.title.as_deref(), Some("初配信"));
+        assert_eq!(first.created_at.as_deref(), Some("2021-01-01T18:04:05Z"));
+        assert_eq!(first.visibility, Some(Visibility::Published));
+        let second = parsed[1].1.as_ref().unwrap();
+        assert_eq!(second.youtube_video_id.as_deref(), Some("ddddddddddd"));
+        assert_eq!(second.description.as_deref(), Some(""));
+        assert_eq!(second.visibility, None);
+        assert_eq!(parsed[2], (3, Err("動画IDがありません".to_string())));
+
+        let wrapped = format!(r#"{{"items": {}}}"#, VIDEOS_JSON);
+        assert_eq!(parse_videos_json(wrapped.as_bytes()).unwrap().len(), 3);
+        assert!(parse_videos_json(b"{}").is_err());
+        assert!(parse_videos_json(b"not json").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_import_takeout_rows() {
+        let repo = MockStreamRepository::default();
+        let auth = AuthUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Owner,
+        };
+        let mut rows = parse_videos_csv(VIDEOS_CSV.as_bytes()).unwrap().unwrap();
+        rows.extend(parse_videos_json(VIDEOS_JSON.as_bytes()).unwrap());
+
+        let response = import_rows(&repo, &auth, rows, false).await.unwrap();
+        assert_eq!(response.created, Some(3));
+        assert_eq!(response.duplicates, Some(1));
+        assert_eq!(response.failed, Some(3));
+
+        let streams = repo.streams.lock().unwrap().clone();
+        assert_eq!(
+            streams[0].created_at,
+            "2021-01-01T18:04:05Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            repo.find_youtube_videos(auth.user_id)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|v| v.video_id)
+                .collect::<Vec<_>>(),
+            vec!["dQw4w9WgXcQ", "aaaaaaaaaaa", "ddddddddddd"]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! `POST /api/v2/streams:batch` のようなカスタムメソッドのパスの書き換え
+//!
+//! axum のルーターは `:` をパスパラメーターの始まりとして扱うため、`{collection}:{verb}` 形式の
+//! パスはそのままでは登録できない。ルーティングの前に `/_custom` 以下の内部パスへ書き換える。
+
+use axum::{extract::Request, http::Uri};
+
+/// 書き換え後のパスの接頭辞
+const PREFIX: &str = "/_custom";
+
+/// ルーターに登録する内部パス（`path("/api/v2/streams", "batch")` は `/_custom/api/v2/streams/batch`）
+pub fn path(collection: &str, verb: &str) -> String {
+    format!("{}{}/{}", PREFIX, collection, verb)
+}
+
+/// 最後のセグメントが `{collection}:{verb}` の場合のみ書き換えたパスを返す
+fn rewrite_path(path: &str) -> Option<String> {
+    let (parent, last) = path.rsplit_once('/')?;
+    let (collection, verb) = last.split_once(':')?;
+    if collection.is_empty() || verb.is_empty() {
+        return None;
+    }
+    Some(self::path(&format!("{}/{}", parent, collection), verb))
+}
+
+/// ルーターより外側で適用する（`Router::layer` はルーティングの後に実行されるため）
+pub async fn rewrite(mut req: Request) -> Request {
+    if let Some(path) = rewrite_path(req.uri().path()) {
+        let path_and_query = match req.uri().query() {
+            Some(query) => format!("{}?{}", path, query),
+            None => path,
+        };
+        let mut parts = req.uri().clone().into_parts();
+        if let Ok(path_and_query) = path_and_query.parse() {
+            parts.path_and_query = Some(path_and_query);
+            if let Ok(uri) = Uri::from_parts(parts) {
+                *req.uri_mut() = uri;
+            }
+        }
+    }
+    req
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_path() {
+        assert_eq!(
+            rewrite_path("/api/v2/streams:batch").as_deref(),
+            Some("/_custom/api/v2/streams/batch")
+        );
+        assert_eq!(
+            rewrite_path("/api/v2/streams:batchDelete").as_deref(),
+            Some("/_custom/api/v2/streams/batchDelete")
+        );
+        assert_eq!(rewrite_path("/api/v2/streams"), None);
+        assert_eq!(rewrite_path("/api/v2/streams/:"), None);
+        assert_eq!(rewrite_path("/api/v2/streams:"), None);
+    }
+
+    #[tokio::test]
+    async fn test_rewrite_keeps_query() {
+        let req = Request::builder()
+            .uri("/api/v2/streams:batch?mode=partial")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let req = rewrite(req).await;
+        assert_eq!(
+            req.uri().to_string(),
+            "/_custom/api/v2/streams/batch?mode=partial"
+        );
+    }
+}
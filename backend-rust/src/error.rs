@@ -13,9 +13,27 @@ pub enum AppError {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Not found: {0}")]
     NotFound(String),
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
+    #[error("Unprocessable entity: {0}")]
+    UnprocessableEntity(String),
+
+    #[error("Precondition required: {0}")]
+    PreconditionRequired(String),
+
     #[error("Internal error: {0}")]
     Internal(#[from] anyhow::Error),
 }
@@ -26,9 +44,10 @@ struct ErrorResponse {
     message: String,
 }
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let (status, error_code, message) = match self {
+impl AppError {
+    /// ステータスコード・エラーコード・メッセージに分解する（内部エラーの詳細はログにのみ出力する）
+    pub fn into_parts(self) -> (StatusCode, String, String) {
+        match self {
             AppError::Database(ref e) => {
                 tracing::error!("Database error: {:?}", e);
                 (
@@ -42,11 +61,41 @@ impl IntoResponse for AppError {
                 "validation_error".to_string(),
                 msg.clone(),
             ),
+            AppError::Unauthorized(ref msg) => (
+                StatusCode::UNAUTHORIZED,
+                "unauthorized".to_string(),
+                msg.clone(),
+            ),
+            AppError::Forbidden(ref msg) => (
+                StatusCode::FORBIDDEN,
+                "forbidden".to_string(),
+                msg.clone(),
+            ),
             AppError::NotFound(ref msg) => (
                 StatusCode::NOT_FOUND,
                 "not_found".to_string(),
                 msg.clone(),
             ),
+            AppError::Conflict(ref msg) => (
+                StatusCode::CONFLICT,
+                "conflict".to_string(),
+                msg.clone(),
+            ),
+            AppError::PreconditionFailed(ref msg) => (
+                StatusCode::PRECONDITION_FAILED,
+                "precondition_failed".to_string(),
+                msg.clone(),
+            ),
+            AppError::UnprocessableEntity(ref msg) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "unprocessable_entity".to_string(),
+                msg.clone(),
+            ),
+            AppError::PreconditionRequired(ref msg) => (
+                StatusCode::PRECONDITION_REQUIRED,
+                "precondition_required".to_string(),
+                msg.clone(),
+            ),
             AppError::Internal(ref e) => {
                 tracing::error!("Internal error: {:?}", e);
                 (
@@ -55,7 +104,13 @@ impl IntoResponse for AppError {
                     "サーバー内部でエラーが発生しました".to_string(),
                 )
             }
-        };
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let (status, error_code, message) = self.into_parts();
 
         let body = Json(ErrorResponse {
             error: error_code,
@@ -78,6 +133,20 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[test]
+    fn test_unauthorized_error_response() {
+        let error = AppError::Unauthorized("Authentication required".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn test_forbidden_error_response() {
+        let error = AppError::Forbidden("Not allowed".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
     #[test]
     fn test_not_found_error_response() {
         let error = AppError::NotFound("Resource not found".to_string());
@@ -85,6 +154,34 @@ mod tests {
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
+    #[test]
+    fn test_conflict_error_response() {
+        let error = AppError::Conflict("Already in use".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn test_precondition_error_responses() {
+        let error = AppError::PreconditionFailed("Stale ETag".to_string());
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::PRECONDITION_FAILED
+        );
+        let error = AppError::PreconditionRequired("If-Match required".to_string());
+        assert_eq!(
+            error.into_response().status(),
+            StatusCode::PRECONDITION_REQUIRED
+        );
+    }
+
+    #[test]
+    fn test_unprocessable_entity_error_response() {
+        let error = AppError::UnprocessableEntity("Idempotency key reused".to_string());
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[test]
     fn test_database_error_response() {
         let error = AppError::Database(sqlx::Error::RowNotFound);
@@ -0,0 +1,232 @@
+//! 管理者向けの操作
+
+use super::{stream, user};
+use crate::{
+    auth::{
+        permission::{self, StreamAction},
+        AuthUser,
+    },
+    error::AppError,
+    model::{PrivateScope, Role, StreamFilter, Visibility},
+    repository::{StreamRepository, UserRepository},
+    schema::{RoleRequest, StreamListResponse, UpdateUserRoleRequest, UserResponse},
+};
+use axum::{
+    body::Bytes,
+    extract::{OriginalUri, Path, State},
+    http::HeaderMap,
+    Json,
+};
+use axum_extra::extract::Query;
+use std::sync::Arc;
+
+/// 下書き・非公開・削除済みを含む全ユーザーの配信の一覧
+pub async fn get_all_streams(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    auth: AuthUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<stream::ListStreamsQuery>,
+) -> Result<(HeaderMap, Json<StreamListResponse>), AppError> {
+    permission::authorize(Some(&auth), StreamAction::ListAll, None, None)?;
+
+    let mut filter = StreamFilter {
+        private_scope: PrivateScope::All,
+        include_deleted: true,
+        ..stream::parse_filter(&query)?
+    };
+    if filter.visibilities.is_empty() {
+        filter.visibilities = Visibility::ALL.to_vec();
+    }
+
+    stream::list_streams(repo.as_ref(), &uri, &query, &filter).await
+}
+
+pub async fn update_user_role(
+    State(repo): State<Arc<dyn UserRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+    body: Bytes,
+) -> Result<Json<UserResponse>, AppError> {
+    if auth.role != Role::Admin {
+        return Err(AppError::Forbidden("管理者のみ操作できます".to_string()));
+    }
+    let id = user::parse_user_id(&user_id)?;
+    // 管理者が自分の権限を外して管理者がいなくなることを防ぐ
+    if id == auth.user_id {
+        return Err(AppError::Validation(
+            "自分自身の役割は変更できません".to_string(),
+        ));
+    }
+
+    let value: serde_json::Value = serde_json::from_slice(&body).map_err(|_| {
+        AppError::Validation("リクエストボディの形式が正しくありません".to_string())
+    })?;
+    let req: UpdateUserRoleRequest = serde_json::from_value(value).map_err(|_| {
+        AppError::Validation(
+            "roleはowner, moderator, adminのいずれかで指定してください".to_string(),
+        )
+    })?;
+    let role = match req.role {
+        RoleRequest::Owner => Role::Owner,
+        RoleRequest::Moderator => Role::Moderator,
+        RoleRequest::Admin => Role::Admin,
+    };
+
+    let updated = repo
+        .update_role(id, role)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok(Json(user::to_response(updated)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Stream;
+    use crate::repository::mock::{MockStreamRepository, MockUserRepository};
+    use axum::http::Uri;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn auth(role: Role) -> AuthUser {
+        AuthUser {
+            user_id: Uuid::new_v4(),
+            role,
+        }
+    }
+
+    fn test_stream(user_id: Uuid, visibility: Visibility) -> Stream {
+        Stream {
+            stream_id: Uuid::new_v4(),
+            user_id,
+            title: "title".to_string(),
+            description: "desc".to_string(),
+            category: "".to_string(),
+            visibility,
+            version: 1,
+            created_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    async fn list(
+        repo: &Arc<MockStreamRepository>,
+        auth: AuthUser,
+    ) -> Result<StreamListResponse, AppError> {
+        get_all_streams(
+            State(repo.clone()),
+            auth,
+            OriginalUri(Uri::from_static("/api/v2/admin/streams")),
+            Query(stream::ListStreamsQuery::default()),
+        )
+        .await
+        .map(|(_, Json(response))| response)
+    }
+
+    #[tokio::test]
+    async fn test_get_all_streams() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let owner_id = Uuid::new_v4();
+        for visibility in [
+            Visibility::Published,
+            Visibility::Private,
+            Visibility::Draft,
+        ] {
+            repo.create(&test_stream(owner_id, visibility))
+                .await
+                .unwrap();
+        }
+        let deleted = repo
+            .create(&test_stream(owner_id, Visibility::Published))
+            .await
+            .unwrap();
+        repo.delete(deleted.stream_id, deleted.version)
+            .await
+            .unwrap();
+
+        let response = list(&repo, auth(Role::Admin)).await.unwrap();
+        assert_eq!(response.total, Some(4));
+        let items = response.items.unwrap();
+        let deleted_item = items
+            .iter()
+            .find(|s| s.stream_id == Some(deleted.stream_id.to_string()))
+            .unwrap();
+        assert!(deleted_item.deleted_at.is_some());
+
+        for role in [Role::Owner, Role::Moderator] {
+            match list(&repo, auth(role)).await.unwrap_err() {
+                AppError::Forbidden(msg) => assert_eq!(msg, "管理者のみ操作できます"),
+                _ => panic!("Unexpected error type"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_user_role() {
+        let target_id = Uuid::new_v4();
+        let repo = Arc::new(MockUserRepository::with_users(&[target_id]));
+        let admin = auth(Role::Admin);
+        let body = || Bytes::from_static(br#"{"role":"moderator"}"#);
+
+        let Json(updated) = update_user_role(
+            State(repo.clone()),
+            admin,
+            Path(target_id.to_string()),
+            body(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.role, Some(crate::schema::RoleResponse::Moderator));
+        assert_eq!(repo.users.lock().unwrap()[0].role, Role::Moderator);
+
+        // 管理者以外
+        let result = update_user_role(
+            State(repo.clone()),
+            auth(Role::Moderator),
+            Path(target_id.to_string()),
+            body(),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Forbidden(msg) => assert_eq!(msg, "管理者のみ操作できます"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 自分自身
+        let result = update_user_role(
+            State(repo.clone()),
+            admin,
+            Path(admin.user_id.to_string()),
+            body(),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "自分自身の役割は変更できません"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 不正な役割
+        let result = update_user_role(
+            State(repo.clone()),
+            admin,
+            Path(target_id.to_string()),
+            Bytes::from_static(br#"{"role":"superuser"}"#),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(
+                    msg,
+                    "roleはowner, moderator, adminのいずれかで指定してください"
+                )
+            }
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 存在しないユーザー
+        let result =
+            update_user_role(State(repo), admin, Path(Uuid::new_v4().to_string()), body()).await;
+        assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+    }
+}
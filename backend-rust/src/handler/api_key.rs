@@ -0,0 +1,211 @@
+use crate::{
+    auth::{api_key, AuthUser},
+    error::AppError,
+    model::ApiKey,
+    repository::ApiKeyRepository,
+    schema::{ApiKeyListResponse, ApiKeyResponse, IssueApiKeyRequest},
+};
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn parse_user_id(user_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(user_id).map_err(|_| AppError::Validation("Invalid userId format".to_string()))
+}
+
+/// 平文のキーは発行時のみ `token` として渡す
+fn to_response(api_key: ApiKey, token: Option<String>) -> ApiKeyResponse {
+    ApiKeyResponse {
+        key_id: Some(api_key.key_id.to_string()),
+        name: Some(api_key.name),
+        api_key: token,
+        created_at: Some(api_key.created_at.to_rfc3339()),
+    }
+}
+
+/// キーを発行して保存し、平文のキーを返す（ユーザー登録時の初回キーの発行にも使う）
+pub(crate) async fn issue(
+    repo: &dyn ApiKeyRepository,
+    user_id: Uuid,
+    name: String,
+) -> Result<(ApiKey, String), AppError> {
+    let (api_key, token) = api_key::generate(user_id, name);
+    let created = repo.create(&api_key).await?;
+
+    Ok((created, token))
+}
+
+pub async fn issue_api_key(
+    State(repo): State<Arc<dyn ApiKeyRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<ApiKeyResponse>), AppError> {
+    let user_id = parse_user_id(&user_id)?;
+    auth.ensure_user(user_id)?;
+
+    let req: IssueApiKeyRequest = if body.is_empty() {
+        IssueApiKeyRequest::default()
+    } else {
+        serde_json::from_slice(&body).map_err(|_| {
+            AppError::Validation("リクエストボディの形式が正しくありません".to_string())
+        })?
+    };
+    let name = req.name.unwrap_or_default();
+    if name.chars().count() > 50 {
+        return Err(AppError::Validation(
+            "キーの名前は50文字以内で入力してください".to_string(),
+        ));
+    }
+
+    let (created, token) = issue(repo.as_ref(), user_id, name).await?;
+
+    Ok((StatusCode::CREATED, Json(to_response(created, Some(token)))))
+}
+
+pub async fn get_api_keys(
+    State(repo): State<Arc<dyn ApiKeyRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+) -> Result<Json<ApiKeyListResponse>, AppError> {
+    let user_id = parse_user_id(&user_id)?;
+    auth.ensure_user(user_id)?;
+
+    let api_keys = repo.find_active_by_user(user_id).await?;
+
+    Ok(Json(ApiKeyListResponse {
+        items: Some(api_keys.into_iter().map(|k| to_response(k, None)).collect()),
+    }))
+}
+
+pub async fn revoke_api_key(
+    State(repo): State<Arc<dyn ApiKeyRepository>>,
+    auth: AuthUser,
+    Path((user_id, key_id)): Path<(String, String)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = parse_user_id(&user_id)?;
+    auth.ensure_user(user_id)?;
+    let key_id = Uuid::parse_str(&key_id)
+        .map_err(|_| AppError::Validation("Invalid keyId format".to_string()))?;
+
+    if !repo.revoke(user_id, key_id).await? {
+        return Err(AppError::NotFound("API key not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Role;
+    use crate::repository::mock::MockApiKeyRepository;
+
+    #[tokio::test]
+    async fn test_issue_list_and_revoke() {
+        let repo = Arc::new(MockApiKeyRepository::default());
+        let user_id = Uuid::new_v4();
+        let auth = AuthUser {
+            user_id,
+            role: Role::Owner,
+        };
+
+        let (status, Json(issued)) = issue_api_key(
+            State(repo.clone()),
+            auth,
+            Path(user_id.to_string()),
+            Bytes::from_static(br#"{"name":"OBS"}"#),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(issued.name.as_deref(), Some("OBS"));
+        assert!(issued.api_key.as_deref().unwrap().starts_with("sk_"));
+
+        // 一覧にはキーの値を含めない
+        let Json(list) = get_api_keys(State(repo.clone()), auth, Path(user_id.to_string()))
+            .await
+            .unwrap();
+        let items = list.items.unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].key_id, issued.key_id);
+        assert!(items[0].api_key.is_none());
+
+        let key_id = issued.key_id.unwrap();
+        let status = revoke_api_key(
+            State(repo.clone()),
+            auth,
+            Path((user_id.to_string(), key_id.clone())),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        // 失効済みのキー
+        let result = revoke_api_key(State(repo), auth, Path((user_id.to_string(), key_id))).await;
+        match result.unwrap_err() {
+            AppError::NotFound(msg) => assert_eq!(msg, "API key not found"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_other_users_keys_are_forbidden() {
+        let repo = Arc::new(MockApiKeyRepository::default());
+        let auth = AuthUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Owner,
+        };
+        let other_user_id = Uuid::new_v4().to_string();
+
+        let result = issue_api_key(
+            State(repo.clone()),
+            auth,
+            Path(other_user_id.clone()),
+            Bytes::new(),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+
+        let result = get_api_keys(State(repo.clone()), auth, Path(other_user_id.clone())).await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+
+        let result = revoke_api_key(
+            State(repo),
+            auth,
+            Path((other_user_id, Uuid::new_v4().to_string())),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+    }
+
+    #[tokio::test]
+    async fn test_issue_api_key_validation() {
+        let repo = Arc::new(MockApiKeyRepository::default());
+        let user_id = Uuid::new_v4();
+
+        let body = Bytes::from(format!(r#"{{"name":"{}"}}"#, "a".repeat(51)));
+        let result = issue_api_key(
+            State(repo.clone()),
+            AuthUser {
+                user_id,
+                role: Role::Owner,
+            },
+            Path(user_id.to_string()),
+            body,
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(msg, "キーの名前は50文字以内で入力してください")
+            }
+            _ => panic!("Unexpected error type"),
+        }
+        assert!(repo.api_keys.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,642 @@
+use super::{etag, stream};
+use crate::{
+    auth::{
+        permission::{self, StreamAction},
+        AuthUser,
+    },
+    config::PreconditionConfig,
+    error::AppError,
+    model::{Grant, Stream},
+    repository::{GrantRepository, StreamRepository},
+    schema::{
+        BatchCreateStreamsRequest, BatchDeleteItem, BatchDeleteStreamsRequest,
+        BatchGetStreamsRequest, BatchGetStreamsResponse, BatchMode, BatchStreamResult,
+        BatchStreamsResponse, ServerErrorResponse,
+    },
+};
+use axum::{extract::State, http::StatusCode, Json};
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    sync::Arc,
+};
+use uuid::Uuid;
+
+/// 1回の一括処理で指定できる件数の上限
+const MAX_BATCH_ITEMS: usize = 100;
+
+fn validate_batch_size(len: usize, name: &str) -> Result<(), AppError> {
+    if len == 0 {
+        return Err(AppError::Validation(format!(
+            "{}を1件以上指定してください",
+            name
+        )));
+    }
+    if len > MAX_BATCH_ITEMS {
+        return Err(AppError::Validation(format!(
+            "{}は{}件以下で指定してください",
+            name, MAX_BATCH_ITEMS
+        )));
+    }
+    Ok(())
+}
+
+/// 項目ごとの失敗として返すエラー（データベースなどのエラーはリクエスト全体を失敗させる）
+fn item_error(error: AppError) -> Result<AppError, AppError> {
+    match error {
+        AppError::Database(_) | AppError::Internal(_) => Err(error),
+        error => Ok(error),
+    }
+}
+
+fn failed(index: usize, stream_id: Option<String>, error: AppError) -> BatchStreamResult {
+    let (status, error, message) = error.into_parts();
+    BatchStreamResult {
+        index: Some(index as i32),
+        status: Some(status.as_u16() as i32),
+        stream_id,
+        stream: None,
+        error: Some(Box::new(ServerErrorResponse {
+            error: Some(error),
+            message: Some(message),
+        })),
+    }
+}
+
+/// atomic で他の項目が失敗したために反映しなかった項目
+fn skipped(index: usize, stream_id: Option<String>) -> BatchStreamResult {
+    BatchStreamResult {
+        index: Some(index as i32),
+        status: Some(StatusCode::FAILED_DEPENDENCY.as_u16() as i32),
+        stream_id,
+        stream: None,
+        error: None,
+    }
+}
+
+fn succeeded(
+    index: usize,
+    status: StatusCode,
+    stream_id: Uuid,
+    stream: Option<Stream>,
+) -> BatchStreamResult {
+    BatchStreamResult {
+        index: Some(index as i32),
+        status: Some(status.as_u16() as i32),
+        stream_id: Some(stream_id.to_string()),
+        stream: stream.map(|s| Box::new(stream::to_response(s))),
+        error: None,
+    }
+}
+
+fn to_response(items: Vec<BatchStreamResult>) -> BatchStreamsResponse {
+    let count = |f: fn(i32) -> bool| items.iter().filter(|i| i.status.is_some_and(f)).count();
+    BatchStreamsResponse {
+        succeeded: Some(count(|s| s < 400) as i32),
+        failed: Some(count(|s| s >= 400 && s != 424) as i32),
+        items: Some(items),
+    }
+}
+
+/// 配信をまとめて取得する（閲覧できない配信は存在しない配信と同じく `missingIds` に含める）
+pub async fn batch_get_streams(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    auth: Option<AuthUser>,
+    Json(req): Json<BatchGetStreamsRequest>,
+) -> Result<Json<BatchGetStreamsResponse>, AppError> {
+    validate_batch_size(req.stream_ids.len(), "streamIds")?;
+
+    let mut seen = HashSet::new();
+    let mut ids = Vec::with_capacity(req.stream_ids.len());
+    for stream_id in &req.stream_ids {
+        let id = stream::parse_stream_id(stream_id)?;
+        if seen.insert(id) {
+            ids.push(id);
+        }
+    }
+
+    // 委任は配信者ごとに1回だけ確認する
+    let mut grants: HashMap<Uuid, Option<Grant>> = HashMap::new();
+    let mut items = Vec::new();
+    let mut found = HashSet::new();
+    for stream in repo.find_by_ids(&ids).await? {
+        let grant = match auth {
+            Some(auth)
+                if auth.user_id != stream.user_id && !stream.visibility.is_viewable_by_anyone() =>
+            {
+                if let Entry::Vacant(entry) = grants.entry(stream.user_id) {
+                    entry.insert(grant_repo.find_active(stream.user_id, auth.user_id).await?);
+                }
+                grants[&stream.user_id].as_ref()
+            }
+            _ => None,
+        };
+        if permission::authorize(auth.as_ref(), StreamAction::View, Some(&stream), grant).is_ok() {
+            found.insert(stream.stream_id);
+            items.push(stream::to_response(stream));
+        }
+    }
+
+    let missing_ids = ids
+        .into_iter()
+        .filter(|id| !found.contains(id))
+        .map(|id| id.to_string())
+        .collect();
+
+    Ok(Json(BatchGetStreamsResponse {
+        items: Some(items),
+        missing_ids: Some(missing_ids),
+    }))
+}
+
+/// 配信をまとめて登録する（配信者は認証したユーザー）
+///
+/// 検証は1件ずつ行い、atomic では1件でも失敗した場合は何も登録せずに 422 を返す。
+pub async fn batch_create_streams(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    auth: AuthUser,
+    Json(req): Json<BatchCreateStreamsRequest>,
+) -> Result<(StatusCode, Json<BatchStreamsResponse>), AppError> {
+    permission::authorize(Some(&auth), StreamAction::Create, None, None)?;
+    validate_batch_size(req.items.len(), "items")?;
+    let mode = req.mode.unwrap_or_default();
+
+    let validated: Vec<Result<Stream, AppError>> = req
+        .items
+        .into_iter()
+        .map(|item| stream::new_stream(&auth, item))
+        .collect();
+
+    if mode == BatchMode::Atomic && validated.iter().any(Result::is_err) {
+        let items = validated
+            .into_iter()
+            .enumerate()
+            .map(|(index, result)| match result {
+                Ok(_) => Ok(skipped(index, None)),
+                Err(e) => Ok(failed(index, None, item_error(e)?)),
+            })
+            .collect::<Result<_, AppError>>()?;
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, Json(to_response(items))));
+    }
+
+    let streams: Vec<Stream> = validated
+        .iter()
+        .filter_map(|result| result.as_ref().ok().cloned())
+        .collect();
+    let mut created = repo.create_many(&streams).await?.into_iter();
+
+    let mut items = Vec::with_capacity(validated.len());
+    for (index, result) in validated.into_iter().enumerate() {
+        items.push(match result {
+            Ok(_) => {
+                let stream = created
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("created streams are fewer than requested"))?;
+                succeeded(index, StatusCode::CREATED, stream.stream_id, Some(stream))
+            }
+            Err(e) => failed(index, None, item_error(e)?),
+        });
+    }
+
+    let status = match mode {
+        BatchMode::Atomic => StatusCode::CREATED,
+        BatchMode::Partial => StatusCode::OK,
+    };
+    Ok((status, Json(to_response(items))))
+}
+
+/// 配信をまとめて削除する（権限は1件ずつ `DELETE /streams/{streamId}` と同じ基準で確認する）
+///
+/// atomic では1件でも削除できない場合は何も削除せずに 422 を返す。
+/// `items` で ETag を指定した配信は、取得時から他の更新があれば削除せずに 412 とする。
+pub async fn batch_delete_streams(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    State(precondition): State<PreconditionConfig>,
+    auth: AuthUser,
+    Json(req): Json<BatchDeleteStreamsRequest>,
+) -> Result<(StatusCode, Json<BatchStreamsResponse>), AppError> {
+    let items: Vec<BatchDeleteItem> = match (req.stream_ids, req.items) {
+        (Some(stream_ids), None) => {
+            validate_batch_size(stream_ids.len(), "streamIds")?;
+            stream_ids.into_iter().map(BatchDeleteItem::new).collect()
+        }
+        (None, Some(items)) => {
+            validate_batch_size(items.len(), "items")?;
+            items
+        }
+        _ => {
+            return Err(AppError::Validation(
+                "streamIdsとitemsのどちらか一方を指定してください".to_string(),
+            ))
+        }
+    };
+    if precondition.require_if_match && items.iter().any(|item| item.etag.is_none()) {
+        return Err(AppError::PreconditionRequired(
+            "itemsのetagで削除対象のETagを指定してください".to_string(),
+        ));
+    }
+    let mode = req.mode.unwrap_or_default();
+
+    let mut checked = Vec::with_capacity(items.len());
+    for item in &items {
+        let result = match stream::parse_stream_id(&item.stream_id) {
+            Ok(id) => stream::find_authorized(
+                repo.as_ref(),
+                grant_repo.as_ref(),
+                Some(&auth),
+                StreamAction::Delete,
+                id,
+            )
+            .await
+            .and_then(|s| match &item.etag {
+                Some(tag) if !etag::matches(tag, s.version) => Err(stream::version_mismatch(true)),
+                _ => Ok((s.stream_id, s.version)),
+            }),
+            Err(e) => Err(e),
+        };
+        checked.push(match result {
+            Ok(target) => Ok(target),
+            Err(e) => Err(item_error(e)?),
+        });
+    }
+
+    // atomic では1件でも削除できなければ何も削除しない
+    let atomic = mode == BatchMode::Atomic;
+    if !(atomic && checked.iter().any(Result::is_err)) {
+        let mut seen = HashSet::new();
+        let targets: Vec<(Uuid, i64)> = checked
+            .iter()
+            .filter_map(|r| r.as_ref().ok().copied())
+            .filter(|(id, _)| seen.insert(*id))
+            .collect();
+        let deleted: HashSet<Uuid> = repo
+            .delete_many(&targets, atomic)
+            .await?
+            .into_iter()
+            .collect();
+
+        // 確認してから削除するまでの間に他の更新があった配信は版が一致しない
+        for (result, item) in checked.iter_mut().zip(&items) {
+            if result.as_ref().is_ok_and(|(id, _)| !deleted.contains(id)) {
+                *result = Err(stream::version_mismatch(item.etag.is_some()));
+            }
+        }
+    }
+    let rejected = atomic && checked.iter().any(Result::is_err);
+
+    let items = checked
+        .into_iter()
+        .zip(items)
+        .enumerate()
+        .map(|(index, (result, item))| match result {
+            Ok((id, _)) if rejected => skipped(index, Some(id.to_string())),
+            Ok((id, _)) => succeeded(index, StatusCode::NO_CONTENT, id, None),
+            Err(e) => failed(index, Some(item.stream_id), e),
+        })
+        .collect();
+
+    let status = if rejected {
+        StatusCode::UNPROCESSABLE_ENTITY
+    } else {
+        StatusCode::OK
+    };
+    Ok((status, Json(to_response(items))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Role, Visibility};
+    use crate::repository::mock::{MockGrantRepository, MockStreamRepository};
+    use crate::schema::CreateStreamRequest;
+    use chrono::Utc;
+
+    fn auth(user_id: Uuid) -> AuthUser {
+        AuthUser {
+            user_id,
+            role: Role::Owner,
+        }
+    }
+
+    fn create_request(title: &str) -> CreateStreamRequest {
+        CreateStreamRequest {
+            title: title.to_string(),
+            description: "desc".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn statuses(response: &BatchStreamsResponse) -> Vec<i32> {
+        response
+            .items
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|i| i.status.unwrap())
+            .collect()
+    }
+
+    async fn create_stream(repo: &MockStreamRepository, user_id: Uuid) -> Uuid {
+        repo.create(&Stream {
+            stream_id: Uuid::new_v4(),
+            user_id,
+            title: "title".to_string(),
+            description: "desc".to_string(),
+            category: "".to_string(),
+            visibility: Visibility::Published,
+            version: 1,
+            created_at: Utc::now(),
+            deleted_at: None,
+        })
+        .await
+        .unwrap()
+        .stream_id
+    }
+
+    #[tokio::test]
+    async fn test_batch_get() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let grants = Arc::new(MockGrantRepository::default());
+        let owner_id = Uuid::new_v4();
+        let first = create_stream(&repo, owner_id).await;
+        let second = create_stream(&repo, owner_id).await;
+        let private = repo
+            .create(&Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: owner_id,
+                title: "private".to_string(),
+                description: "".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Private,
+                version: 1,
+                created_at: Utc::now(),
+                deleted_at: None,
+            })
+            .await
+            .unwrap()
+            .stream_id;
+        let unknown = Uuid::new_v4();
+        let ids: Vec<String> = [second, unknown, private, first, second]
+            .iter()
+            .map(Uuid::to_string)
+            .collect();
+
+        // 閲覧できない配信は存在しない配信と同じく missingIds に含める
+        for (auth, missing) in [
+            (None, vec![unknown, private]),
+            (Some(auth(Uuid::new_v4())), vec![unknown, private]),
+            (Some(auth(owner_id)), vec![unknown]),
+        ] {
+            let Json(response) = batch_get_streams(
+                State(repo.clone()),
+                State(grants.clone()),
+                auth,
+                Json(BatchGetStreamsRequest::new(ids.clone())),
+            )
+            .await
+            .unwrap();
+            let items: Vec<String> = response
+                .items
+                .unwrap()
+                .into_iter()
+                .map(|s| s.stream_id.unwrap())
+                .collect();
+            let mut expected = vec![second.to_string()];
+            if auth.is_some_and(|a| a.user_id == owner_id) {
+                expected.push(private.to_string());
+            }
+            expected.push(first.to_string());
+            assert_eq!(items, expected);
+            let missing: Vec<String> = missing.iter().map(Uuid::to_string).collect();
+            assert_eq!(response.missing_ids, Some(missing));
+        }
+
+        let result = batch_get_streams(
+            State(repo),
+            State(grants),
+            None,
+            Json(BatchGetStreamsRequest::new(vec!["not-a-uuid".to_string()])),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_atomic() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+
+        let req = BatchCreateStreamsRequest::new(vec![create_request("a"), create_request("b")]);
+        let (status, Json(response)) =
+            batch_create_streams(State(repo.clone()), auth(user_id), Json(req))
+                .await
+                .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(statuses(&response), vec![201, 201]);
+        assert_eq!(response.succeeded, Some(2));
+        let items = response.items.unwrap();
+        let created = items[1].stream.as_ref().unwrap();
+        assert_eq!(created.title.as_deref(), Some("b"));
+        assert_eq!(created.user_id, Some(user_id.to_string()));
+        assert_eq!(repo.streams.lock().unwrap().len(), 2);
+
+        // 検証エラーが1件でもあれば何も登録しない
+        let req = BatchCreateStreamsRequest::new(vec![create_request("c"), create_request("")]);
+        let (status, Json(response)) =
+            batch_create_streams(State(repo.clone()), auth(user_id), Json(req))
+                .await
+                .unwrap();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(statuses(&response), vec![424, 400]);
+        assert_eq!((response.succeeded, response.failed), (Some(0), Some(1)));
+        let error = response.items.unwrap()[1].error.clone().unwrap();
+        assert_eq!(error.error.as_deref(), Some("validation_error"));
+        assert_eq!(repo.streams.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_partial() {
+        let repo = Arc::new(MockStreamRepository::default());
+
+        let req = BatchCreateStreamsRequest {
+            items: vec![create_request(""), create_request("ok")],
+            mode: Some(BatchMode::Partial),
+        };
+        let (status, Json(response)) =
+            batch_create_streams(State(repo.clone()), auth(Uuid::new_v4()), Json(req))
+                .await
+                .unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(statuses(&response), vec![400, 201]);
+        assert_eq!((response.succeeded, response.failed), (Some(1), Some(1)));
+        assert_eq!(repo.streams.lock().unwrap()[0].title, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_batch_size_limits() {
+        let repo = Arc::new(MockStreamRepository::default());
+
+        for items in [vec![], vec![create_request("a"); MAX_BATCH_ITEMS + 1]] {
+            let req = BatchCreateStreamsRequest::new(items);
+            let result =
+                batch_create_streams(State(repo.clone()), auth(Uuid::new_v4()), Json(req)).await;
+            assert!(matches!(result.unwrap_err(), AppError::Validation(_)));
+        }
+        // streamIds と items はどちらか一方のみ指定できる
+        let empty = BatchDeleteStreamsRequest {
+            stream_ids: Some(vec![]),
+            ..BatchDeleteStreamsRequest::new()
+        };
+        let both = BatchDeleteStreamsRequest {
+            stream_ids: Some(vec![Uuid::new_v4().to_string()]),
+            items: Some(vec![BatchDeleteItem::new(Uuid::new_v4().to_string())]),
+            ..BatchDeleteStreamsRequest::new()
+        };
+        for req in [empty, both, BatchDeleteStreamsRequest::new()] {
+            let result = batch_delete_streams(
+                State(repo.clone()),
+                State(Arc::new(MockGrantRepository::default())),
+                State(PreconditionConfig::default()),
+                auth(Uuid::new_v4()),
+                Json(req),
+            )
+            .await;
+            assert!(matches!(result.unwrap_err(), AppError::Validation(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let grants = Arc::new(MockGrantRepository::default());
+        let owner_id = Uuid::new_v4();
+        let own = create_stream(&repo, owner_id).await;
+        let others = create_stream(&repo, Uuid::new_v4()).await;
+        let ids = vec![
+            own.to_string(),
+            others.to_string(),
+            Uuid::new_v4().to_string(),
+            "not-a-uuid".to_string(),
+        ];
+
+        // 削除できない配信が含まれる場合は何も削除しない
+        let req = BatchDeleteStreamsRequest {
+            stream_ids: Some(ids.clone()),
+            ..BatchDeleteStreamsRequest::new()
+        };
+        let (status, Json(response)) = batch_delete_streams(
+            State(repo.clone()),
+            State(grants.clone()),
+            State(PreconditionConfig::default()),
+            auth(owner_id),
+            Json(req),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(statuses(&response), vec![424, 403, 404, 400]);
+        assert!(repo.find_by_id(own).await.unwrap().is_some());
+
+        let req = BatchDeleteStreamsRequest {
+            stream_ids: Some(ids),
+            mode: Some(BatchMode::Partial),
+            ..BatchDeleteStreamsRequest::new()
+        };
+        let (status, Json(response)) = batch_delete_streams(
+            State(repo.clone()),
+            State(grants),
+            State(PreconditionConfig::default()),
+            auth(owner_id),
+            Json(req),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(statuses(&response), vec![204, 403, 404, 400]);
+        assert_eq!((response.succeeded, response.failed), (Some(1), Some(3)));
+        assert!(repo.find_by_id(own).await.unwrap().is_none());
+        assert!(repo.find_by_id(others).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_with_etags() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let grants = Arc::new(MockGrantRepository::default());
+        let owner_id = Uuid::new_v4();
+        let first = create_stream(&repo, owner_id).await;
+        let second = create_stream(&repo, owner_id).await;
+        let item = |id: Uuid, etag: Option<&str>| BatchDeleteItem {
+            stream_id: id.to_string(),
+            etag: etag.map(str::to_string),
+        };
+        let delete = |items: Vec<BatchDeleteItem>, mode: BatchMode, required: bool| {
+            batch_delete_streams(
+                State(repo.clone()),
+                State(grants.clone()),
+                State(PreconditionConfig {
+                    require_if_match: required,
+                }),
+                auth(owner_id),
+                Json(BatchDeleteStreamsRequest {
+                    items: Some(items),
+                    mode: Some(mode),
+                    ..BatchDeleteStreamsRequest::new()
+                }),
+            )
+        };
+
+        // If-Match を必須にした場合は etag のない項目があれば 428
+        let result = delete(
+            vec![item(first, Some("\"1\"")), item(second, None)],
+            BatchMode::Atomic,
+            true,
+        )
+        .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            AppError::PreconditionRequired(_)
+        ));
+
+        // 古い ETag の項目は 412
+        let (status, Json(response)) = delete(
+            vec![item(first, Some("\"1\"")), item(second, Some("\"0\""))],
+            BatchMode::Atomic,
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(statuses(&response), vec![424, 412]);
+        assert!(repo.find_by_id(first).await.unwrap().is_some());
+
+        // 確認してから削除するまでの間に更新された項目は 409（etag を指定した場合は 412）
+        repo.concurrent_update
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        let (status, Json(response)) = delete(
+            vec![item(first, None), item(second, Some("*"))],
+            BatchMode::Partial,
+            false,
+        )
+        .await
+        .unwrap();
+        repo.concurrent_update
+            .store(false, std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(statuses(&response), vec![409, 412]);
+        assert!(repo.find_by_id(first).await.unwrap().is_some());
+
+        // 現在の ETag なら削除する
+        let (status, Json(response)) = delete(
+            vec![item(first, Some("\"2\"")), item(second, Some("*"))],
+            BatchMode::Atomic,
+            true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(statuses(&response), vec![204, 204]);
+        assert!(repo.find_by_id(first).await.unwrap().is_none());
+        assert!(repo.find_by_id(second).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::error::AppError;
+use axum::http::{header, HeaderMap, HeaderValue};
+use sha2::{Digest, Sha256};
+
+/// 配信の版から強い ETag（`"<version>"`）を生成する
+pub fn etag(version: i64) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", version)).expect("ETag is always a valid header")
+}
+
+/// 関連リソースを埋め込んだ表現の弱い ETag（`W/"<version>-<埋め込んだ内容のハッシュ>"`）を生成する
+///
+/// 埋め込んだ内容が変わった場合も別の ETag になる。弱い ETag のため `If-Match` には一致しない。
+pub fn expanded_etag(version: i64, embedded: &[u8]) -> HeaderValue {
+    let hash = Sha256::digest(embedded);
+    let hash: String = hash[..8].iter().map(|b| format!("{:02x}", b)).collect();
+    HeaderValue::from_str(&format!("W/\"{}-{}\"", version, hash))
+        .expect("ETag is always a valid header")
+}
+
+/// `If-Match` / `If-None-Match` に列挙された ETag（`*` を含む）
+fn entity_tags<'a>(headers: &'a HeaderMap, name: &header::HeaderName) -> Vec<&'a str> {
+    headers
+        .get_all(name)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+/// `If-Match` を現在の版と照合する（更新・削除の前に呼び出す）
+///
+/// 比較は強い比較で、弱い ETag（`W/"..."`）は一致しない。
+/// ヘッダーがない場合は `required` のときだけ 428 を返す。
+pub fn check_if_match(headers: &HeaderMap, version: i64, required: bool) -> Result<(), AppError> {
+    let tags = entity_tags(headers, &header::IF_MATCH);
+    if tags.is_empty() {
+        if required {
+            return Err(AppError::PreconditionRequired(
+                "If-Matchヘッダーで更新対象のETagを指定してください".to_string(),
+            ));
+        }
+        return Ok(());
+    }
+
+    if tags.iter().any(|tag| matches(tag, version)) {
+        Ok(())
+    } else {
+        Err(AppError::PreconditionFailed(
+            "配信情報が他のユーザーによって更新されています".to_string(),
+        ))
+    }
+}
+
+/// ETag（`*` を含む）が現在の版と一致するか（強い比較）
+pub fn matches(tag: &str, version: i64) -> bool {
+    tag == "*" || tag == etag(version)
+}
+
+/// `If-None-Match` のいずれかが現在の ETag と一致するか（弱い比較）
+pub fn is_not_modified(headers: &HeaderMap, current: &HeaderValue) -> bool {
+    let current = current
+        .to_str()
+        .unwrap_or_default()
+        .trim_start_matches("W/");
+    entity_tags(headers, &header::IF_NONE_MATCH)
+        .iter()
+        .any(|tag| *tag == "*" || tag.trim_start_matches("W/") == current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(name: header::HeaderName, value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn test_etag_format() {
+        assert_eq!(etag(3), "\"3\"");
+
+        let expanded = expanded_etag(3, b"{}");
+        let value = expanded.to_str().unwrap();
+        assert!(
+            value.starts_with("W/\"3-") && value.ends_with('"'),
+            "{}",
+            value
+        );
+        assert_ne!(expanded, expanded_etag(3, b"{\"a\":1}"));
+        assert_ne!(expanded, expanded_etag(4, b"{}"));
+    }
+
+    #[test]
+    fn test_check_if_match() {
+        assert!(check_if_match(&HeaderMap::new(), 3, false).is_ok());
+        assert!(matches!(
+            check_if_match(&HeaderMap::new(), 3, true),
+            Err(AppError::PreconditionRequired(_))
+        ));
+
+        for value in ["\"3\"", "\"1\", \"3\"", "*"] {
+            assert!(check_if_match(&headers(header::IF_MATCH, value), 3, true).is_ok());
+        }
+        for value in ["\"2\"", "W/\"3\"", "3"] {
+            assert!(matches!(
+                check_if_match(&headers(header::IF_MATCH, value), 3, false),
+                Err(AppError::PreconditionFailed(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_is_not_modified() {
+        let current = etag(3);
+        assert!(!is_not_modified(&HeaderMap::new(), &current));
+        for value in ["\"3\"", "W/\"3\"", "\"1\", \"3\"", "*"] {
+            assert!(is_not_modified(
+                &headers(header::IF_NONE_MATCH, value),
+                &current
+            ));
+        }
+        assert!(!is_not_modified(
+            &headers(header::IF_NONE_MATCH, "\"2\""),
+            &current
+        ));
+
+        // 弱い ETag 同士も弱い比較で一致する
+        let expanded = expanded_etag(3, b"{}");
+        let mut matching = HeaderMap::new();
+        matching.insert(header::IF_NONE_MATCH, expanded.clone());
+        assert!(is_not_modified(&matching, &expanded));
+        assert!(!is_not_modified(&matching, &current));
+    }
+}
@@ -0,0 +1,700 @@
+use super::stream::visibility_to_response;
+use super::user::parse_user_id;
+use crate::{
+    auth::AuthUser,
+    error::AppError,
+    markdown,
+    model::{Stream, StreamCursor},
+    repository::{StreamRepository, UserRepository},
+    schema::ExportedStream,
+};
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, State},
+    http::{header, HeaderValue},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::Query;
+use chrono::{Datelike, Timelike};
+use chrono_tz::Tz;
+use futures_util::{stream, StreamExt, TryStreamExt};
+use serde::Deserialize;
+use std::{
+    io::{Cursor, Write},
+    sync::Arc,
+};
+use uuid::Uuid;
+
+/// 1回の読み込みで取得する件数（この件数ずつ送信し、全件をメモリに読み込まない）
+const EXPORT_BATCH_SIZE: i64 = 500;
+
+/// CSV のヘッダー行（`ExportedStream` の項目と同じ順序）
+const CSV_HEADER: [&str; 8] = [
+    "streamId",
+    "userId",
+    "title",
+    "description",
+    "category",
+    "visibility",
+    "createdAt",
+    "deletedAt",
+];
+
+/// tar の終わりを表す 512 バイトの空のブロック 2 つ
+const TAR_TRAILER: [u8; 1024] = [0; 1024];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Jsonl,
+    Csv,
+    /// 1 件 1 ファイルの Markdown を年・月のディレクトリに分けた tar
+    MarkdownTar,
+    /// `MarkdownTar` と同じ構成の zip（全件をまとめてから返す）
+    MarkdownZip,
+}
+
+impl ExportFormat {
+    pub fn from_param(value: &str) -> Option<Self> {
+        match value {
+            "jsonl" => Some(Self::Jsonl),
+            "csv" => Some(Self::Csv),
+            "markdown-tar" => Some(Self::MarkdownTar),
+            "markdown-zip" => Some(Self::MarkdownZip),
+            _ => None,
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Jsonl => "application/x-ndjson",
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::MarkdownTar => "application/x-tar",
+            Self::MarkdownZip => "application/zip",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Jsonl => "jsonl",
+            Self::Csv => "csv",
+            Self::MarkdownTar => "tar",
+            Self::MarkdownZip => "zip",
+        }
+    }
+
+    /// 読み込んだ分ずつチャンクに分けて送信できる形式（zip は `None`）
+    fn chunked(self) -> Option<ChunkedFormat> {
+        match self {
+            Self::Jsonl => Some(ChunkedFormat::Jsonl),
+            Self::Csv => Some(ChunkedFormat::Csv),
+            Self::MarkdownTar => Some(ChunkedFormat::MarkdownTar),
+            Self::MarkdownZip => None,
+        }
+    }
+}
+
+/// チャンクに分けて送信する出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChunkedFormat {
+    Jsonl,
+    Csv,
+    MarkdownTar,
+}
+
+#[derive(Deserialize, Default)]
+pub struct ExportQuery {
+    /// 省略した場合は jsonl
+    pub format: Option<String>,
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+pub(crate) fn to_exported(stream: Stream) -> ExportedStream {
+    ExportedStream {
+        stream_id: stream.stream_id.to_string(),
+        user_id: stream.user_id.to_string(),
+        title: stream.title,
+        description: stream.description,
+        category: stream.category,
+        visibility: visibility_to_response(stream.visibility),
+        created_at: stream.created_at.to_rfc3339(),
+        deleted_at: stream.deleted_at.map(|d| d.to_rfc3339()),
+    }
+}
+
+fn csv_error(e: csv::Error) -> AppError {
+    AppError::Internal(e.into())
+}
+
+fn csv_bytes(writer: csv::Writer<Vec<u8>>) -> Result<Bytes, AppError> {
+    let buf = writer
+        .into_inner()
+        .map_err(|e| AppError::Internal(e.into_error().into()))?;
+    Ok(Bytes::from(buf))
+}
+
+/// 配信を Markdown ファイルにする（パスと内容）
+fn markdown_file(stream: &Stream, tz: Tz) -> Result<(String, String), AppError> {
+    let text = markdown::render(stream).map_err(|e| AppError::Internal(e.into()))?;
+    Ok((markdown::file_path(stream, tz), text))
+}
+
+/// アーカイブ内のファイルの更新日時（同じ配信からは同じアーカイブができるよう、作成日時にそろえる）
+fn modified_time(stream: &Stream) -> u64 {
+    stream.created_at.timestamp().max(0) as u64
+}
+
+/// zip の更新日時（タイムゾーンを持たないため `tz` の日時にする。1980 年より前の日時は 1980-01-01 にする）
+fn zip_modified_time(stream: &Stream, tz: Tz) -> zip::DateTime {
+    let t = stream.created_at.with_timezone(&tz);
+    zip::DateTime::from_date_and_time(
+        t.year().try_into().unwrap_or_default(),
+        t.month() as u8,
+        t.day() as u8,
+        t.hour() as u8,
+        t.minute() as u8,
+        t.second() as u8,
+    )
+    .unwrap_or_default()
+}
+
+/// 配信ごとの tar のエントリー（終わりのブロックは含まない）
+fn tar_entries(streams: Vec<Stream>, tz: Tz) -> Result<Bytes, AppError> {
+    let mut buf = Vec::new();
+    for stream in streams {
+        let (path, text) = markdown_file(&stream, tz)?;
+        let mut header = tar::Header::new_ustar();
+        header
+            .set_path(&path)
+            .map_err(|e| AppError::Internal(e.into()))?;
+        header.set_size(text.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(modified_time(&stream));
+        header.set_entry_type(tar::EntryType::Regular);
+        header.set_cksum();
+        buf.extend_from_slice(header.as_bytes());
+        buf.extend_from_slice(text.as_bytes());
+        // 内容は 512 バイト単位に切り上げる
+        buf.resize(buf.len().next_multiple_of(512), 0);
+    }
+    Ok(Bytes::from(buf))
+}
+
+/// 読み込んだ配信を出力形式に変換する（CSV の値は必要に応じて引用符で囲み、改行もそのまま保持する）
+///
+/// `tz` は Markdown のファイルをディレクトリに分ける日付に使う。
+fn encode(format: ChunkedFormat, tz: Tz, streams: Vec<Stream>) -> Result<Bytes, AppError> {
+    match format {
+        ChunkedFormat::Jsonl => {
+            let mut buf = Vec::new();
+            for stream in streams {
+                serde_json::to_writer(&mut buf, &to_exported(stream))
+                    .map_err(|e| AppError::Internal(e.into()))?;
+                buf.push(b'\n');
+            }
+            Ok(Bytes::from(buf))
+        }
+        ChunkedFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for stream in streams {
+                let s = to_exported(stream);
+                writer
+                    .write_record([
+                        s.stream_id,
+                        s.user_id,
+                        s.title,
+                        s.description,
+                        s.category,
+                        s.visibility.to_string(),
+                        s.created_at,
+                        s.deleted_at.unwrap_or_default(),
+                    ])
+                    .map_err(csv_error)?;
+            }
+            csv_bytes(writer)
+        }
+        ChunkedFormat::MarkdownTar => tar_entries(streams, tz),
+    }
+}
+
+fn zip_error(e: zip::result::ZipError) -> AppError {
+    AppError::Internal(e.into())
+}
+
+/// 配信をすべて読み込んで zip にする（zip は書き終えた後に先頭へ戻って書き換えるため、チャンクに分けて送れない）
+async fn markdown_zip(
+    repo: Arc<dyn StreamRepository>,
+    user_id: Uuid,
+    include_deleted: bool,
+    tz: Tz,
+) -> Result<Bytes, AppError> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let mut batches = std::pin::pin!(export_batches(repo, user_id, include_deleted));
+    while let Some(streams) = batches.try_next().await? {
+        for stream in streams {
+            let (path, text) = markdown_file(&stream, tz)?;
+            let options = zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated)
+                .last_modified_time(zip_modified_time(&stream, tz))
+                .unix_permissions(0o644);
+            writer.start_file(path, options).map_err(zip_error)?;
+            writer
+                .write_all(text.as_bytes())
+                .map_err(|e| AppError::Internal(e.into()))?;
+        }
+    }
+    let buf = writer.finish().map_err(zip_error)?.into_inner();
+    Ok(Bytes::from(buf))
+}
+
+fn csv_header() -> Result<Bytes, AppError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(CSV_HEADER).map_err(csv_error)?;
+    csv_bytes(writer)
+}
+
+/// 配信を `EXPORT_BATCH_SIZE` 件ずつ読み込んで順に返すストリーム
+fn export_batches(
+    repo: Arc<dyn StreamRepository>,
+    user_id: Uuid,
+    include_deleted: bool,
+) -> impl futures_util::Stream<Item = Result<Vec<Stream>, AppError>> {
+    // 状態は次に読み込む位置（`None` は読み終えたことを表す）
+    stream::try_unfold(Some(None), move |after: Option<Option<StreamCursor>>| {
+        let repo = repo.clone();
+        async move {
+            let Some(after) = after else {
+                return Ok(None);
+            };
+            let streams = repo
+                .find_by_user_after(user_id, include_deleted, after, EXPORT_BATCH_SIZE)
+                .await?;
+            if streams.is_empty() {
+                return Ok(None);
+            }
+            let next = if (streams.len() as i64) < EXPORT_BATCH_SIZE {
+                None
+            } else {
+                streams.last().map(|s| {
+                    Some(StreamCursor {
+                        created_at: s.created_at,
+                        stream_id: s.stream_id,
+                    })
+                })
+            };
+            Ok(Some((streams, next)))
+        }
+    })
+}
+
+/// 読み込んだ配信を変換したチャンクを順に返すストリーム
+fn export_chunks(
+    repo: Arc<dyn StreamRepository>,
+    user_id: Uuid,
+    include_deleted: bool,
+    format: ChunkedFormat,
+    tz: Tz,
+) -> impl futures_util::Stream<Item = Result<Bytes, AppError>> {
+    export_batches(repo, user_id, include_deleted)
+        .and_then(move |streams| async move { encode(format, tz, streams) })
+}
+
+/// ユーザーのタイムゾーン（更新時に検証しているため、読み込めない値は UTC とみなす）
+async fn user_time_zone(user_repo: &dyn UserRepository, user_id: Uuid) -> Result<Tz, AppError> {
+    let user = user_repo
+        .find_by_id(user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+    Ok(user.time_zone.parse().unwrap_or(Tz::UTC))
+}
+
+/// 配信者本人の配信を下書き・非公開も含めてすべて出力する
+///
+/// Markdown のファイルは配信者のタイムゾーンでの作成日ごとのディレクトリに分ける。
+/// 送信を始めた後に読み込みに失敗した場合はステータスを変えられないため、接続を切断する。
+pub async fn export_user_streams(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    State(user_repo): State<Arc<dyn UserRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response, AppError> {
+    let user_id = parse_user_id(&user_id)?;
+    auth.ensure_user(user_id)?;
+    let format = match query.format.as_deref() {
+        Some(value) => ExportFormat::from_param(value).ok_or_else(|| {
+            AppError::Validation(
+                "formatはjsonl・csv・markdown-tar・markdown-zipのいずれかで指定してください"
+                    .to_string(),
+            )
+        })?,
+        None => ExportFormat::Jsonl,
+    };
+    let tz = user_time_zone(user_repo.as_ref(), user_id).await?;
+
+    let body = match format.chunked() {
+        Some(chunked) => {
+            let (header_row, trailer) = match chunked {
+                ChunkedFormat::Jsonl => (None, None),
+                ChunkedFormat::Csv => (Some(csv_header()?), None),
+                ChunkedFormat::MarkdownTar => (None, Some(Bytes::from_static(&TAR_TRAILER))),
+            };
+            let chunks = stream::iter(header_row.map(Ok))
+                .chain(export_chunks(
+                    repo,
+                    user_id,
+                    query.include_deleted,
+                    chunked,
+                    tz,
+                ))
+                .chain(stream::iter(trailer.map(Ok)))
+                .inspect_err(|e| tracing::error!("Export failed: {:?}", e));
+            Body::from_stream(chunks)
+        }
+        None => Body::from(markdown_zip(repo, user_id, query.include_deleted, tz).await?),
+    };
+
+    let disposition = format!(
+        "attachment; filename=\"streams-{}.{}\"",
+        user_id,
+        format.extension()
+    );
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(format.content_type()),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                HeaderValue::from_str(&disposition).map_err(|e| AppError::Internal(e.into()))?,
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{user::DEFAULT_TIME_ZONE, Role, User, Visibility};
+    use crate::repository::mock::{MockStreamRepository, MockUserRepository};
+    use chrono::{Duration, Utc};
+
+    fn auth(user_id: Uuid) -> AuthUser {
+        AuthUser {
+            user_id,
+            role: Role::Owner,
+        }
+    }
+
+    fn query(format: Option<&str>, include_deleted: bool) -> ExportQuery {
+        ExportQuery {
+            format: format.map(str::to_string),
+            include_deleted,
+        }
+    }
+
+    async fn export(
+        repo: Arc<MockStreamRepository>,
+        user_id: Uuid,
+        query: ExportQuery,
+    ) -> (String, String) {
+        let (content_type, body) = export_bytes(repo, user_id, query).await;
+        (content_type, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    async fn export_bytes(
+        repo: Arc<MockStreamRepository>,
+        user_id: Uuid,
+        query: ExportQuery,
+    ) -> (String, Bytes) {
+        let user_repo = Arc::new(MockUserRepository::with_users(&[user_id]));
+        export_bytes_as(repo, user_repo, user_id, query).await
+    }
+
+    async fn export_bytes_as(
+        repo: Arc<MockStreamRepository>,
+        user_repo: Arc<MockUserRepository>,
+        user_id: Uuid,
+        query: ExportQuery,
+    ) -> (String, Bytes) {
+        let response = export_user_streams(
+            State(repo),
+            State(user_repo),
+            auth(user_id),
+            Path(user_id.to_string()),
+            Query(query),
+        )
+        .await
+        .unwrap();
+        let content_type = response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (content_type, body)
+    }
+
+    /// 書き出した Markdown ファイル（パスと内容）の期待値（ユーザーは既定のタイムゾーン）
+    fn markdown_files(repo: &MockStreamRepository, titles: &[&str]) -> Vec<(String, String)> {
+        let streams = repo.streams.lock().unwrap().clone();
+        let tz: Tz = DEFAULT_TIME_ZONE.parse().unwrap();
+        titles
+            .iter()
+            .map(|title| {
+                let stream = streams.iter().find(|s| s.title == *title).unwrap();
+                markdown_file(stream, tz).unwrap()
+            })
+            .collect()
+    }
+
+    /// 古い順に `titles` の配信を登録する
+    async fn create_streams(repo: &MockStreamRepository, user_id: Uuid, titles: &[&str]) {
+        let base = Utc::now();
+        for (i, title) in titles.iter().enumerate() {
+            repo.create(&Stream {
+                stream_id: Uuid::new_v4(),
+                user_id,
+                title: title.to_string(),
+                description: "1行目\n2行目, \"引用\"".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Draft,
+                version: 1,
+                created_at: base + Duration::seconds(i as i64),
+                deleted_at: None,
+            })
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        create_streams(&repo, user_id, &["first", "deleted", "last"]).await;
+        create_streams(&repo, Uuid::new_v4(), &["other"]).await;
+        let deleted_id = repo.streams.lock().unwrap()[1].stream_id;
+        repo.delete(deleted_id, 1).await.unwrap();
+
+        let (content_type, body) = export(repo.clone(), user_id, query(None, false)).await;
+        assert_eq!(content_type, "application/x-ndjson");
+        let rows: Vec<ExportedStream> = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let titles: Vec<&str> = rows.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, vec!["first", "last"]);
+        assert_eq!(rows[0].description, "1行目\n2行目, \"引用\"");
+
+        // 削除済みの配信も含める
+        let (_, body) = export(repo, user_id, query(Some("jsonl"), true)).await;
+        let rows: Vec<ExportedStream> = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let titles: Vec<&str> = rows.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, vec!["first", "deleted", "last"]);
+        assert!(rows[1].deleted_at.is_some());
+        assert!(rows[0].deleted_at.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_keeps_newlines() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        create_streams(&repo, user_id, &["first", "second"]).await;
+
+        let (content_type, body) = export(repo, user_id, query(Some("csv"), false)).await;
+        assert_eq!(content_type, "text/csv; charset=utf-8");
+
+        let mut reader = csv::Reader::from_reader(body.as_bytes());
+        assert_eq!(reader.headers().unwrap(), CSV_HEADER.as_slice());
+        let records: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(&records[0][2], "first");
+        assert_eq!(&records[0][3], "1行目\n2行目, \"引用\"");
+        assert_eq!(&records[0][5], "draft");
+        assert_eq!(&records[0][7], "");
+    }
+
+    #[tokio::test]
+    async fn test_export_markdown_tar() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        create_streams(&repo, user_id, &["first", "deleted", "last"]).await;
+        let deleted_id = repo.streams.lock().unwrap()[1].stream_id;
+        repo.delete(deleted_id, 1).await.unwrap();
+
+        let (content_type, body) =
+            export_bytes(repo.clone(), user_id, query(Some("markdown-tar"), false)).await;
+        assert_eq!(content_type, "application/x-tar");
+
+        let mut archive = tar::Archive::new(body.as_ref());
+        let files: Vec<(String, String)> = archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let mut entry = entry.unwrap();
+                assert_eq!(entry.header().mode().unwrap(), 0o644);
+                let path = entry.path().unwrap().to_string_lossy().to_string();
+                let mut text = String::new();
+                std::io::Read::read_to_string(&mut entry, &mut text).unwrap();
+                (path, text)
+            })
+            .collect();
+        assert_eq!(files, markdown_files(&repo, &["first", "last"]));
+        assert!(files[0].1.contains("\n---\n\n1行目\n2行目, \"引用\"\n"));
+
+        // 同じ内容からは同じアーカイブができる
+        let (_, again) =
+            export_bytes(repo.clone(), user_id, query(Some("markdown-tar"), false)).await;
+        assert_eq!(again, body);
+
+        let (_, body) =
+            export_bytes(repo.clone(), user_id, query(Some("markdown-tar"), true)).await;
+        let mut archive = tar::Archive::new(body.as_ref());
+        assert_eq!(archive.entries().unwrap().count(), 3);
+
+        // 配信がなければ空のアーカイブ
+        let (_, body) =
+            export_bytes(repo, Uuid::new_v4(), query(Some("markdown-tar"), false)).await;
+        assert_eq!(body.as_ref(), TAR_TRAILER.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_export_markdown_uses_user_time_zone() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        let stream_id = Uuid::new_v4();
+        repo.create(&Stream {
+            stream_id,
+            user_id,
+            title: "a".to_string(),
+            description: "".to_string(),
+            category: "".to_string(),
+            visibility: Visibility::Draft,
+            version: 1,
+            created_at: "2025-01-01T03:00:00Z".parse().unwrap(),
+            deleted_at: None,
+        })
+        .await
+        .unwrap();
+        let user_repo = Arc::new(MockUserRepository::default());
+        user_repo
+            .create(&User {
+                time_zone: "America/New_York".to_string(),
+                ..User::new(user_id)
+            })
+            .await
+            .unwrap();
+
+        // UTC では 2025-01-01 だが、ユーザーのタイムゾーンでは前年の 12/31
+        let (_, body) = export_bytes_as(
+            repo.clone(),
+            user_repo.clone(),
+            user_id,
+            query(Some("markdown-tar"), false),
+        )
+        .await;
+        let mut archive = tar::Archive::new(body.as_ref());
+        let entry = archive.entries().unwrap().next().unwrap().unwrap();
+        assert_eq!(
+            entry.path().unwrap().to_string_lossy(),
+            format!("2024/12/2024-12-31-{}.md", stream_id)
+        );
+
+        let (_, body) =
+            export_bytes_as(repo, user_repo, user_id, query(Some("markdown-zip"), false)).await;
+        let mut archive = zip::ZipArchive::new(Cursor::new(body)).unwrap();
+        let file = archive.by_index(0).unwrap();
+        assert_eq!(file.name(), format!("2024/12/2024-12-31-{}.md", stream_id));
+        let modified = file.last_modified().unwrap();
+        assert_eq!(
+            (modified.year(), modified.month(), modified.day()),
+            (2024, 12, 31)
+        );
+        assert_eq!(modified.hour(), 22);
+    }
+
+    #[tokio::test]
+    async fn test_export_markdown_zip() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        create_streams(&repo, user_id, &["first", "second"]).await;
+
+        let (content_type, body) =
+            export_bytes(repo.clone(), user_id, query(Some("markdown-zip"), false)).await;
+        assert_eq!(content_type, "application/zip");
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(body.clone())).unwrap();
+        let files: Vec<(String, String)> = (0..archive.len())
+            .map(|i| {
+                let mut file = archive.by_index(i).unwrap();
+                let mut text = String::new();
+                std::io::Read::read_to_string(&mut file, &mut text).unwrap();
+                (file.name().to_string(), text)
+            })
+            .collect();
+        assert_eq!(files, markdown_files(&repo, &["first", "second"]));
+
+        let (_, again) = export_bytes(repo, user_id, query(Some("markdown-zip"), false)).await;
+        assert_eq!(again, body);
+    }
+
+    #[tokio::test]
+    async fn test_export_reads_in_batches() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        let titles: Vec<String> = (0..EXPORT_BATCH_SIZE + 1).map(|i| i.to_string()).collect();
+        let titles: Vec<&str> = titles.iter().map(String::as_str).collect();
+        create_streams(&repo, user_id, &titles).await;
+
+        let (_, body) = export(repo, user_id, query(None, false)).await;
+        let rows: Vec<ExportedStream> = body
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(rows.len() as i64, EXPORT_BATCH_SIZE + 1);
+        assert_eq!(rows.last().unwrap().title, EXPORT_BATCH_SIZE.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_export_validation() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        let user_repo = Arc::new(MockUserRepository::with_users(&[user_id]));
+
+        let result = export_user_streams(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            auth(Uuid::new_v4()),
+            Path(user_id.to_string()),
+            Query(ExportQuery::default()),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+        let result = export_user_streams(
+            State(repo),
+            State(user_repo),
+            auth(user_id),
+            Path(user_id.to_string()),
+            Query(query(Some("xml"), false)),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(
+                    msg,
+                    "formatはjsonl・csv・markdown-tar・markdown-zipのいずれかで指定してください"
+                )
+            }
+            _ => panic!("Unexpected error type"),
+        }
+    }
+}
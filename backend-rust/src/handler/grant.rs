@@ -0,0 +1,288 @@
+use super::user::parse_user_id;
+use crate::{
+    auth::AuthUser,
+    error::AppError,
+    model::{Grant, GrantScope},
+    repository::{GrantRepository, UserRepository},
+    schema::{
+        GrantListResponse, GrantRequest, GrantResponse, GrantScopeRequest, GrantScopeResponse,
+    },
+};
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn to_response(grant: Grant) -> GrantResponse {
+    GrantResponse {
+        grant_id: Some(grant.grant_id.to_string()),
+        owner_id: Some(grant.owner_id.to_string()),
+        grantee_id: Some(grant.grantee_id.to_string()),
+        scope: Some(match grant.scope {
+            GrantScope::Read => GrantScopeResponse::Read,
+            GrantScope::Write => GrantScopeResponse::Write,
+            GrantScope::Delete => GrantScopeResponse::Delete,
+        }),
+        created_at: Some(grant.created_at.to_rfc3339()),
+        expires_at: grant.expires_at.map(|e| e.to_rfc3339()),
+    }
+}
+
+/// 有効期限（RFC 3339 形式の未来の日時、省略可）を検証する（共有リンクの発行と共通）
+pub(crate) fn parse_expires_at(value: Option<&str>) -> Result<Option<DateTime<Utc>>, AppError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let expires_at = DateTime::parse_from_rfc3339(value)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|_| {
+            AppError::Validation("expiresAtはRFC 3339形式で指定してください".to_string())
+        })?;
+    if expires_at <= Utc::now() {
+        return Err(AppError::Validation(
+            "expiresAtには未来の日時を指定してください".to_string(),
+        ));
+    }
+
+    Ok(Some(expires_at))
+}
+
+/// 自分の配信の操作を他のユーザーに委任する（同じユーザーへの有効な委任は置き換える）
+pub async fn grant_access(
+    State(repo): State<Arc<dyn GrantRepository>>,
+    State(user_repo): State<Arc<dyn UserRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<GrantResponse>), AppError> {
+    let owner_id = parse_user_id(&user_id)?;
+    auth.ensure_user(owner_id)?;
+
+    let value: serde_json::Value = serde_json::from_slice(&body).map_err(|_| {
+        AppError::Validation("リクエストボディの形式が正しくありません".to_string())
+    })?;
+    let req: GrantRequest = serde_json::from_value(value).map_err(|_| {
+        AppError::Validation(
+            "granteeIdとscope（read, write, deleteのいずれか）を指定してください".to_string(),
+        )
+    })?;
+
+    let grantee_id = Uuid::parse_str(&req.grantee_id)
+        .map_err(|_| AppError::Validation("Invalid granteeId format".to_string()))?;
+    if grantee_id == owner_id {
+        return Err(AppError::Validation(
+            "自分自身には委任できません".to_string(),
+        ));
+    }
+    let expires_at = parse_expires_at(req.expires_at.as_deref())?;
+    if !user_repo.exists(grantee_id).await? {
+        return Err(AppError::Validation(
+            "委任先のユーザーが存在しません".to_string(),
+        ));
+    }
+
+    let grant = Grant {
+        grant_id: Uuid::new_v4(),
+        owner_id,
+        grantee_id,
+        scope: match req.scope {
+            GrantScopeRequest::Read => GrantScope::Read,
+            GrantScopeRequest::Write => GrantScope::Write,
+            GrantScopeRequest::Delete => GrantScope::Delete,
+        },
+        created_at: Utc::now(),
+        expires_at,
+        revoked_at: None,
+    };
+    let created = repo.create(&grant).await?;
+
+    Ok((StatusCode::CREATED, Json(to_response(created))))
+}
+
+pub async fn get_grants(
+    State(repo): State<Arc<dyn GrantRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+) -> Result<Json<GrantListResponse>, AppError> {
+    let owner_id = parse_user_id(&user_id)?;
+    auth.ensure_user(owner_id)?;
+
+    let grants = repo.find_active_by_owner(owner_id).await?;
+
+    Ok(Json(GrantListResponse {
+        items: Some(grants.into_iter().map(to_response).collect()),
+    }))
+}
+
+pub async fn revoke_grant(
+    State(repo): State<Arc<dyn GrantRepository>>,
+    auth: AuthUser,
+    Path((user_id, grant_id)): Path<(String, String)>,
+) -> Result<StatusCode, AppError> {
+    let owner_id = parse_user_id(&user_id)?;
+    auth.ensure_user(owner_id)?;
+    let grant_id = Uuid::parse_str(&grant_id)
+        .map_err(|_| AppError::Validation("Invalid grantId format".to_string()))?;
+
+    if !repo.revoke(owner_id, grant_id).await? {
+        return Err(AppError::NotFound("Grant not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Role;
+    use crate::repository::mock::{MockGrantRepository, MockUserRepository};
+
+    fn auth(user_id: Uuid) -> AuthUser {
+        AuthUser {
+            user_id,
+            role: Role::Owner,
+        }
+    }
+
+    async fn grant(
+        repo: &Arc<MockGrantRepository>,
+        user_repo: &Arc<MockUserRepository>,
+        owner_id: Uuid,
+        body: String,
+    ) -> Result<GrantResponse, AppError> {
+        grant_access(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            auth(owner_id),
+            Path(owner_id.to_string()),
+            Bytes::from(body),
+        )
+        .await
+        .map(|(_, Json(response))| response)
+    }
+
+    #[tokio::test]
+    async fn test_grant_list_and_revoke() {
+        let owner_id = Uuid::new_v4();
+        let grantee_id = Uuid::new_v4();
+        let repo = Arc::new(MockGrantRepository::default());
+        let user_repo = Arc::new(MockUserRepository::with_users(&[owner_id, grantee_id]));
+
+        let body = format!(r#"{{"granteeId":"{}","scope":"read"}}"#, grantee_id);
+        let created = grant(&repo, &user_repo, owner_id, body).await.unwrap();
+        assert_eq!(created.scope, Some(GrantScopeResponse::Read));
+        assert!(created.expires_at.is_none());
+
+        // 同じユーザーへの委任は置き換える
+        let body = format!(
+            r#"{{"granteeId":"{}","scope":"write","expiresAt":"2099-01-01T00:00:00+09:00"}}"#,
+            grantee_id
+        );
+        let replaced = grant(&repo, &user_repo, owner_id, body).await.unwrap();
+        assert_eq!(replaced.grant_id, created.grant_id);
+        assert_eq!(replaced.scope, Some(GrantScopeResponse::Write));
+        assert_eq!(
+            replaced.expires_at.as_deref(),
+            Some("2098-12-31T15:00:00+00:00")
+        );
+
+        let Json(list) = get_grants(
+            State(repo.clone()),
+            auth(owner_id),
+            Path(owner_id.to_string()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(list.items.unwrap().len(), 1);
+
+        let grant_id = created.grant_id.unwrap();
+        let status = revoke_grant(
+            State(repo.clone()),
+            auth(owner_id),
+            Path((owner_id.to_string(), grant_id.clone())),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(repo
+            .find_active(owner_id, grantee_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        // 取り消し済み
+        let result = revoke_grant(
+            State(repo),
+            auth(owner_id),
+            Path((owner_id.to_string(), grant_id)),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::NotFound(msg) => assert_eq!(msg, "Grant not found"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_grant_validation() {
+        let owner_id = Uuid::new_v4();
+        let grantee_id = Uuid::new_v4();
+        let repo = Arc::new(MockGrantRepository::default());
+        let user_repo = Arc::new(MockUserRepository::with_users(&[owner_id, grantee_id]));
+
+        let cases = [
+            (
+                format!(r#"{{"granteeId":"{}","scope":"admin"}}"#, grantee_id),
+                "granteeIdとscope（read, write, deleteのいずれか）を指定してください",
+            ),
+            (
+                format!(r#"{{"granteeId":"{}","scope":"read"}}"#, owner_id),
+                "自分自身には委任できません",
+            ),
+            (
+                format!(r#"{{"granteeId":"{}","scope":"read"}}"#, Uuid::new_v4()),
+                "委任先のユーザーが存在しません",
+            ),
+            (
+                format!(
+                    r#"{{"granteeId":"{}","scope":"read","expiresAt":"2000-01-01T00:00:00Z"}}"#,
+                    grantee_id
+                ),
+                "expiresAtには未来の日時を指定してください",
+            ),
+            (
+                format!(
+                    r#"{{"granteeId":"{}","scope":"read","expiresAt":"tomorrow"}}"#,
+                    grantee_id
+                ),
+                "expiresAtはRFC 3339形式で指定してください",
+            ),
+        ];
+        for (body, expected) in cases {
+            match grant(&repo, &user_repo, owner_id, body).await.unwrap_err() {
+                AppError::Validation(msg) => assert_eq!(msg, expected),
+                _ => panic!("Unexpected error type"),
+            }
+        }
+        assert!(repo.grants.lock().unwrap().is_empty());
+
+        // 他のユーザーの配信は委任できない
+        let result = grant_access(
+            State(repo),
+            State(user_repo),
+            auth(grantee_id),
+            Path(owner_id.to_string()),
+            Bytes::from(format!(
+                r#"{{"granteeId":"{}","scope":"read"}}"#,
+                grantee_id
+            )),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+    }
+}
@@ -0,0 +1,652 @@
+use super::stream::{self, parse_datetime};
+use super::user::parse_user_id;
+use crate::{
+    auth::{
+        permission::{self, StreamAction},
+        AuthUser,
+    },
+    error::AppError,
+    model::{Stream, StreamCursor, YoutubeVideo},
+    repository::StreamRepository,
+    schema::{
+        CreateStreamRequest, ImportStreamResult, ImportStreamRow, ImportStreamsResponse,
+        ServerErrorResponse,
+    },
+};
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use axum_extra::extract::Query;
+use serde::Deserialize;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    sync::Arc,
+};
+use uuid::Uuid;
+
+/// 1回のインポートで取り込める行数の上限
+pub(crate) const MAX_IMPORT_ROWS: usize = 10_000;
+/// インポートで受け付けるファイルの大きさの上限（ルーターで `DefaultBodyLimit` に指定する）
+pub const MAX_IMPORT_BYTES: usize = 10 * 1024 * 1024;
+/// 重複の判定のために登録済みの配信を読み込む際の1回あたりの件数
+const READ_BATCH_SIZE: i64 = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Jsonl,
+    Csv,
+}
+
+impl ImportFormat {
+    pub fn from_param(value: &str) -> Option<Self> {
+        match value {
+            "jsonl" => Some(Self::Jsonl),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct ImportQuery {
+    /// 省略した場合は jsonl
+    pub format: Option<String>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// ファイルから読み込んだ1行（読み込めなかった行は理由を持つ）
+pub(crate) struct ParsedRow {
+    pub line: usize,
+    pub row: Result<ImportStreamRow, String>,
+}
+
+/// 表計算ソフトが先頭に付ける BOM を取り除く
+fn strip_bom(body: &[u8]) -> &[u8] {
+    body.strip_prefix("\u{feff}".as_bytes()).unwrap_or(body)
+}
+
+/// 空行を除いた各行を JSON として読み込む（行番号は空行も数える）
+fn parse_jsonl(body: &[u8]) -> Result<Vec<ParsedRow>, AppError> {
+    let body = std::str::from_utf8(strip_bom(body))
+        .map_err(|_| AppError::Validation("ファイルはUTF-8で指定してください".to_string()))?;
+    Ok(body
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| ParsedRow {
+            line: i + 1,
+            row: serde_json::from_str(line)
+                .map_err(|e| format!("JSONとして読み込めません（{}）", e)),
+        })
+        .collect())
+}
+
+/// 1行目をヘッダー行として、列名で項目を判別する（引用符で囲んだ値は改行を含められる）
+fn parse_csv(body: &[u8]) -> Result<Vec<ParsedRow>, AppError> {
+    let mut reader = csv::Reader::from_reader(strip_bom(body));
+    let headers = reader
+        .headers()
+        .map_err(|e| AppError::Validation(format!("ヘッダー行を読み込めません（{}）", e)))?
+        .clone();
+
+    let mut rows = Vec::new();
+    let mut record = csv::StringRecord::new();
+    loop {
+        let line = reader.position().line() as usize;
+        match reader.read_record(&mut record) {
+            Ok(false) => break,
+            Ok(true) => rows.push(ParsedRow {
+                line,
+                row: record
+                    .deserialize(Some(&headers))
+                    .map_err(|e| format!("CSVの行を読み込めません（{}）", e)),
+            }),
+            Err(e) => rows.push(ParsedRow {
+                line,
+                row: Err(format!("CSVの行を読み込めません（{}）", e)),
+            }),
+        }
+    }
+    Ok(rows)
+}
+
+/// YouTube の動画ID（英数字・`-`・`_` の11文字）
+fn parse_video_id(value: &str) -> Result<String, AppError> {
+    let value = value.trim();
+    if value.len() != 11
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(AppError::Validation(
+            "youtubeVideoIdはYouTubeの動画IDで指定してください".to_string(),
+        ));
+    }
+    Ok(value.to_string())
+}
+
+/// 配信情報の登録と同じルールで検証する（作成日時は指定された値を引き継ぐ）
+///
+/// 元になった動画がある場合は動画IDもあわせて返す。
+fn to_stream(auth: &AuthUser, row: ImportStreamRow) -> Result<(Stream, Option<String>), AppError> {
+    if row.deleted_at.is_some() {
+        return Err(AppError::Validation(
+            "削除済みの配信は取り込めません".to_string(),
+        ));
+    }
+    let created_at = row
+        .created_at
+        .as_deref()
+        .map(|v| parse_datetime(v, "createdAt"))
+        .transpose()?;
+    let video_id = row
+        .youtube_video_id
+        .as_deref()
+        .filter(|v| !v.trim().is_empty())
+        .map(parse_video_id)
+        .transpose()?;
+
+    let stream = stream::new_stream(
+        auth,
+        CreateStreamRequest {
+            title: row.title.unwrap_or_default(),
+            description: row.description.unwrap_or_default(),
+            category: row.category,
+            visibility: row.visibility,
+            is_private: None,
+        },
+    )?;
+
+    Ok((
+        Stream {
+            created_at: created_at.unwrap_or(stream.created_at),
+            ..stream
+        },
+        video_id,
+    ))
+}
+
+/// 登録済みの配信の内容のハッシュと配信ID（削除済みの配信は含めない）
+async fn existing_hashes(
+    repo: &dyn StreamRepository,
+    user_id: Uuid,
+) -> Result<HashMap<Vec<u8>, Uuid>, AppError> {
+    let mut hashes = HashMap::new();
+    let mut after = None;
+    loop {
+        let streams = repo
+            .find_by_user_after(user_id, false, after, READ_BATCH_SIZE)
+            .await?;
+        for stream in &streams {
+            hashes
+                .entry(stream.content_hash())
+                .or_insert(stream.stream_id);
+        }
+        match streams.last() {
+            Some(last) if streams.len() as i64 == READ_BATCH_SIZE => {
+                after = Some(StreamCursor {
+                    created_at: last.created_at,
+                    stream_id: last.stream_id,
+                });
+            }
+            _ => break,
+        }
+    }
+    Ok(hashes)
+}
+
+fn result(line: usize, status: StatusCode, stream_id: Uuid) -> ImportStreamResult {
+    ImportStreamResult {
+        line: Some(line as i32),
+        status: Some(status.as_u16() as i32),
+        stream_id: Some(stream_id.to_string()),
+        stream: None,
+        error: None,
+    }
+}
+
+/// 登録済みの配信（またはファイル内の前の行）と重複した行
+fn duplicate(line: usize, stream_id: Uuid, message: &str) -> ImportStreamResult {
+    ImportStreamResult {
+        error: Some(Box::new(ServerErrorResponse {
+            error: Some("conflict".to_string()),
+            message: Some(message.to_string()),
+        })),
+        ..result(line, StatusCode::CONFLICT, stream_id)
+    }
+}
+
+fn failed(line: usize, error: AppError) -> ImportStreamResult {
+    let (status, error, message) = error.into_parts();
+    ImportStreamResult {
+        line: Some(line as i32),
+        status: Some(status.as_u16() as i32),
+        stream_id: None,
+        stream: None,
+        error: Some(Box::new(ServerErrorResponse {
+            error: Some(error),
+            message: Some(message),
+        })),
+    }
+}
+
+/// JSON Lines または CSV の各行を配信として登録する
+///
+/// 不正な行と重複した行を除き、残りの行を1つのトランザクションで登録する。
+/// `dry_run` の場合は登録せずに同じ結果を返す。
+pub async fn import_user_streams(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+    Query(query): Query<ImportQuery>,
+    body: Bytes,
+) -> Result<Json<ImportStreamsResponse>, AppError> {
+    let user_id = parse_user_id(&user_id)?;
+    auth.ensure_user(user_id)?;
+    permission::authorize(Some(&auth), StreamAction::Create, None, None)?;
+    let format = match query.format.as_deref() {
+        Some(value) => ImportFormat::from_param(value).ok_or_else(|| {
+            AppError::Validation("formatはjsonlまたはcsvで指定してください".to_string())
+        })?,
+        None => ImportFormat::Jsonl,
+    };
+
+    let rows = match format {
+        ImportFormat::Jsonl => parse_jsonl(&body)?,
+        ImportFormat::Csv => parse_csv(&body)?,
+    };
+    let response = import_rows(repo.as_ref(), &auth, rows, query.dry_run).await?;
+
+    Ok(Json(response))
+}
+
+/// 重複の判定に使う、登録済み・取り込み中の配信の内容のハッシュと元の動画
+pub(crate) struct KnownStreams {
+    hashes: HashMap<Vec<u8>, Uuid>,
+    video_ids: HashMap<String, Uuid>,
+}
+
+impl KnownStreams {
+    /// ユーザーの登録済みの配信を読み込む
+    pub(crate) async fn load(repo: &dyn StreamRepository, user_id: Uuid) -> Result<Self, AppError> {
+        Ok(Self {
+            hashes: existing_hashes(repo, user_id).await?,
+            video_ids: repo
+                .find_youtube_videos(user_id)
+                .await?
+                .into_iter()
+                .map(|v| (v.video_id, v.stream_id))
+                .collect(),
+        })
+    }
+}
+
+/// 読み込んだ行を検証・重複の判定をして登録する（入力形式によらない共通処理）
+pub(crate) async fn import_rows(
+    repo: &dyn StreamRepository,
+    auth: &AuthUser,
+    rows: Vec<ParsedRow>,
+    dry_run: bool,
+) -> Result<ImportStreamsResponse, AppError> {
+    if rows.is_empty() {
+        return Err(AppError::Validation("取り込む行がありません".to_string()));
+    }
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(AppError::Validation(format!(
+            "1回に取り込める行数は{}行までです",
+            MAX_IMPORT_ROWS
+        )));
+    }
+
+    let mut known = KnownStreams::load(repo, auth.user_id).await?;
+    let items = import_chunk(repo, auth, rows, dry_run, &mut known).await?;
+    Ok(summarize(dry_run, items))
+}
+
+/// `known` と重複しない行を1つのトランザクションで登録し、行ごとの結果を返す
+///
+/// 登録した（`dry_run` では登録する）配信は `known` に加えるため、続けて呼び出すと前の呼び出しの行とも重複を判定する。
+/// 行数の上限は確認しないため、コマンドライン（`cli`）からは `MAX_IMPORT_ROWS` 行ずつに分けて呼び出す。
+pub(crate) async fn import_chunk(
+    repo: &dyn StreamRepository,
+    auth: &AuthUser,
+    rows: Vec<ParsedRow>,
+    dry_run: bool,
+    known: &mut KnownStreams,
+) -> Result<Vec<ImportStreamResult>, AppError> {
+    // 登録済みの配信と、ファイル内の前の行と同じ動画・同じ内容の行は登録しない
+    let mut items = Vec::with_capacity(rows.len());
+    let mut streams = Vec::new();
+    let mut videos = Vec::new();
+    for ParsedRow { line, row } in rows {
+        let (stream, video_id) = match row
+            .map_err(AppError::Validation)
+            .and_then(|row| to_stream(auth, row))
+        {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                items.push(failed(line, e));
+                continue;
+            }
+        };
+        if let Some(stream_id) = video_id.as_ref().and_then(|id| known.video_ids.get(id)) {
+            items.push(duplicate(line, *stream_id, "同じ動画の配信が登録済みです"));
+            continue;
+        }
+        match known.hashes.entry(stream.content_hash()) {
+            Entry::Occupied(entry) => items.push(duplicate(
+                line,
+                *entry.get(),
+                "同じ内容の配信が登録済みです",
+            )),
+            Entry::Vacant(entry) => {
+                entry.insert(stream.stream_id);
+                if let Some(video_id) = video_id {
+                    known.video_ids.insert(video_id.clone(), stream.stream_id);
+                    videos.push(YoutubeVideo {
+                        stream_id: stream.stream_id,
+                        video_id,
+                    });
+                }
+                items.push(result(line, StatusCode::CREATED, stream.stream_id));
+                streams.push(stream);
+            }
+        }
+    }
+
+    let created = if dry_run || streams.is_empty() {
+        streams
+    } else {
+        repo.create_many_with_videos(&streams, &videos).await?
+    };
+    // 登録した順に、対応する行の結果へ配信を設定する
+    let mut created = created.into_iter();
+    for item in items
+        .iter_mut()
+        .filter(|i| i.status == Some(StatusCode::CREATED.as_u16() as i32))
+    {
+        item.stream = created.next().map(|s| Box::new(stream::to_response(s)));
+    }
+
+    Ok(items)
+}
+
+/// 行ごとの結果を集計する
+pub(crate) fn summarize(dry_run: bool, items: Vec<ImportStreamResult>) -> ImportStreamsResponse {
+    let count = |status: fn(i32) -> bool| {
+        items
+            .iter()
+            .filter(|i| i.status.is_some_and(status))
+            .count()
+    };
+    ImportStreamsResponse {
+        dry_run: Some(dry_run),
+        created: Some(count(|s| s == 201) as i32),
+        duplicates: Some(count(|s| s == 409) as i32),
+        failed: Some(count(|s| s == 400) as i32),
+        items: Some(items),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Role, Visibility};
+    use crate::repository::mock::MockStreamRepository;
+    use chrono::{DateTime, Utc};
+
+    fn auth(user_id: Uuid) -> AuthUser {
+        AuthUser {
+            user_id,
+            role: Role::Owner,
+        }
+    }
+
+    fn query(format: Option<&str>, dry_run: bool) -> ImportQuery {
+        ImportQuery {
+            format: format.map(str::to_string),
+            dry_run,
+        }
+    }
+
+    async fn import(
+        repo: Arc<MockStreamRepository>,
+        user_id: Uuid,
+        query: ImportQuery,
+        body: &str,
+    ) -> Result<ImportStreamsResponse, AppError> {
+        let Json(response) = import_user_streams(
+            State(repo),
+            auth(user_id),
+            Path(user_id.to_string()),
+            Query(query),
+            Bytes::from(body.to_string()),
+        )
+        .await?;
+        Ok(response)
+    }
+
+    fn statuses(response: &ImportStreamsResponse) -> Vec<(i32, i32)> {
+        response
+            .items
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|i| (i.line.unwrap(), i.status.unwrap()))
+            .collect()
+    }
+
+    const JSONL: &str = r#"{"title":"first","description":"desc","createdAt":"2020-01-02T03:04:05Z"}
+{"title":"","description":"desc","visibility":"published"}
+
+{"title":"draft","description":"desc","visibility":"draft"}
+{"title":"first","description":"desc"}
+not json
+{"title":"deleted","description":"desc","deletedAt":"2021-01-01T00:00:00Z"}
+"#;
+
+    #[tokio::test]
+    async fn test_import_jsonl() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+
+        let response = import(repo.clone(), user_id, query(None, false), JSONL)
+            .await
+            .unwrap();
+        assert_eq!(response.dry_run, Some(false));
+        assert_eq!(
+            statuses(&response),
+            vec![(1, 201), (2, 400), (4, 201), (5, 409), (6, 400), (7, 400)]
+        );
+        assert_eq!(response.created, Some(2));
+        assert_eq!(response.duplicates, Some(1));
+        assert_eq!(response.failed, Some(3));
+
+        let items = response.items.unwrap();
+        assert_eq!(
+            items[1].error.as_ref().unwrap().message.as_deref(),
+            Some("タイトルは必須です")
+        );
+        // 重複した行は同じ内容の配信のIDを返す
+        assert_eq!(items[3].stream_id, items[0].stream_id);
+
+        // 作成日時を引き継ぎ、配信者は認証したユーザーとする
+        let streams = repo.streams.lock().unwrap().clone();
+        assert_eq!(streams.len(), 2);
+        assert_eq!(
+            streams[0].created_at,
+            "2020-01-02T03:04:05Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert!(streams.iter().all(|s| s.user_id == user_id));
+        assert_eq!(streams[1].visibility, Visibility::Draft);
+        assert_eq!(
+            items[0].stream.as_ref().unwrap().stream_id,
+            Some(streams[0].stream_id.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_skips_existing_streams() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        import(repo.clone(), user_id, query(None, false), JSONL)
+            .await
+            .unwrap();
+
+        // 同じファイルをもう一度取り込んでも登録しない
+        let response = import(repo.clone(), user_id, query(None, false), JSONL)
+            .await
+            .unwrap();
+        assert_eq!(response.created, Some(0));
+        assert_eq!(response.duplicates, Some(3));
+        assert_eq!(repo.streams.lock().unwrap().len(), 2);
+
+        // 他のユーザーの配信とは重複しない
+        let response = import(repo.clone(), Uuid::new_v4(), query(None, false), JSONL)
+            .await
+            .unwrap();
+        assert_eq!(response.created, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_import_dry_run() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+
+        let response = import(repo.clone(), user_id, query(None, true), JSONL)
+            .await
+            .unwrap();
+        assert_eq!(response.dry_run, Some(true));
+        assert_eq!(response.created, Some(2));
+        assert_eq!(response.failed, Some(3));
+        assert_eq!(
+            response.items.unwrap()[0]
+                .stream
+                .as_ref()
+                .unwrap()
+                .title
+                .as_deref(),
+            Some("first")
+        );
+        assert!(repo.streams.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_import_csv() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        // エクスポートした列（streamId など）を含み、値に改行・カンマ・引用符を含む
+        let body =
+            "\u{feff}streamId,userId,title,description,category,visibility,createdAt,deletedAt\n\
+            1,2,first,\"1行目\n2行目, \"\"引用\"\"\",game,private,2020-01-02T03:04:05Z,\n\
+            3,4,second,desc,,,,\n\
+            5,6,third,desc\n\
+            7,8,fourth,desc,,secret,,\n";
+
+        let response = import(repo.clone(), user_id, query(Some("csv"), false), body)
+            .await
+            .unwrap();
+        assert_eq!(
+            statuses(&response),
+            vec![(2, 201), (4, 201), (5, 400), (6, 400)]
+        );
+
+        let streams = repo.streams.lock().unwrap().clone();
+        assert_eq!(streams[0].description, "1行目\n2行目, \"引用\"");
+        assert_eq!(streams[0].category, "game");
+        assert_eq!(streams[0].visibility, Visibility::Private);
+        assert_eq!(streams[1].visibility, Visibility::Published);
+    }
+
+    #[tokio::test]
+    async fn test_import_youtube_video_id() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        let body = r#"{"title":"a","description":"","youtubeVideoId":"dQw4w9WgXcQ"}
+{"title":"b","description":"","youtubeVideoId":"dQw4w9WgXcQ"}
+{"title":"c","description":"","youtubeVideoId":"invalid"}
+{"title":"d","description":"","youtubeVideoId":"aaaaaaaaaaa"}
+"#;
+
+        let response = import(repo.clone(), user_id, query(None, false), body)
+            .await
+            .unwrap();
+        assert_eq!(
+            statuses(&response),
+            vec![(1, 201), (2, 409), (3, 400), (4, 201)]
+        );
+        let items = response.items.unwrap();
+        assert_eq!(items[1].stream_id, items[0].stream_id);
+        assert_eq!(
+            items[1].error.as_ref().unwrap().message.as_deref(),
+            Some("同じ動画の配信が登録済みです")
+        );
+        let videos = repo.find_youtube_videos(user_id).await.unwrap();
+        assert_eq!(
+            videos
+                .iter()
+                .map(|v| v.video_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["dQw4w9WgXcQ", "aaaaaaaaaaa"]
+        );
+
+        // 内容が変わっていても、同じ動画は登録済みとして扱う
+        let body = r#"{"title":"a (edited)","description":"","youtubeVideoId":"dQw4w9WgXcQ"}"#;
+        let response = import(repo, user_id, query(None, false), body)
+            .await
+            .unwrap();
+        assert_eq!(statuses(&response), vec![(1, 409)]);
+        assert_eq!(
+            response.items.unwrap()[0].stream_id,
+            Some(videos[0].stream_id.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_validation() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+
+        let result = import_user_streams(
+            State(repo.clone()),
+            auth(Uuid::new_v4()),
+            Path(user_id.to_string()),
+            Query(ImportQuery::default()),
+            Bytes::from(JSONL),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+
+        for (query, body, message) in [
+            (
+                query(Some("xml"), false),
+                JSONL,
+                "formatはjsonlまたはcsvで指定してください",
+            ),
+            (query(None, false), "\n\n", "取り込む行がありません"),
+            (query(Some("csv"), false), "", "取り込む行がありません"),
+        ] {
+            match import(repo.clone(), user_id, query, body)
+                .await
+                .unwrap_err()
+            {
+                AppError::Validation(msg) => assert_eq!(msg, message),
+                _ => panic!("Unexpected error type"),
+            }
+        }
+
+        let result = import_user_streams(
+            State(repo),
+            auth(user_id),
+            Path(user_id.to_string()),
+            Query(ImportQuery::default()),
+            Bytes::from_static(b"\xff\xfe"),
+        )
+        .await;
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
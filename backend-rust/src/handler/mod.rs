@@ -1,3 +1,25 @@
+pub mod admin;
+pub mod api_key;
+pub mod batch;
+pub mod etag;
+pub mod export;
+pub mod grant;
+pub mod import;
+pub mod pagination;
+pub mod session;
+pub mod share;
 pub mod stream;
+pub mod user;
 
-pub use stream::{create_stream, delete_stream, get_stream, get_streams};
+pub use admin::{get_all_streams, update_user_role};
+pub use api_key::{get_api_keys, issue_api_key, revoke_api_key};
+pub use batch::{batch_create_streams, batch_delete_streams, batch_get_streams};
+pub use export::export_user_streams;
+pub use grant::{get_grants, grant_access, revoke_grant};
+pub use import::import_user_streams;
+pub use session::{get_current_session, login, logout};
+pub use share::{create_share_link, get_share_links, get_shared_stream, revoke_share_link};
+pub use stream::{
+    create_stream, delete_stream, get_stream, get_streams, get_user_streams, update_stream,
+};
+pub use user::{create_user, delete_user, get_user, get_users, update_user};
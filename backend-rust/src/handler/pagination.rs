@@ -0,0 +1,251 @@
+use crate::{error::AppError, model::StreamCursor, schema::PaginationLinks};
+use axum::http::{HeaderValue, Uri};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::DateTime;
+use uuid::Uuid;
+
+/// 一覧レスポンスのページ位置
+#[derive(Debug, Clone)]
+pub struct Pagination {
+    pub limit: i32,
+    pub offset: i32,
+    pub total: i64,
+    pub has_next: bool,
+    pub next_cursor: Option<String>,
+    /// カーソル指定で取得したページかどうか
+    pub by_cursor: bool,
+}
+
+impl Pagination {
+    pub fn has_prev(&self) -> bool {
+        self.by_cursor || self.offset > 0
+    }
+
+    /// offset 指定時の次ページの取得開始位置
+    pub fn next_offset(&self) -> Option<i32> {
+        (self.has_next && !self.by_cursor).then_some(self.offset + self.limit)
+    }
+
+    fn last_offset(&self) -> i32 {
+        if self.total <= 0 {
+            return 0;
+        }
+        let limit = self.limit as i64;
+        (((self.total - 1) / limit) * limit) as i32
+    }
+
+    /// リクエストURLのクエリ（絞り込み条件など）を引き継いだページ移動用URLを生成する
+    ///
+    /// カーソル指定のページからは前のページを辿れないため `prev` は省略する。
+    pub fn links(&self, uri: &Uri) -> PaginationLinks {
+        let next = if self.by_cursor {
+            self.next_cursor
+                .as_deref()
+                .map(|cursor| page_url(uri, "cursor", cursor))
+        } else {
+            self.next_offset()
+                .map(|offset| page_url(uri, "offset", &offset.to_string()))
+        };
+        let prev = (!self.by_cursor && self.offset > 0).then(|| {
+            let offset = (self.offset - self.limit).max(0);
+            page_url(uri, "offset", &offset.to_string())
+        });
+
+        PaginationLinks {
+            first: Some(page_url(uri, "offset", "0")),
+            prev,
+            next,
+            last: Some(page_url(uri, "offset", &self.last_offset().to_string())),
+        }
+    }
+}
+
+/// RFC 8288 形式の `Link` ヘッダー値を生成する
+pub fn link_header(links: &PaginationLinks) -> Option<HeaderValue> {
+    let value = [
+        ("first", &links.first),
+        ("prev", &links.prev),
+        ("next", &links.next),
+        ("last", &links.last),
+    ]
+    .into_iter()
+    .filter_map(|(rel, url)| {
+        url.as_ref()
+            .map(|url| format!("<{}>; rel=\"{}\"", url, rel))
+    })
+    .collect::<Vec<_>>()
+    .join(", ");
+
+    if value.is_empty() {
+        return None;
+    }
+    HeaderValue::from_str(&value).ok()
+}
+
+/// ページ位置を表すクエリ（offset / cursor）を差し替えたURLを返す
+fn page_url(uri: &Uri, key: &str, value: &str) -> String {
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    if let Some(query) = uri.query() {
+        for (k, v) in url::form_urlencoded::parse(query.as_bytes()) {
+            if k != "offset" && k != "cursor" {
+                serializer.append_pair(&k, &v);
+            }
+        }
+    }
+    serializer.append_pair(key, value);
+
+    format!("{}?{}", uri.path(), serializer.finish())
+}
+
+/// カーソルをクライアントに渡す不透明な文字列に変換する
+pub fn encode_cursor(cursor: &StreamCursor) -> String {
+    let raw = format!(
+        "{}:{}",
+        cursor.created_at.timestamp_micros(),
+        cursor.stream_id
+    );
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// `encode_cursor` で生成した文字列をカーソルに戻す
+pub fn decode_cursor(value: &str) -> Result<StreamCursor, AppError> {
+    let invalid = || AppError::Validation("Invalid cursor format".to_string());
+
+    let raw = URL_SAFE_NO_PAD.decode(value).map_err(|_| invalid())?;
+    let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+    let (micros, stream_id) = raw.split_once(':').ok_or_else(invalid)?;
+
+    let created_at = micros
+        .parse::<i64>()
+        .ok()
+        .and_then(DateTime::from_timestamp_micros)
+        .ok_or_else(invalid)?;
+    let stream_id = Uuid::parse_str(stream_id).map_err(|_| invalid())?;
+
+    Ok(StreamCursor {
+        created_at,
+        stream_id,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pagination(offset: i32, total: i64, has_next: bool) -> Pagination {
+        Pagination {
+            limit: 10,
+            offset,
+            total,
+            has_next,
+            next_cursor: None,
+            by_cursor: false,
+        }
+    }
+
+    #[test]
+    fn test_offset_links_keep_filters() {
+        let uri: Uri = "/api/v2/streams?category=%E9%9B%91%E8%AB%87&limit=10&offset=10"
+            .parse()
+            .unwrap();
+        let page = pagination(10, 35, true);
+
+        assert!(page.has_prev());
+        assert_eq!(page.next_offset(), Some(20));
+
+        let links = page.links(&uri);
+        assert_eq!(
+            links.first.as_deref(),
+            Some("/api/v2/streams?category=%E9%9B%91%E8%AB%87&limit=10&offset=0")
+        );
+        assert_eq!(
+            links.prev.as_deref(),
+            Some("/api/v2/streams?category=%E9%9B%91%E8%AB%87&limit=10&offset=0")
+        );
+        assert_eq!(
+            links.next.as_deref(),
+            Some("/api/v2/streams?category=%E9%9B%91%E8%AB%87&limit=10&offset=20")
+        );
+        assert_eq!(
+            links.last.as_deref(),
+            Some("/api/v2/streams?category=%E9%9B%91%E8%AB%87&limit=10&offset=30")
+        );
+    }
+
+    #[test]
+    fn test_first_and_last_page() {
+        let uri: Uri = "/api/v2/streams".parse().unwrap();
+
+        let first = pagination(0, 5, false);
+        assert!(!first.has_prev());
+        assert_eq!(first.next_offset(), None);
+        let links = first.links(&uri);
+        assert!(links.prev.is_none());
+        assert!(links.next.is_none());
+        assert_eq!(links.last.as_deref(), Some("/api/v2/streams?offset=0"));
+
+        let empty = pagination(0, 0, false);
+        assert_eq!(
+            empty.links(&uri).last.as_deref(),
+            Some("/api/v2/streams?offset=0")
+        );
+    }
+
+    #[test]
+    fn test_cursor_links() {
+        let uri: Uri = "/api/v2/streams?cursor=abc&limit=10".parse().unwrap();
+        let page = Pagination {
+            next_cursor: Some("def".to_string()),
+            by_cursor: true,
+            ..pagination(0, 35, true)
+        };
+
+        assert!(page.has_prev());
+        assert_eq!(page.next_offset(), None);
+
+        let links = page.links(&uri);
+        assert!(links.prev.is_none());
+        assert_eq!(
+            links.next.as_deref(),
+            Some("/api/v2/streams?limit=10&cursor=def")
+        );
+    }
+
+    #[test]
+    fn test_link_header() {
+        let links = PaginationLinks {
+            first: Some("/api/v2/streams?offset=0".to_string()),
+            prev: None,
+            next: Some("/api/v2/streams?offset=10".to_string()),
+            last: Some("/api/v2/streams?offset=20".to_string()),
+        };
+
+        let header = link_header(&links).unwrap();
+        assert_eq!(
+            header.to_str().unwrap(),
+            r#"</api/v2/streams?offset=0>; rel="first", </api/v2/streams?offset=10>; rel="next", </api/v2/streams?offset=20>; rel="last""#
+        );
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = StreamCursor {
+            created_at: DateTime::from_timestamp_micros(1_733_652_000_123_456).unwrap(),
+            stream_id: Uuid::new_v4(),
+        };
+
+        let encoded = encode_cursor(&cursor);
+        let decoded = decode_cursor(&encoded).expect("Failed to decode cursor");
+        assert_eq!(decoded, cursor);
+    }
+
+    #[test]
+    fn test_decode_invalid_cursor() {
+        for value in ["", "not-base64!", "bm8tc2VwYXJhdG9y", "MTIzOm5vdC1hLXV1aWQ"] {
+            match decode_cursor(value) {
+                Err(AppError::Validation(msg)) => assert_eq!(msg, "Invalid cursor format"),
+                _ => panic!("Unexpected result for {:?}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    auth::{session, AuthUser},
+    config::SessionConfig,
+    error::AppError,
+    model::Session,
+    repository::SessionRepository,
+    schema::SessionResponse,
+};
+use axum::{extract::State, http::StatusCode, Extension, Json};
+use axum_extra::extract::cookie::CookieJar;
+use std::sync::Arc;
+
+fn to_response(session: Session) -> SessionResponse {
+    SessionResponse {
+        user_id: Some(session.user_id.to_string()),
+        csrf_token: Some(session.csrf_token),
+        expires_at: Some(session.expires_at.to_rfc3339()),
+    }
+}
+
+/// 認証済みのユーザー（APIキー・JWT・既存のセッション）で新しいセッションを発行する
+///
+/// セッション固定攻撃を防ぐため、Cookie で認証していた場合は元のセッションを削除する。
+pub async fn login(
+    State(repo): State<Arc<dyn SessionRepository>>,
+    State(config): State<SessionConfig>,
+    auth: AuthUser,
+    current: Option<Extension<Session>>,
+    jar: CookieJar,
+) -> Result<(StatusCode, CookieJar, Json<SessionResponse>), AppError> {
+    if let Some(Extension(current)) = current {
+        repo.delete(current.session_id).await?;
+    }
+
+    let (new_session, token) = session::generate(auth.user_id, &config);
+    let created = repo.create(&new_session).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        jar.add(session::session_cookie(token, &config)),
+        Json(to_response(created)),
+    ))
+}
+
+pub async fn get_current_session(
+    current: Option<Extension<Session>>,
+) -> Result<Json<SessionResponse>, AppError> {
+    let Extension(current) =
+        current.ok_or_else(|| AppError::Unauthorized("ログインしていません".to_string()))?;
+
+    Ok(Json(to_response(current)))
+}
+
+/// ログインしていない場合も Cookie を破棄して 204 を返す
+pub async fn logout(
+    State(repo): State<Arc<dyn SessionRepository>>,
+    State(config): State<SessionConfig>,
+    current: Option<Extension<Session>>,
+    jar: CookieJar,
+) -> Result<(StatusCode, CookieJar), AppError> {
+    if let Some(Extension(current)) = current {
+        repo.delete(current.session_id).await?;
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        jar.add(session::removal_cookie(&config)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Role;
+    use crate::repository::mock::MockSessionRepository;
+    use axum::http::{header, HeaderMap};
+    use axum::response::IntoResponse;
+    use uuid::Uuid;
+
+    fn set_cookie(jar: CookieJar) -> String {
+        let response = (jar, ()).into_response();
+        response.headers()[header::SET_COOKIE]
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_login_and_logout() {
+        let repo = Arc::new(MockSessionRepository::default());
+        let config = SessionConfig::default();
+        let user_id = Uuid::new_v4();
+        let auth = AuthUser {
+            user_id,
+            role: Role::Owner,
+        };
+
+        let (status, jar, Json(response)) = login(
+            State(repo.clone()),
+            State(config.clone()),
+            auth,
+            None,
+            CookieJar::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(response.user_id, Some(user_id.to_string()));
+
+        // 発行した Cookie のトークンでセッションを取得できる
+        let token = jar
+            .get(session::SESSION_COOKIE)
+            .unwrap()
+            .value()
+            .to_string();
+        assert!(set_cookie(jar).contains("HttpOnly"));
+        let current = repo
+            .find_active_by_token_hash(&session::hash_token(&token))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            response.csrf_token.as_deref(),
+            Some(current.csrf_token.as_str())
+        );
+
+        let Json(response) = get_current_session(Some(Extension(current.clone())))
+            .await
+            .unwrap();
+        assert_eq!(response.user_id, Some(user_id.to_string()));
+
+        // 再ログインすると元のセッションは無効になる
+        let (_, _, Json(relogin)) = login(
+            State(repo.clone()),
+            State(config.clone()),
+            auth,
+            Some(Extension(current.clone())),
+            CookieJar::new(),
+        )
+        .await
+        .unwrap();
+        assert_ne!(relogin.csrf_token, response.csrf_token);
+        assert_eq!(repo.sessions.lock().unwrap().len(), 1);
+
+        let latest = repo.sessions.lock().unwrap()[0].clone();
+        let (status, jar) = logout(
+            State(repo.clone()),
+            State(config),
+            Some(Extension(latest)),
+            CookieJar::from_headers(&HeaderMap::new()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(set_cookie(jar).contains("Max-Age=0"));
+        assert!(repo.sessions.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_current_session_requires_login() {
+        match get_current_session(None).await {
+            Err(AppError::Unauthorized(msg)) => assert_eq!(msg, "ログインしていません"),
+            _ => panic!("Unexpected result"),
+        }
+    }
+}
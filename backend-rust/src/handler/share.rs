@@ -0,0 +1,377 @@
+use super::{grant::parse_expires_at, stream};
+use crate::{
+    auth::{permission::StreamAction, session::hash_token, share, AuthUser},
+    error::AppError,
+    model::ShareLink,
+    repository::{GrantRepository, ShareLinkRepository, StreamRepository},
+    schema::{CreateShareLinkRequest, ShareLinkListResponse, ShareLinkResponse, StreamResponse},
+};
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::{header, StatusCode},
+    Json,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// トークンは発行時のみ `token` として渡す
+fn to_response(link: ShareLink, token: Option<String>) -> ShareLinkResponse {
+    ShareLinkResponse {
+        link_id: Some(link.link_id.to_string()),
+        stream_id: Some(link.stream_id.to_string()),
+        token,
+        created_at: Some(link.created_at.to_rfc3339()),
+        expires_at: link.expires_at.map(|e| e.to_rfc3339()),
+        access_count: Some(link.access_count),
+        last_accessed_at: link.last_accessed_at.map(|a| a.to_rfc3339()),
+    }
+}
+
+pub async fn create_share_link(
+    State(repo): State<Arc<dyn ShareLinkRepository>>,
+    State(stream_repo): State<Arc<dyn StreamRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    auth: AuthUser,
+    Path(stream_id): Path<String>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<ShareLinkResponse>), AppError> {
+    let id = stream::parse_stream_id(&stream_id)?;
+    let req: CreateShareLinkRequest = if body.is_empty() {
+        CreateShareLinkRequest::default()
+    } else {
+        serde_json::from_slice(&body).map_err(|_| {
+            AppError::Validation("リクエストボディの形式が正しくありません".to_string())
+        })?
+    };
+    let expires_at = parse_expires_at(req.expires_at.as_deref())?;
+
+    stream::find_authorized(
+        stream_repo.as_ref(),
+        grant_repo.as_ref(),
+        Some(&auth),
+        StreamAction::Share,
+        id,
+    )
+    .await?;
+
+    let (link, token) = share::generate(id, auth.user_id, expires_at);
+    let created = repo.create(&link).await?;
+
+    Ok((StatusCode::CREATED, Json(to_response(created, Some(token)))))
+}
+
+pub async fn get_share_links(
+    State(repo): State<Arc<dyn ShareLinkRepository>>,
+    State(stream_repo): State<Arc<dyn StreamRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    auth: AuthUser,
+    Path(stream_id): Path<String>,
+) -> Result<Json<ShareLinkListResponse>, AppError> {
+    let id = stream::parse_stream_id(&stream_id)?;
+    stream::find_authorized(
+        stream_repo.as_ref(),
+        grant_repo.as_ref(),
+        Some(&auth),
+        StreamAction::Share,
+        id,
+    )
+    .await?;
+
+    let links = repo.find_active_by_stream(id).await?;
+
+    Ok(Json(ShareLinkListResponse {
+        items: Some(links.into_iter().map(|l| to_response(l, None)).collect()),
+    }))
+}
+
+pub async fn revoke_share_link(
+    State(repo): State<Arc<dyn ShareLinkRepository>>,
+    State(stream_repo): State<Arc<dyn StreamRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    auth: AuthUser,
+    Path((stream_id, link_id)): Path<(String, String)>,
+) -> Result<StatusCode, AppError> {
+    let id = stream::parse_stream_id(&stream_id)?;
+    let link_id = Uuid::parse_str(&link_id)
+        .map_err(|_| AppError::Validation("Invalid linkId format".to_string()))?;
+    stream::find_authorized(
+        stream_repo.as_ref(),
+        grant_repo.as_ref(),
+        Some(&auth),
+        StreamAction::Share,
+        id,
+    )
+    .await?;
+
+    if !repo.revoke(id, link_id).await? {
+        return Err(AppError::NotFound("Share link not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 認証不要。取り消し・期限切れがすぐ反映されるよう、レスポンスはキャッシュさせない
+pub async fn get_shared_stream(
+    State(repo): State<Arc<dyn ShareLinkRepository>>,
+    Path(token): Path<String>,
+) -> Result<
+    (
+        [(header::HeaderName, &'static str); 1],
+        Json<StreamResponse>,
+    ),
+    AppError,
+> {
+    let stream = repo
+        .access(&hash_token(&token))
+        .await?
+        .ok_or_else(|| AppError::NotFound("Share link not found".to_string()))?;
+
+    Ok((
+        [(header::CACHE_CONTROL, "no-store")],
+        Json(stream::to_response(stream)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Grant, GrantScope, Role, Stream, Visibility};
+    use crate::repository::mock::{
+        MockGrantRepository, MockShareLinkRepository, MockStreamRepository, MockUserRepository,
+    };
+    use chrono::Utc;
+
+    struct Repos {
+        links: Arc<MockShareLinkRepository>,
+        streams: Arc<MockStreamRepository>,
+        grants: Arc<MockGrantRepository>,
+    }
+
+    impl Repos {
+        fn new() -> Self {
+            let streams = Arc::new(MockStreamRepository::default());
+            let grants = Arc::new(MockGrantRepository::default());
+            Self {
+                links: Arc::new(MockShareLinkRepository::new(
+                    streams.clone(),
+                    Arc::new(MockUserRepository::default()),
+                    grants.clone(),
+                )),
+                streams,
+                grants,
+            }
+        }
+    }
+
+    fn auth(user_id: Uuid) -> AuthUser {
+        AuthUser {
+            user_id,
+            role: Role::Owner,
+        }
+    }
+
+    async fn create_private_stream(repos: &Repos, owner_id: Uuid) -> Stream {
+        repos
+            .streams
+            .create(&Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: owner_id,
+                title: "title".to_string(),
+                description: "共有したい概要欄".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Private,
+                version: 1,
+                created_at: Utc::now(),
+                deleted_at: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    async fn create(
+        repos: &Repos,
+        auth: AuthUser,
+        stream_id: Uuid,
+        body: &'static [u8],
+    ) -> Result<ShareLinkResponse, AppError> {
+        create_share_link(
+            State(repos.links.clone()),
+            State(repos.streams.clone()),
+            State(repos.grants.clone()),
+            auth,
+            Path(stream_id.to_string()),
+            Bytes::from_static(body),
+        )
+        .await
+        .map(|(_, Json(response))| response)
+    }
+
+    async fn get_shared(repos: &Repos, token: &str) -> Result<StreamResponse, AppError> {
+        get_shared_stream(State(repos.links.clone()), Path(token.to_string()))
+            .await
+            .map(|(_, Json(response))| response)
+    }
+
+    #[tokio::test]
+    async fn test_share_access_and_revoke() {
+        let repos = Repos::new();
+        let owner_id = Uuid::new_v4();
+        let stream = create_private_stream(&repos, owner_id).await;
+
+        let created = create(&repos, auth(owner_id), stream.stream_id, b"")
+            .await
+            .unwrap();
+        let token = created.token.clone().unwrap();
+        assert_eq!(created.access_count, Some(0));
+
+        // 未認証でも非公開の配信を取得できる
+        for _ in 0..2 {
+            let shared = get_shared(&repos, &token).await.unwrap();
+            assert_eq!(shared.description.as_deref(), Some("共有したい概要欄"));
+        }
+
+        // 一覧にはトークンを含めず、アクセス回数を返す
+        let Json(list) = get_share_links(
+            State(repos.links.clone()),
+            State(repos.streams.clone()),
+            State(repos.grants.clone()),
+            auth(owner_id),
+            Path(stream.stream_id.to_string()),
+        )
+        .await
+        .unwrap();
+        let items = list.items.unwrap();
+        assert_eq!(items.len(), 1);
+        assert!(items[0].token.is_none());
+        assert_eq!(items[0].access_count, Some(2));
+        assert!(items[0].last_accessed_at.is_some());
+
+        let link_id = created.link_id.unwrap();
+        let status = revoke_share_link(
+            State(repos.links.clone()),
+            State(repos.streams.clone()),
+            State(repos.grants.clone()),
+            auth(owner_id),
+            Path((stream.stream_id.to_string(), link_id.clone())),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        match get_shared(&repos, &token).await.unwrap_err() {
+            AppError::NotFound(msg) => assert_eq!(msg, "Share link not found"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        let result = revoke_share_link(
+            State(repos.links.clone()),
+            State(repos.streams.clone()),
+            State(repos.grants.clone()),
+            auth(owner_id),
+            Path((stream.stream_id.to_string(), link_id)),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_link_created_by_grantee_after_revocation() {
+        let repos = Repos::new();
+        let owner_id = Uuid::new_v4();
+        let grantee_id = Uuid::new_v4();
+        let stream = create_private_stream(&repos, owner_id).await;
+        let grant = repos
+            .grants
+            .create(&Grant {
+                grant_id: Uuid::new_v4(),
+                owner_id,
+                grantee_id,
+                scope: GrantScope::Write,
+                created_at: Utc::now(),
+                expires_at: None,
+                revoked_at: None,
+            })
+            .await
+            .unwrap();
+
+        let token = create(&repos, auth(grantee_id), stream.stream_id, b"")
+            .await
+            .unwrap()
+            .token
+            .unwrap();
+        assert!(get_shared(&repos, &token).await.is_ok());
+
+        // 委任を取り消すと、委任されたユーザーが発行したリンクも使えなくなる
+        assert!(repos.grants.revoke(owner_id, grant.grant_id).await.unwrap());
+        assert!(matches!(
+            get_shared(&repos, &token).await.unwrap_err(),
+            AppError::NotFound(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shared_link_of_deleted_stream() {
+        let repos = Repos::new();
+        let owner_id = Uuid::new_v4();
+        let stream = create_private_stream(&repos, owner_id).await;
+        let token = create(&repos, auth(owner_id), stream.stream_id, b"")
+            .await
+            .unwrap()
+            .token
+            .unwrap();
+
+        repos
+            .streams
+            .delete(stream.stream_id, stream.version)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            get_shared(&repos, &token).await.unwrap_err(),
+            AppError::NotFound(_)
+        ));
+        assert!(matches!(
+            get_shared(&repos, "unknown").await.unwrap_err(),
+            AppError::NotFound(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_create_share_link_validation() {
+        let repos = Repos::new();
+        let owner_id = Uuid::new_v4();
+        let stream = create_private_stream(&repos, owner_id).await;
+
+        let created = create(
+            &repos,
+            auth(owner_id),
+            stream.stream_id,
+            br#"{"expiresAt":"2099-01-01T00:00:00Z"}"#,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            created.expires_at.as_deref(),
+            Some("2099-01-01T00:00:00+00:00")
+        );
+
+        let result = create(
+            &repos,
+            auth(owner_id),
+            stream.stream_id,
+            br#"{"expiresAt":"2000-01-01T00:00:00Z"}"#,
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(msg, "expiresAtには未来の日時を指定してください")
+            }
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 非公開の配信を閲覧できないユーザーには存在を明かさない
+        let result = create(&repos, auth(Uuid::new_v4()), stream.stream_id, b"").await;
+        assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+        assert_eq!(repos.links.links.lock().unwrap().len(), 1);
+    }
+}
@@ -1,141 +1,667 @@
+use super::{etag, pagination, user};
 use crate::{
+    auth::{
+        permission::{self, StreamAction},
+        AuthUser,
+    },
+    config::PreconditionConfig,
     error::AppError,
-    model::Stream,
-    repository::StreamRepository,
+    model::{
+        SortOrder, Stream, StreamColumns, StreamCursor, StreamFilter, StreamSort, StreamSortKey,
+        Visibility,
+    },
+    repository::{GrantRepository, StreamRepository, UserRepository},
     schema::{
         CreateStreamRequest, StreamListResponse, StreamResponse, StreamSummaryResponse,
+        UpdateStreamRequest, VisibilitySchema,
     },
 };
 use axum::{
-    extract::{Path, Query, State},
-    http::StatusCode,
+    extract::{OriginalUri, Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode, Uri},
+    response::{IntoResponse, Response},
     Json,
 };
-use chrono::Utc;
+use axum_extra::extract::Query;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use std::sync::Arc;
 use uuid::Uuid;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default)]
 pub struct ListStreamsQuery {
-    pub category: Option<String>,
+    /// 複数指定した場合はいずれかに一致するものを返す
+    #[serde(default)]
+    pub category: Vec<String>,
+    #[serde(default)]
+    pub exclude_category: Vec<String>,
+    /// 省略した場合は下書き以外を返す
+    #[serde(default)]
+    pub visibility: Vec<String>,
+    #[serde(rename = "userId")]
+    pub user_id: Option<String>,
+    pub created_from: Option<String>,
+    pub created_to: Option<String>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
+    pub cursor: Option<String>,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+    /// 一覧の各要素に含める項目（カンマ区切り）
+    pub fields: Option<String>,
+    /// 既定の項目に加えて含める項目（カンマ区切り）
+    pub include: Option<String>,
 }
 
-pub async fn create_stream(
-    State(repo): State<Arc<dyn StreamRepository>>,
-    Json(req): Json<CreateStreamRequest>,
-) -> Result<(StatusCode, Json<StreamResponse>), AppError> {
-    // UUIDのバリデーション
-    let user_id = Uuid::parse_str(&req.user_id)
+/// 一覧の各要素に含める項目（`streamId` は常に含める）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SummaryFields {
+    user_id: bool,
+    title: bool,
+    description: bool,
+    category: bool,
+    visibility: bool,
+    is_private: bool,
+    created_at: bool,
+    deleted_at: bool,
+}
+
+impl SummaryFields {
+    /// `fields` を省略した場合は概要欄以外の項目を返す
+    const DEFAULT: Self = Self {
+        user_id: true,
+        title: true,
+        description: false,
+        category: true,
+        visibility: true,
+        is_private: true,
+        created_at: true,
+        deleted_at: true,
+    };
+
+    fn parse(fields: Option<&str>, include: Option<&str>) -> Result<Self, AppError> {
+        let mut selected = match fields {
+            Some(fields) => {
+                let mut selected = Self {
+                    user_id: false,
+                    title: false,
+                    description: false,
+                    category: false,
+                    visibility: false,
+                    is_private: false,
+                    created_at: false,
+                    deleted_at: false,
+                };
+                for value in fields.split(',') {
+                    match value.trim() {
+                        "" | "streamId" => {}
+                        "userId" => selected.user_id = true,
+                        "title" => selected.title = true,
+                        "description" => selected.description = true,
+                        "category" => selected.category = true,
+                        "visibility" => selected.visibility = true,
+                        "isPrivate" => selected.is_private = true,
+                        "createdAt" => selected.created_at = true,
+                        "deletedAt" => selected.deleted_at = true,
+                        _ => {
+                            return Err(AppError::Validation(
+                                "fieldsはstreamId, userId, title, description, category, visibility, isPrivate, createdAt, deletedAtから指定してください"
+                                    .to_string(),
+                            ))
+                        }
+                    }
+                }
+                selected
+            }
+            None => Self::DEFAULT,
+        };
+
+        for value in include.unwrap_or_default().split(',') {
+            match value.trim() {
+                "" => {}
+                "description" => selected.description = true,
+                _ => {
+                    return Err(AppError::Validation(
+                        "includeはdescriptionのみ指定できます".to_string(),
+                    ))
+                }
+            }
+        }
+
+        Ok(selected)
+    }
+
+    /// 返さない項目の列はデータベースから取得しない
+    fn columns(&self) -> StreamColumns {
+        StreamColumns {
+            title: self.title,
+            description: self.description,
+            category: self.category,
+        }
+    }
+
+    fn to_summary(self, stream: Stream) -> StreamSummaryResponse {
+        StreamSummaryResponse {
+            stream_id: Some(stream.stream_id.to_string()),
+            user_id: self.user_id.then(|| stream.user_id.to_string()),
+            title: self.title.then_some(stream.title),
+            description: self.description.then_some(stream.description),
+            category: self.category.then_some(stream.category),
+            visibility: self
+                .visibility
+                .then(|| visibility_to_response(stream.visibility)),
+            is_private: self.is_private.then(|| is_private(stream.visibility)),
+            created_at: self.created_at.then(|| stream.created_at.to_rfc3339()),
+            deleted_at: stream
+                .deleted_at
+                .filter(|_| self.deleted_at)
+                .map(|d| d.to_rfc3339()),
+        }
+    }
+}
+
+pub(crate) fn parse_datetime(value: &str, name: &str) -> Result<DateTime<Utc>, AppError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|d| d.with_timezone(&Utc))
+        .map_err(|_| AppError::Validation(format!("{}はRFC 3339形式で指定してください", name)))
+}
+
+pub(crate) fn parse_filter(query: &ListStreamsQuery) -> Result<StreamFilter, AppError> {
+    let user_id = query
+        .user_id
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
         .map_err(|_| AppError::Validation("Invalid userId format".to_string()))?;
+    let created_from = query
+        .created_from
+        .as_deref()
+        .map(|v| parse_datetime(v, "created_from"))
+        .transpose()?;
+    let created_to = query
+        .created_to
+        .as_deref()
+        .map(|v| parse_datetime(v, "created_to"))
+        .transpose()?;
+
+    if let (Some(from), Some(to)) = (created_from, created_to) {
+        if from >= to {
+            return Err(AppError::Validation(
+                "created_fromはcreated_toより前の日時を指定してください".to_string(),
+            ));
+        }
+    }
+
+    let visibilities = query
+        .visibility
+        .iter()
+        .map(|v| {
+            Visibility::from_param(v).ok_or_else(|| {
+                AppError::Validation(
+                    "visibilityはdraft, private, unlisted, publishedのいずれかで指定してください"
+                        .to_string(),
+                )
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(StreamFilter {
+        categories: query.category.clone(),
+        exclude_categories: query.exclude_category.clone(),
+        user_id,
+        created_from,
+        created_to,
+        visibilities,
+        private_scope: Default::default(),
+        include_deleted: false,
+    })
+}
 
-    // タイトルの長さチェック
-    if req.title.is_empty() {
+/// 下書きは自動保存のためタイトルが空でも保存できる
+fn validate_title(title: &str, visibility: Visibility) -> Result<(), AppError> {
+    if title.is_empty() && visibility != Visibility::Draft {
         return Err(AppError::Validation("タイトルは必須です".to_string()));
     }
+    Ok(())
+}
 
-    // 説明文の長さチェック
-    if req.description.len() > 500 {
+fn validate_description(description: &str) -> Result<(), AppError> {
+    if description.len() > 500 {
         return Err(AppError::Validation(
             "概要欄は500文字以内で入力してください".to_string(),
         ));
     }
+    Ok(())
+}
+
+fn visibility_from_request(visibility: VisibilitySchema) -> Visibility {
+    match visibility {
+        VisibilitySchema::Draft => Visibility::Draft,
+        VisibilitySchema::Private => Visibility::Private,
+        VisibilitySchema::Unlisted => Visibility::Unlisted,
+        VisibilitySchema::Published => Visibility::Published,
+    }
+}
+
+pub(crate) fn visibility_to_response(visibility: Visibility) -> VisibilitySchema {
+    match visibility {
+        Visibility::Draft => VisibilitySchema::Draft,
+        Visibility::Private => VisibilitySchema::Private,
+        Visibility::Unlisted => VisibilitySchema::Unlisted,
+        Visibility::Published => VisibilitySchema::Published,
+    }
+}
+
+/// `visibility` と互換のため残している `isPrivate` から公開状態を決める（両方の指定はエラー）
+fn requested_visibility(
+    visibility: Option<VisibilitySchema>,
+    is_private: Option<bool>,
+) -> Result<Option<Visibility>, AppError> {
+    match (visibility, is_private) {
+        (Some(_), Some(_)) => Err(AppError::Validation(
+            "visibilityとisPrivateは同時に指定できません".to_string(),
+        )),
+        (Some(v), None) => Ok(Some(visibility_from_request(v))),
+        (None, Some(true)) => Ok(Some(Visibility::Private)),
+        (None, Some(false)) => Ok(Some(Visibility::Published)),
+        (None, None) => Ok(None),
+    }
+}
+
+/// 下書きと非公開の配信は `isPrivate` を true として返す
+fn is_private(visibility: Visibility) -> bool {
+    !visibility.is_viewable_by_anyone()
+}
+
+pub(crate) fn to_response(stream: Stream) -> StreamResponse {
+    StreamResponse {
+        stream_id: Some(stream.stream_id.to_string()),
+        user_id: Some(stream.user_id.to_string()),
+        title: Some(stream.title),
+        description: Some(stream.description),
+        category: Some(stream.category),
+        visibility: Some(visibility_to_response(stream.visibility)),
+        is_private: Some(is_private(stream.visibility)),
+        created_at: Some(stream.created_at.to_rfc3339()),
+        user: None,
+    }
+}
+
+pub(crate) fn parse_stream_id(stream_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(stream_id)
+        .map_err(|_| AppError::Validation("Invalid streamId format".to_string()))
+}
+
+/// 配信を取得し、操作できるか確認する（配信者本人以外は配信者からの委任も確認する）
+pub(crate) async fn find_authorized(
+    repo: &dyn StreamRepository,
+    grant_repo: &dyn GrantRepository,
+    auth: Option<&AuthUser>,
+    action: StreamAction,
+    stream_id: Uuid,
+) -> Result<Stream, AppError> {
+    let stream = repo
+        .find_by_id(stream_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Stream not found".to_string()))?;
+    let grant = match auth {
+        Some(auth) if auth.user_id != stream.user_id => {
+            grant_repo.find_active(stream.user_id, auth.user_id).await?
+        }
+        _ => None,
+    };
+    permission::authorize(auth, action, Some(&stream), grant.as_ref())?;
+
+    Ok(stream)
+}
+
+/// 登録内容を検証し、認証したユーザーを配信者とする新しい配信を組み立てる
+pub(crate) fn new_stream(auth: &AuthUser, req: CreateStreamRequest) -> Result<Stream, AppError> {
+    let visibility = requested_visibility(req.visibility, req.is_private)?.unwrap_or_default();
+    validate_title(&req.title, visibility)?;
+    validate_description(&req.description)?;
 
-    let stream = Stream {
+    Ok(Stream {
         stream_id: Uuid::new_v4(),
-        user_id,
+        user_id: auth.user_id,
         title: req.title,
         description: req.description,
         category: req.category.unwrap_or_default(),
+        visibility,
+        version: 1,
         created_at: Utc::now(),
         deleted_at: None,
-    };
+    })
+}
+
+/// 配信者はAPIキーで認証したユーザーとする
+///
+/// 登録した配信の URL を `Location` に、続けて `If-Match` を指定して更新できるよう版を `ETag` に返す。
+pub async fn create_stream(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    auth: AuthUser,
+    Json(req): Json<CreateStreamRequest>,
+) -> Result<(StatusCode, HeaderMap, Json<StreamResponse>), AppError> {
+    permission::authorize(Some(&auth), StreamAction::Create, None, None)?;
+    let stream = new_stream(&auth, req)?;
 
     let created = repo.create(&stream).await?;
 
-    let response = StreamResponse {
-        stream_id: Some(created.stream_id.to_string()),
-        user_id: Some(created.user_id.to_string()),
-        title: Some(created.title),
-        description: Some(created.description),
-        category: Some(created.category),
-        created_at: Some(created.created_at.to_rfc3339()),
+    let mut headers = HeaderMap::new();
+    let location = format!("/api/v2/streams/{}", created.stream_id);
+    headers.insert(
+        header::LOCATION,
+        HeaderValue::from_str(&location).map_err(|e| AppError::Internal(e.into()))?,
+    );
+    headers.insert(header::ETAG, etag::etag(created.version));
+    Ok((StatusCode::CREATED, headers, Json(to_response(created))))
+}
+
+/// 公開以外の配信は閲覧できる場合のみ含める（限定公開の配信も他のユーザーには表示しない）
+pub async fn get_streams(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    auth: Option<AuthUser>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<ListStreamsQuery>,
+) -> Result<(HeaderMap, Json<StreamListResponse>), AppError> {
+    let filter = StreamFilter {
+        private_scope: permission::private_scope(auth.as_ref()),
+        ..parse_filter(&query)?
     };
 
-    Ok((StatusCode::CREATED, Json(response)))
+    list_streams(repo.as_ref(), &uri, &query, &filter).await
 }
 
-pub async fn get_streams(
+pub async fn get_user_streams(
     State(repo): State<Arc<dyn StreamRepository>>,
+    State(user_repo): State<Arc<dyn UserRepository>>,
+    auth: Option<AuthUser>,
+    OriginalUri(uri): OriginalUri,
+    Path(user_id): Path<String>,
     Query(query): Query<ListStreamsQuery>,
-) -> Result<Json<StreamListResponse>, AppError> {
+) -> Result<(HeaderMap, Json<StreamListResponse>), AppError> {
+    let user_id = Uuid::parse_str(&user_id)
+        .map_err(|_| AppError::Validation("Invalid userId format".to_string()))?;
+
+    if !user_repo.exists(user_id).await? {
+        return Err(AppError::NotFound("User not found".to_string()));
+    }
+
+    // パスで指定したユーザーで絞り込む（クエリの userId より優先する）
+    let filter = StreamFilter {
+        user_id: Some(user_id),
+        private_scope: permission::private_scope(auth.as_ref()),
+        ..parse_filter(&query)?
+    };
+
+    list_streams(repo.as_ref(), &uri, &query, &filter).await
+}
+
+/// 一覧取得の共通処理（件数・並び順・ページ位置の検証とレスポンスの組み立て）
+pub(crate) async fn list_streams(
+    repo: &dyn StreamRepository,
+    uri: &Uri,
+    query: &ListStreamsQuery,
+    filter: &StreamFilter,
+) -> Result<(HeaderMap, Json<StreamListResponse>), AppError> {
     if let Some(limit) = query.limit {
         if limit > 100 {
             return Err(AppError::Validation(
                 "limitは100以下で指定してください".to_string(),
             ));
         }
+        if limit < 1 {
+            return Err(AppError::Validation(
+                "limitは1以上で指定してください".to_string(),
+            ));
+        }
     }
 
-    let (streams, total) = repo
-        .find_all(query.category, query.limit, query.offset)
+    if query.cursor.is_some() && query.offset.is_some() {
+        return Err(AppError::Validation(
+            "cursorとoffsetは同時に指定できません".to_string(),
+        ));
+    }
+
+    let sort = StreamSort {
+        key: match query.sort.as_deref() {
+            Some(value) => StreamSortKey::from_param(value).ok_or_else(|| {
+                AppError::Validation(
+                    "sortはcreated_at, title, categoryのいずれかで指定してください".to_string(),
+                )
+            })?,
+            None => StreamSortKey::default(),
+        },
+        order: match query.order.as_deref() {
+            Some(value) => SortOrder::from_param(value).ok_or_else(|| {
+                AppError::Validation("orderはascまたはdescで指定してください".to_string())
+            })?,
+            None => SortOrder::default(),
+        },
+    };
+
+    // カーソルは created_at の並び順上の位置なので、他の並び替えとは組み合わせられない
+    if query.cursor.is_some() && sort.key != StreamSortKey::CreatedAt {
+        return Err(AppError::Validation(
+            "cursorはsort=created_atの場合のみ指定できます".to_string(),
+        ));
+    }
+
+    let cursor = query
+        .cursor
+        .as_deref()
+        .map(pagination::decode_cursor)
+        .transpose()?;
+    let limit = query.limit.unwrap_or(10);
+    let fields = SummaryFields::parse(query.fields.as_deref(), query.include.as_deref())?;
+
+    // 続きの有無を判定するため1件多く取得する
+    let (mut streams, total) = repo
+        .find_all(
+            filter,
+            Some(limit + 1),
+            query.offset,
+            cursor,
+            sort,
+            fields.columns(),
+        )
         .await?;
+    let has_more = streams.len() > limit as usize;
+    streams.truncate(limit as usize);
+
+    let next_cursor = if has_more && sort.key == StreamSortKey::CreatedAt {
+        streams.last().map(|s| {
+            pagination::encode_cursor(&StreamCursor {
+                created_at: s.created_at,
+                stream_id: s.stream_id,
+            })
+        })
+    } else {
+        None
+    };
+
+    let page = pagination::Pagination {
+        limit,
+        offset: query.offset.unwrap_or(0),
+        total,
+        has_next: has_more,
+        next_cursor,
+        by_cursor: cursor.is_some(),
+    };
+    let links = page.links(uri);
+
+    let mut headers = HeaderMap::new();
+    if let Some(link) = pagination::link_header(&links) {
+        headers.insert(header::LINK, link);
+    }
 
     let response = StreamListResponse {
         total: Some(total as i32),
-        limit: Some(query.limit.unwrap_or(10)),
-        offset: Some(query.offset.unwrap_or(0)),
-        items: Some(
-            streams
-                .into_iter()
-                .map(|s| StreamSummaryResponse {
-                    stream_id: Some(s.stream_id.to_string()),
-                    user_id: Some(s.user_id.to_string()),
-                    title: Some(s.title),
-                    category: Some(s.category),
-                    created_at: Some(s.created_at.to_rfc3339()),
-                })
-                .collect(),
-        ),
+        limit: Some(limit),
+        offset: Some(page.offset),
+        has_more: Some(has_more),
+        next_cursor: page.next_cursor.clone(),
+        has_next: Some(page.has_next),
+        has_prev: Some(page.has_prev()),
+        next_offset: page.next_offset(),
+        links: Some(Box::new(links)),
+        items: Some(streams.into_iter().map(|s| fields.to_summary(s)).collect()),
     };
 
-    Ok(Json(response))
+    Ok((headers, Json(response)))
+}
+
+#[derive(Deserialize, Default)]
+pub struct GetStreamQuery {
+    /// 埋め込む関連リソース（カンマ区切り）
+    pub expand: Option<String>,
 }
 
 pub async fn get_stream(
     State(repo): State<Arc<dyn StreamRepository>>,
+    State(user_repo): State<Arc<dyn UserRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    auth: Option<AuthUser>,
     Path(stream_id): Path<String>,
-) -> Result<Json<StreamResponse>, AppError> {
-    let id = Uuid::parse_str(&stream_id)
-        .map_err(|_| AppError::Validation("Invalid streamId format".to_string()))?;
+    Query(query): Query<GetStreamQuery>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let id = parse_stream_id(&stream_id)?;
 
-    let stream = repo
-        .find_by_id(id)
-        .await?
-        .ok_or_else(|| AppError::NotFound("Stream not found".to_string()))?;
+    let mut expand_user = false;
+    for value in query.expand.as_deref().unwrap_or_default().split(',') {
+        match value.trim() {
+            "" => {}
+            "user" => expand_user = true,
+            _ => {
+                return Err(AppError::Validation(
+                    "expandはuserのみ指定できます".to_string(),
+                ))
+            }
+        }
+    }
+
+    let stream = find_authorized(
+        repo.as_ref(),
+        grant_repo.as_ref(),
+        auth.as_ref(),
+        StreamAction::View,
+        id,
+    )
+    .await?;
+
+    let user = if expand_user {
+        user_repo
+            .find_by_id(stream.user_id)
+            .await?
+            .map(|u| Box::new(user::to_response(u)))
+    } else {
+        None
+    };
+
+    // 配信者情報を埋め込む場合は、プロフィールの変更でも変わる別の ETag にする
+    let etag = if expand_user {
+        let embedded = serde_json::to_vec(&user).map_err(|e| AppError::Internal(e.into()))?;
+        etag::expanded_etag(stream.version, &embedded)
+    } else {
+        etag::etag(stream.version)
+    };
+    if etag::is_not_modified(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response());
+    }
 
     let response = StreamResponse {
-        stream_id: Some(stream.stream_id.to_string()),
-        user_id: Some(stream.user_id.to_string()),
-        title: Some(stream.title),
-        description: Some(stream.description),
-        category: Some(stream.category),
-        created_at: Some(stream.created_at.to_rfc3339()),
+        user,
+        ..to_response(stream)
     };
+    Ok(([(header::ETAG, etag)], Json(response)).into_response())
+}
 
-    Ok(Json(response))
+/// 取得した後に他の更新があった（ETag を指定した場合は 412、指定しない場合は 409）
+pub(crate) fn version_mismatch(etag_given: bool) -> AppError {
+    let message = "配信情報が他のユーザーによって更新されています".to_string();
+    if etag_given {
+        AppError::PreconditionFailed(message)
+    } else {
+        AppError::Conflict(message)
+    }
 }
 
+/// 指定した項目のみ更新する（配信者本人・更新を委任されたユーザー・モデレーター・管理者のみ）
+///
+/// `If-Match` を指定した場合は取得時の ETag と一致するときだけ更新する。
+pub async fn update_stream(
+    State(repo): State<Arc<dyn StreamRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    State(precondition): State<PreconditionConfig>,
+    auth: AuthUser,
+    Path(stream_id): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<UpdateStreamRequest>,
+) -> Result<(HeaderMap, Json<StreamResponse>), AppError> {
+    let id = parse_stream_id(&stream_id)?;
+
+    let mut stream = find_authorized(
+        repo.as_ref(),
+        grant_repo.as_ref(),
+        Some(&auth),
+        StreamAction::Update,
+        id,
+    )
+    .await?;
+    etag::check_if_match(&headers, stream.version, precondition.require_if_match)?;
+
+    if let Some(visibility) = requested_visibility(req.visibility, req.is_private)? {
+        stream.visibility = visibility;
+    }
+    if let Some(title) = req.title {
+        stream.title = title;
+    }
+    if let Some(description) = req.description {
+        validate_description(&description)?;
+        stream.description = description;
+    }
+    if let Some(category) = req.category {
+        stream.category = category;
+    }
+    // 下書きを公開する場合はタイトルが空のままにならないよう、更新後の値で確認する
+    validate_title(&stream.title, stream.visibility)?;
+
+    // 取得してから更新するまでの間に他の更新があった場合は版が一致しない
+    let Some(updated) = repo.update(&stream).await? else {
+        return Err(version_mismatch(headers.contains_key(header::IF_MATCH)));
+    };
+
+    let mut response_headers = HeaderMap::new();
+    response_headers.insert(header::ETAG, etag::etag(updated.version));
+    Ok((response_headers, Json(to_response(updated))))
+}
+
+/// 配信者本人・削除を委任されたユーザー・管理者のみ削除できる
 pub async fn delete_stream(
     State(repo): State<Arc<dyn StreamRepository>>,
+    State(grant_repo): State<Arc<dyn GrantRepository>>,
+    State(precondition): State<PreconditionConfig>,
+    auth: AuthUser,
     Path(stream_id): Path<String>,
+    headers: HeaderMap,
 ) -> Result<StatusCode, AppError> {
-    let id = Uuid::parse_str(&stream_id)
-        .map_err(|_| AppError::Validation("Invalid streamId format".to_string()))?;
+    let id = parse_stream_id(&stream_id)?;
+
+    let stream = find_authorized(
+        repo.as_ref(),
+        grant_repo.as_ref(),
+        Some(&auth),
+        StreamAction::Delete,
+        id,
+    )
+    .await?;
+    etag::check_if_match(&headers, stream.version, precondition.require_if_match)?;
 
-    repo.delete(id).await?;
+    // 確認してから削除するまでの間に他の更新があった場合は版が一致しない
+    if !repo.delete(id, stream.version).await? {
+        return Err(version_mismatch(headers.contains_key(header::IF_MATCH)));
+    }
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -143,60 +669,79 @@ pub async fn delete_stream(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use async_trait::async_trait;
-    use std::sync::Mutex;
+    use crate::model::{Grant, GrantScope, Role};
+    use crate::repository::mock::{
+        MockGrantRepository, MockStreamRepository, MockUserRepository,
+    };
+    use axum::extract::FromRequestParts;
 
-    struct MockStreamRepository {
-        streams: Mutex<Vec<Stream>>,
+    fn test_stream(user_id: Uuid, title: &str) -> Stream {
+        Stream {
+            stream_id: Uuid::new_v4(),
+            user_id,
+            title: title.to_string(),
+            description: "desc".to_string(),
+            category: "".to_string(),
+            visibility: Visibility::Published,
+            version: 1,
+            created_at: Utc::now(),
+            deleted_at: None,
+        }
     }
 
-    impl MockStreamRepository {
-        fn new() -> Self {
-            Self {
-                streams: Mutex::new(Vec::new()),
-            }
-        }
+    async fn stream_body(response: Response) -> StreamResponse {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
     }
 
-    #[async_trait]
-    impl StreamRepository for MockStreamRepository {
-        async fn create(&self, stream: &Stream) -> anyhow::Result<Stream> {
-            let mut streams = self.streams.lock().unwrap();
-            streams.push(stream.clone());
-            Ok(stream.clone())
+    fn user_auth(user_id: Uuid) -> AuthUser {
+        AuthUser {
+            user_id,
+            role: Role::Owner,
         }
+    }
 
-        async fn find_by_id(&self, _stream_id: Uuid) -> anyhow::Result<Option<Stream>> {
-            Ok(None)
+    fn admin_auth() -> AuthUser {
+        AuthUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Admin,
         }
+    }
 
-        async fn find_all(
-            &self,
-            _category: Option<String>,
-            _limit: Option<i32>,
-            _offset: Option<i32>,
-        ) -> anyhow::Result<(Vec<Stream>, i64)> {
-            let streams = self.streams.lock().unwrap();
-            Ok((streams.clone(), streams.len() as i64))
+    fn moderator_auth() -> AuthUser {
+        AuthUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Moderator,
         }
+    }
 
-        async fn delete(&self, _stream_id: Uuid) -> anyhow::Result<()> {
-            Ok(())
-        }
+    fn grant_repo() -> State<Arc<dyn GrantRepository>> {
+        State(Arc::new(MockGrantRepository::default()))
+    }
+
+    fn list_uri() -> OriginalUri {
+        OriginalUri(Uri::from_static("/api/v2/streams"))
     }
 
     #[tokio::test]
     async fn test_create_stream_validation() {
-        let repo = Arc::new(MockStreamRepository::new());
+        let repo = Arc::new(MockStreamRepository::default());
+        let auth = AuthUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Owner,
+        };
 
         // Empty title
         let req = CreateStreamRequest {
-            user_id: Uuid::new_v4().to_string(),
             title: "".to_string(),
             description: "desc".to_string(),
             category: None,
+            visibility: None,
+            is_private: None,
         };
-        let result = create_stream(State(repo.clone()), Json(req)).await;
+        let result = create_stream(State(repo.clone()), auth, Json(req)).await;
         assert!(result.is_err());
         match result.unwrap_err() {
             AppError::Validation(msg) => assert_eq!(msg, "タイトルは必須です"),
@@ -205,12 +750,13 @@ mod tests {
 
         // Long description
         let req_long = CreateStreamRequest {
-            user_id: Uuid::new_v4().to_string(),
             title: "title".to_string(),
             description: "a".repeat(501),
             category: None,
+            visibility: None,
+            is_private: None,
         };
-        let result = create_stream(State(repo.clone()), Json(req_long)).await;
+        let result = create_stream(State(repo.clone()), auth, Json(req_long)).await;
         assert!(result.is_err());
         match result.unwrap_err() {
             AppError::Validation(msg) => assert_eq!(msg, "概要欄は500文字以内で入力してください"),
@@ -218,20 +764,1170 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_create_stream_owner_from_auth() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+
+        // リクエストボディの userId は無視される
+        let req: CreateStreamRequest = serde_json::from_str(&format!(
+            r#"{{"userId":"{}","title":"title","description":"desc"}}"#,
+            Uuid::new_v4()
+        ))
+        .unwrap();
+        let (status, headers, Json(created)) =
+            create_stream(State(repo.clone()), user_auth(user_id), Json(req))
+                .await
+                .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(headers[header::ETAG], "\"1\"");
+        assert_eq!(
+            headers[header::LOCATION],
+            format!("/api/v2/streams/{}", created.stream_id.unwrap())
+        );
+        assert_eq!(created.user_id, Some(user_id.to_string()));
+        assert_eq!(repo.streams.lock().unwrap()[0].user_id, user_id);
+    }
+
     #[tokio::test]
     async fn test_get_streams_limit_validation() {
-        let repo = Arc::new(MockStreamRepository::new());
+        let repo = Arc::new(MockStreamRepository::default());
         let query = ListStreamsQuery {
-            category: None,
             limit: Some(101),
-            offset: None,
+            ..Default::default()
         };
 
-        let result = get_streams(State(repo), Query(query)).await;
+        let result = get_streams(State(repo), None, list_uri(), Query(query)).await;
         assert!(result.is_err());
         match result.unwrap_err() {
             AppError::Validation(msg) => assert_eq!(msg, "limitは100以下で指定してください"),
             _ => panic!("Unexpected error type"),
         }
     }
+
+    #[tokio::test]
+    async fn test_get_streams_cursor_validation() {
+        let repo = Arc::new(MockStreamRepository::default());
+
+        // cursorとoffsetの同時指定
+        let query = ListStreamsQuery {
+            offset: Some(10),
+            cursor: Some("MTIzOjQ1Ng".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo.clone()), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "cursorとoffsetは同時に指定できません"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 不正なcursor
+        let query = ListStreamsQuery {
+            cursor: Some("invalid".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "Invalid cursor format"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_streams_sort_validation() {
+        let repo = Arc::new(MockStreamRepository::default());
+
+        // 許可されていない並び替えキー
+        let query = ListStreamsQuery {
+            sort: Some("description; DROP TABLE streams".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo.clone()), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(
+                msg,
+                "sortはcreated_at, title, categoryのいずれかで指定してください"
+            ),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 許可されていない並び順
+        let query = ListStreamsQuery {
+            order: Some("random".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo.clone()), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "orderはascまたはdescで指定してください"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // created_at 以外の並び替えとカーソルの組み合わせ
+        let query = ListStreamsQuery {
+            cursor: Some("MTIzOjQ1Ng".to_string()),
+            sort: Some("title".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(msg, "cursorはsort=created_atの場合のみ指定できます")
+            }
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_streams_filter_validation() {
+        let repo = Arc::new(MockStreamRepository::default());
+
+        let query = ListStreamsQuery {
+            created_from: Some("2025-03-01".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo.clone()), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(msg, "created_fromはRFC 3339形式で指定してください")
+            }
+            _ => panic!("Unexpected error type"),
+        }
+
+        let query = ListStreamsQuery {
+            created_from: Some("2025-04-01T00:00:00+09:00".to_string()),
+            created_to: Some("2025-03-01T00:00:00+09:00".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo.clone()), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(msg, "created_fromはcreated_toより前の日時を指定してください")
+            }
+            _ => panic!("Unexpected error type"),
+        }
+
+        let query = ListStreamsQuery {
+            user_id: Some("not-a-uuid".to_string()),
+            ..Default::default()
+        };
+        let result = get_streams(State(repo), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "Invalid userId format"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parse_filter_repeated_categories() {
+        let (mut parts, _) = axum::http::Request::builder()
+            .uri("/api/v2/streams?category=%E3%82%B2%E3%83%BC%E3%83%A0&category=%E9%9B%91%E8%AB%87&exclude_category=%E6%96%99%E7%90%86&created_from=2025-03-01T00:00:00%2B09:00")
+            .body(())
+            .unwrap()
+            .into_parts();
+        let Query(query) = Query::<ListStreamsQuery>::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+        let filter = parse_filter(&query).unwrap();
+
+        assert_eq!(filter.categories, vec!["ゲーム", "雑談"]);
+        assert_eq!(filter.exclude_categories, vec!["料理"]);
+        assert_eq!(
+            filter.created_from.unwrap().to_rfc3339(),
+            "2025-02-28T15:00:00+00:00"
+        );
+        assert!(filter.created_to.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_streams_next_cursor() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        for i in 0..3 {
+            repo.create(&Stream {
+                stream_id: Uuid::new_v4(),
+                user_id,
+                title: format!("title {}", i),
+                description: "desc".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Published,
+                version: 1,
+                created_at: Utc::now(),
+                deleted_at: None,
+            })
+            .await
+            .unwrap();
+        }
+
+        // 3件中2件を取得 → 続きあり
+        let query = ListStreamsQuery {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let (headers, Json(response)) = get_streams(State(repo.clone()), None, list_uri(), Query(query))
+            .await
+            .unwrap();
+        assert_eq!(response.items.as_ref().unwrap().len(), 2);
+        assert_eq!(response.has_more, Some(true));
+        assert_eq!(response.has_next, Some(true));
+        assert_eq!(response.has_prev, Some(false));
+        assert_eq!(response.next_offset, Some(2));
+        let link = headers.get(header::LINK).unwrap().to_str().unwrap();
+        assert!(link.contains(r#"</api/v2/streams?offset=2>; rel="next""#));
+        assert!(!link.contains(r#"rel="prev""#));
+        let cursor = pagination::decode_cursor(response.next_cursor.as_deref().unwrap()).unwrap();
+        let second_id = repo.streams.lock().unwrap()[1].stream_id;
+        assert_eq!(cursor.stream_id, second_id);
+
+        // 全件取得 → 続きなし
+        let query = ListStreamsQuery {
+            limit: Some(3),
+            ..Default::default()
+        };
+        let (_, Json(response)) = get_streams(State(repo), None, list_uri(), Query(query))
+            .await
+            .unwrap();
+        assert_eq!(response.items.unwrap().len(), 3);
+        assert_eq!(response.has_more, Some(false));
+        assert!(response.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_streams_fields() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let mut stream = test_stream(Uuid::new_v4(), "title");
+        stream.category = "game".to_string();
+        repo.create(&stream).await.unwrap();
+
+        // 既定では概要欄を含めない
+        let (_, Json(response)) = get_streams(
+            State(repo.clone()),
+            None,
+            list_uri(),
+            Query(ListStreamsQuery::default()),
+        )
+        .await
+        .unwrap();
+        let item = &response.items.unwrap()[0];
+        assert_eq!(item.title.as_deref(), Some("title"));
+        assert_eq!(item.category.as_deref(), Some("game"));
+        assert!(item.description.is_none());
+
+        // include=description で既定の項目に概要欄を加える
+        let query = ListStreamsQuery {
+            include: Some("description".to_string()),
+            ..Default::default()
+        };
+        let (_, Json(response)) = get_streams(State(repo.clone()), None, list_uri(), Query(query))
+            .await
+            .unwrap();
+        let item = &response.items.unwrap()[0];
+        assert_eq!(item.title.as_deref(), Some("title"));
+        assert_eq!(item.description.as_deref(), Some("desc"));
+
+        // fields で指定した項目と streamId だけを返す
+        let query = ListStreamsQuery {
+            fields: Some("title, description".to_string()),
+            ..Default::default()
+        };
+        let (_, Json(response)) = get_streams(State(repo.clone()), None, list_uri(), Query(query))
+            .await
+            .unwrap();
+        let item = &response.items.unwrap()[0];
+        assert_eq!(item.stream_id, Some(stream.stream_id.to_string()));
+        assert_eq!(item.title.as_deref(), Some("title"));
+        assert_eq!(item.description.as_deref(), Some("desc"));
+        assert!(item.user_id.is_none());
+        assert!(item.category.is_none());
+        assert!(item.visibility.is_none());
+        assert!(item.created_at.is_none());
+
+        for (fields, include, message) in [
+            (
+                Some("title,body"),
+                None,
+                "fieldsはstreamId, userId, title, description, category, visibility, isPrivate, createdAt, deletedAtから指定してください",
+            ),
+            (None, Some("user"), "includeはdescriptionのみ指定できます"),
+        ] {
+            let query = ListStreamsQuery {
+                fields: fields.map(str::to_string),
+                include: include.map(str::to_string),
+                ..Default::default()
+            };
+            let result = get_streams(State(repo.clone()), None, list_uri(), Query(query)).await;
+            match result.unwrap_err() {
+                AppError::Validation(msg) => assert_eq!(msg, message),
+                _ => panic!("Unexpected error type"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_user_streams() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        let other_user_id = Uuid::new_v4();
+        repo.create(&test_stream(user_id, "mine")).await.unwrap();
+        repo.create(&test_stream(other_user_id, "other")).await.unwrap();
+        let user_repo = Arc::new(MockUserRepository::with_users(&[user_id, other_user_id]));
+        let uri = OriginalUri(Uri::from_static("/api/v2/users/x/streams"));
+
+        // クエリの userId よりパスのユーザーが優先される
+        let query = ListStreamsQuery {
+            user_id: Some(other_user_id.to_string()),
+            ..Default::default()
+        };
+        let (headers, Json(response)) = get_user_streams(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            None,
+            uri.clone(),
+            Path(user_id.to_string()),
+            Query(query),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, Some(1));
+        let items = response.items.unwrap();
+        assert_eq!(items[0].title.as_deref(), Some("mine"));
+        let link = headers.get(header::LINK).unwrap().to_str().unwrap();
+        assert!(link.starts_with("</api/v2/users/x/streams?"));
+
+        // 存在しないユーザー
+        let result = get_user_streams(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            None,
+            uri.clone(),
+            Path(Uuid::new_v4().to_string()),
+            Query(ListStreamsQuery::default()),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::NotFound(msg) => assert_eq!(msg, "User not found"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 不正なユーザーID
+        let result = get_user_streams(
+            State(repo),
+            State(user_repo),
+            None,
+            uri,
+            Path("not-a-uuid".to_string()),
+            Query(ListStreamsQuery::default()),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "Invalid userId format"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_expand_user() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_id = Uuid::new_v4();
+        let user_repo = Arc::new(MockUserRepository::with_users(&[user_id]));
+        let stream = repo.create(&test_stream(user_id, "title")).await.unwrap();
+
+        // 指定なしの場合は埋め込まない
+        let response = get_stream(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            grant_repo(),
+            None,
+            Path(stream.stream_id.to_string()),
+            Query(GetStreamQuery::default()),
+            HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+        let response = stream_body(response).await;
+        assert!(response.user.is_none());
+
+        let query = GetStreamQuery {
+            expand: Some("user".to_string()),
+        };
+        let response = get_stream(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            grant_repo(),
+            None,
+            Path(stream.stream_id.to_string()),
+            Query(query),
+            HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+        let etag = response.headers()[header::ETAG].clone();
+        assert!(etag.to_str().unwrap().starts_with("W/\"1-"));
+        let response = stream_body(response).await;
+        let user = response.user.unwrap();
+        assert_eq!(user.user_id, Some(user_id.to_string()));
+        assert_eq!(user.time_zone.as_deref(), Some("Asia/Tokyo"));
+
+        // 埋め込んだ表現の ETag が一致すれば 304、埋め込まない表現の ETag では一致しない
+        let get = |value: header::HeaderValue| {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::IF_NONE_MATCH, value);
+            get_stream(
+                State(repo.clone()),
+                State(user_repo.clone()),
+                grant_repo(),
+                None,
+                Path(stream.stream_id.to_string()),
+                Query(GetStreamQuery {
+                    expand: Some("user".to_string()),
+                }),
+                headers,
+            )
+        };
+        let response = get(etag.clone()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[header::ETAG], etag);
+        let response = get(header::HeaderValue::from_static("\"1\"")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let query = GetStreamQuery {
+            expand: Some("user,comments".to_string()),
+        };
+        let result = get_stream(
+            State(repo),
+            State(user_repo),
+            grant_repo(),
+            None,
+            Path(stream.stream_id.to_string()),
+            Query(query),
+            HeaderMap::new(),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "expandはuserのみ指定できます"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_private_stream_visibility() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let owner_id = Uuid::new_v4();
+        let user_repo = Arc::new(MockUserRepository::with_users(&[owner_id]));
+        repo.create(&test_stream(owner_id, "public")).await.unwrap();
+        let private = repo
+            .create(&Stream {
+                visibility: Visibility::Private,
+                ..test_stream(owner_id, "private")
+            })
+            .await
+            .unwrap();
+
+        // 一覧: 未認証・他のユーザーには非公開の配信を含めない
+        for (auth, expected) in [
+            (None, 1),
+            (Some(user_auth(Uuid::new_v4())), 1),
+            (Some(user_auth(owner_id)), 2),
+            (Some(admin_auth()), 2),
+        ] {
+            let (_, Json(response)) = get_streams(
+                State(repo.clone()),
+                auth,
+                list_uri(),
+                Query(ListStreamsQuery::default()),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.total, Some(expected));
+
+            let (_, Json(response)) = get_user_streams(
+                State(repo.clone()),
+                State(user_repo.clone()),
+                auth,
+                list_uri(),
+                Path(owner_id.to_string()),
+                Query(ListStreamsQuery::default()),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.total, Some(expected));
+        }
+
+        // 詳細: 閲覧できない場合は 404
+        for auth in [None, Some(user_auth(Uuid::new_v4()))] {
+            let result = get_stream(
+                State(repo.clone()),
+                State(user_repo.clone()),
+                grant_repo(),
+                auth,
+                Path(private.stream_id.to_string()),
+                Query(GetStreamQuery::default()),
+                HeaderMap::new(),
+            )
+            .await;
+            match result.unwrap_err() {
+                AppError::NotFound(msg) => assert_eq!(msg, "Stream not found"),
+                _ => panic!("Unexpected error type"),
+            }
+        }
+        for auth in [user_auth(owner_id), admin_auth()] {
+            let response = get_stream(
+                State(repo.clone()),
+                State(user_repo.clone()),
+                grant_repo(),
+                Some(auth),
+                Path(private.stream_id.to_string()),
+                Query(GetStreamQuery::default()),
+                HeaderMap::new(),
+            )
+            .await
+            .unwrap();
+            let response = stream_body(response).await;
+            assert_eq!(response.is_private, Some(true));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drafts_and_unlisted_streams() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_repo = Arc::new(MockUserRepository::default());
+        let owner_id = Uuid::new_v4();
+        let other = user_auth(Uuid::new_v4());
+
+        // 下書きはタイトルが空でも保存できる
+        let req: CreateStreamRequest =
+            serde_json::from_str(r#"{"title":"","description":"","visibility":"draft"}"#).unwrap();
+        let (_, _, Json(draft)) = create_stream(State(repo.clone()), user_auth(owner_id), Json(req))
+            .await
+            .unwrap();
+        assert_eq!(draft.visibility, Some(VisibilitySchema::Draft));
+        assert_eq!(draft.is_private, Some(true));
+        let req: CreateStreamRequest = serde_json::from_str(
+            r#"{"title":"unlisted","description":"","visibility":"unlisted"}"#,
+        )
+        .unwrap();
+        let (_, _, Json(unlisted)) =
+            create_stream(State(repo.clone()), user_auth(owner_id), Json(req))
+                .await
+                .unwrap();
+        assert_eq!(unlisted.is_private, Some(false));
+
+        // 既定の一覧には下書きを含めず、限定公開の配信は本人にのみ表示する
+        for (auth, expected) in [(other, 0), (user_auth(owner_id), 1)] {
+            let (_, Json(response)) = get_streams(
+                State(repo.clone()),
+                Some(auth),
+                list_uri(),
+                Query(ListStreamsQuery::default()),
+            )
+            .await
+            .unwrap();
+            assert_eq!(response.total, Some(expected));
+        }
+        let query = ListStreamsQuery {
+            visibility: vec!["draft".to_string()],
+            ..Default::default()
+        };
+        let (_, Json(response)) = get_streams(
+            State(repo.clone()),
+            Some(user_auth(owner_id)),
+            list_uri(),
+            Query(query),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.items.unwrap()[0].stream_id, draft.stream_id);
+
+        // 限定公開の配信は URL を知っていれば閲覧できる
+        let response = get_stream(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            grant_repo(),
+            None,
+            Path(unlisted.stream_id.clone().unwrap()),
+            Query(GetStreamQuery::default()),
+            HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+        let response = stream_body(response).await;
+        assert_eq!(response.visibility, Some(VisibilitySchema::Unlisted));
+        let result = get_stream(
+            State(repo.clone()),
+            State(user_repo),
+            grant_repo(),
+            Some(other),
+            Path(draft.stream_id.clone().unwrap()),
+            Query(GetStreamQuery::default()),
+            HeaderMap::new(),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+
+        // タイトルが空のまま公開はできない
+        let req = UpdateStreamRequest {
+            visibility: Some(VisibilitySchema::Published),
+            ..Default::default()
+        };
+        let result = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            Path(draft.stream_id.clone().unwrap()),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "タイトルは必須です"),
+            _ => panic!("Unexpected error type"),
+        }
+        let req = UpdateStreamRequest {
+            title: Some("published".to_string()),
+            visibility: Some(VisibilitySchema::Published),
+            ..Default::default()
+        };
+        let (_, Json(updated)) = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            Path(draft.stream_id.unwrap()),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.visibility, Some(VisibilitySchema::Published));
+
+        // isPrivate との同時指定・不正な値
+        let req: CreateStreamRequest = serde_json::from_str(
+            r#"{"title":"t","description":"","visibility":"private","isPrivate":true}"#,
+        )
+        .unwrap();
+        let result = create_stream(State(repo.clone()), user_auth(owner_id), Json(req)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => {
+                assert_eq!(msg, "visibilityとisPrivateは同時に指定できません")
+            }
+            _ => panic!("Unexpected error type"),
+        }
+        let query = ListStreamsQuery {
+            visibility: vec!["hidden".to_string()],
+            ..Default::default()
+        };
+        let result = get_streams(State(repo), None, list_uri(), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(
+                msg,
+                "visibilityはdraft, private, unlisted, publishedのいずれかで指定してください"
+            ),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_stream() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let owner_id = Uuid::new_v4();
+        let stream = repo.create(&test_stream(owner_id, "before")).await.unwrap();
+        let path = || Path(stream.stream_id.to_string());
+
+        // 他のユーザーは更新できない
+        let req = UpdateStreamRequest {
+            title: Some("after".to_string()),
+            ..Default::default()
+        };
+        let result = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(Uuid::new_v4()),
+            path(),
+            HeaderMap::new(),
+            Json(req.clone()),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Forbidden(msg) => assert_eq!(msg, "この配信を操作する権限がありません"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 配信者本人: 指定した項目のみ更新される
+        let (_, Json(updated)) = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            path(),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.title.as_deref(), Some("after"));
+        assert_eq!(updated.description.as_deref(), Some("desc"));
+
+        // 管理者は他のユーザーの配信も更新できる
+        let req = UpdateStreamRequest {
+            is_private: Some(true),
+            ..Default::default()
+        };
+        let (_, Json(updated)) = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            admin_auth(),
+            path(),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.is_private, Some(true));
+        assert_eq!(updated.visibility, Some(VisibilitySchema::Private));
+        assert_eq!(
+            repo.streams.lock().unwrap()[0].visibility,
+            Visibility::Private
+        );
+
+        // 非公開になった配信は他のユーザーからは存在しないものとして扱う
+        let req = UpdateStreamRequest {
+            title: Some("x".to_string()),
+            ..Default::default()
+        };
+        let result = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(Uuid::new_v4()),
+            path(),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+
+        // 作成時と同じ検証を行う
+        let req = UpdateStreamRequest {
+            title: Some("".to_string()),
+            ..Default::default()
+        };
+        let result = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            path(),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "タイトルは必須です"),
+            _ => panic!("Unexpected error type"),
+        }
+        let req = UpdateStreamRequest {
+            description: Some("a".repeat(501)),
+            ..Default::default()
+        };
+        let result = update_stream(
+            State(repo),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            path(),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "概要欄は500文字以内で入力してください"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_stream() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let owner_id = Uuid::new_v4();
+        let first = repo.create(&test_stream(owner_id, "first")).await.unwrap();
+        let second = repo.create(&test_stream(owner_id, "second")).await.unwrap();
+
+        // 他のユーザーは削除できない
+        let result = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(Uuid::new_v4()),
+            Path(first.stream_id.to_string()),
+            HeaderMap::new(),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+        assert!(repo.find_by_id(first.stream_id).await.unwrap().is_some());
+
+        // 配信者本人
+        let status = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            Path(first.stream_id.to_string()),
+            HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(repo.find_by_id(first.stream_id).await.unwrap().is_none());
+
+        // 削除済み
+        let result = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            Path(first.stream_id.to_string()),
+            HeaderMap::new(),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+
+        // 管理者
+        let status = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            admin_auth(),
+            Path(second.stream_id.to_string()),
+            HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(repo.find_by_id(second.stream_id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_delete_stream_updated_concurrently() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let owner_id = Uuid::new_v4();
+        let stream = repo.create(&test_stream(owner_id, "before")).await.unwrap();
+        let mut if_match = HeaderMap::new();
+        if_match.insert(header::IF_MATCH, header::HeaderValue::from_static("\"1\""));
+
+        // 確認した後、削除するまでの間に更新された場合は削除しない
+        repo.concurrent_update
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        let result = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            Path(stream.stream_id.to_string()),
+            if_match,
+        )
+        .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            AppError::PreconditionFailed(_)
+        ));
+        let result = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            Path(stream.stream_id.to_string()),
+            HeaderMap::new(),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Conflict(_)));
+
+        repo.concurrent_update
+            .store(false, std::sync::atomic::Ordering::SeqCst);
+        assert!(repo.find_by_id(stream.stream_id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_conditional_requests() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let user_repo = Arc::new(MockUserRepository::default());
+        let owner_id = Uuid::new_v4();
+        let stream = repo.create(&test_stream(owner_id, "before")).await.unwrap();
+        let path = || Path(stream.stream_id.to_string());
+        let with = |name: header::HeaderName, value: &'static str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(name, header::HeaderValue::from_static(value));
+            headers
+        };
+        let req = || UpdateStreamRequest {
+            title: Some("after".to_string()),
+            ..Default::default()
+        };
+
+        // 取得時の ETag が一致すれば 304
+        let response = get_stream(
+            State(repo.clone()),
+            State(user_repo.clone()),
+            grant_repo(),
+            None,
+            path(),
+            Query(GetStreamQuery::default()),
+            HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.headers()[header::ETAG], "\"1\"");
+        let response = get_stream(
+            State(repo.clone()),
+            State(user_repo),
+            grant_repo(),
+            None,
+            path(),
+            Query(GetStreamQuery::default()),
+            with(header::IF_NONE_MATCH, "\"1\""),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+
+        // 古い ETag での更新・削除は 412
+        let (headers, Json(updated)) = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            path(),
+            with(header::IF_MATCH, "\"1\""),
+            Json(req()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(headers[header::ETAG], "\"2\"");
+        assert_eq!(updated.title.as_deref(), Some("after"));
+        let result = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            path(),
+            with(header::IF_MATCH, "\"1\""),
+            Json(req()),
+        )
+        .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            AppError::PreconditionFailed(_)
+        ));
+        let result = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            user_auth(owner_id),
+            path(),
+            with(header::IF_MATCH, "\"1\""),
+        )
+        .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            AppError::PreconditionFailed(_)
+        ));
+
+        // If-Match を必須にした場合は指定がなければ 428
+        let required = PreconditionConfig {
+            require_if_match: true,
+        };
+        let result = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(required.clone()),
+            user_auth(owner_id),
+            path(),
+            HeaderMap::new(),
+        )
+        .await;
+        assert!(matches!(
+            result.unwrap_err(),
+            AppError::PreconditionRequired(_)
+        ));
+        let status = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(required),
+            user_auth(owner_id),
+            path(),
+            with(header::IF_MATCH, "\"2\""),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_moderator_can_update_but_not_delete() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let owner_id = Uuid::new_v4();
+        let stream = repo
+            .create(&Stream {
+                visibility: Visibility::Private,
+                ..test_stream(owner_id, "before")
+            })
+            .await
+            .unwrap();
+        let moderator = moderator_auth();
+
+        // 非公開の配信も一覧に含まれる
+        let (_, Json(response)) = get_streams(
+            State(repo.clone()),
+            Some(moderator),
+            list_uri(),
+            Query(ListStreamsQuery::default()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, Some(1));
+
+        let req = UpdateStreamRequest {
+            title: Some("after".to_string()),
+            ..Default::default()
+        };
+        let (_, Json(updated)) = update_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            moderator,
+            Path(stream.stream_id.to_string()),
+            HeaderMap::new(),
+            Json(req),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated.title.as_deref(), Some("after"));
+
+        let result = delete_stream(
+            State(repo.clone()),
+            grant_repo(),
+            State(PreconditionConfig::default()),
+            moderator,
+            Path(stream.stream_id.to_string()),
+            HeaderMap::new(),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::Forbidden(msg) => assert_eq!(msg, "この配信を操作する権限がありません"),
+            _ => panic!("Unexpected error type"),
+        }
+        assert!(repo.find_by_id(stream.stream_id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_delegated_access() {
+        let repo = Arc::new(MockStreamRepository::default());
+        let grants = Arc::new(MockGrantRepository::default());
+        let owner_id = Uuid::new_v4();
+        let grantee_id = Uuid::new_v4();
+        let stream = repo
+            .create(&Stream {
+                visibility: Visibility::Private,
+                ..test_stream(owner_id, "before")
+            })
+            .await
+            .unwrap();
+        let path = || Path(stream.stream_id.to_string());
+        let req = || UpdateStreamRequest {
+            description: Some("マネージャーが書いた概要欄".to_string()),
+            ..Default::default()
+        };
+
+        // 委任がなければ非公開の配信は見えない
+        let result = update_stream(
+            State(repo.clone()),
+            State(grants.clone()),
+            State(PreconditionConfig::default()),
+            user_auth(grantee_id),
+            path(),
+            HeaderMap::new(),
+            Json(req()),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::NotFound(_)));
+
+        // 閲覧のみの委任では更新できない
+        let grant = Grant {
+            grant_id: Uuid::new_v4(),
+            owner_id,
+            grantee_id,
+            scope: GrantScope::Read,
+            created_at: Utc::now(),
+            expires_at: None,
+            revoked_at: None,
+        };
+        grants.create(&grant).await.unwrap();
+        let result = update_stream(
+            State(repo.clone()),
+            State(grants.clone()),
+            State(PreconditionConfig::default()),
+            user_auth(grantee_id),
+            path(),
+            HeaderMap::new(),
+            Json(req()),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+
+        // 更新の委任
+        grants
+            .create(&Grant {
+                scope: GrantScope::Write,
+                ..grant.clone()
+            })
+            .await
+            .unwrap();
+        let (_, Json(updated)) = update_stream(
+            State(repo.clone()),
+            State(grants.clone()),
+            State(PreconditionConfig::default()),
+            user_auth(grantee_id),
+            path(),
+            HeaderMap::new(),
+            Json(req()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            updated.description.as_deref(),
+            Some("マネージャーが書いた概要欄")
+        );
+        let result = delete_stream(
+            State(repo.clone()),
+            State(grants.clone()),
+            State(PreconditionConfig::default()),
+            user_auth(grantee_id),
+            path(),
+            HeaderMap::new(),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+
+        // 削除の委任
+        grants
+            .create(&Grant {
+                scope: GrantScope::Delete,
+                ..grant
+            })
+            .await
+            .unwrap();
+        let status = delete_stream(
+            State(repo.clone()),
+            State(grants),
+            State(PreconditionConfig::default()),
+            user_auth(grantee_id),
+            path(),
+            HeaderMap::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(repo.find_by_id(stream.stream_id).await.unwrap().is_none());
+    }
 }
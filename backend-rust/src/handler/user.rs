@@ -0,0 +1,488 @@
+use super::api_key;
+use crate::{
+    auth::AuthUser,
+    error::AppError,
+    model::{Role, User},
+    repository::{ApiKeyRepository, UserRepository},
+    schema::{RoleResponse, UserListResponse, UserProfileRequest, UserResponse},
+};
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono_tz::Tz;
+use serde::Deserialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+#[derive(Deserialize, Default)]
+pub struct ListUsersQuery {
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+pub(crate) fn parse_user_id(user_id: &str) -> Result<Uuid, AppError> {
+    Uuid::parse_str(user_id).map_err(|_| AppError::Validation("Invalid userId format".to_string()))
+}
+
+fn role_to_response(role: Role) -> RoleResponse {
+    match role {
+        Role::Owner => RoleResponse::Owner,
+        Role::Moderator => RoleResponse::Moderator,
+        Role::Admin => RoleResponse::Admin,
+    }
+}
+
+pub(crate) fn to_response(user: User) -> UserResponse {
+    UserResponse {
+        user_id: Some(user.user_id.to_string()),
+        display_name: Some(user.display_name),
+        channel_handle: user.channel_handle,
+        time_zone: Some(user.time_zone),
+        locale: Some(user.locale),
+        role: Some(role_to_response(user.role)),
+        created_at: Some(user.created_at.to_rfc3339()),
+        api_key: None,
+    }
+}
+
+/// YouTube のチャンネルハンドル（`@` + 3〜30文字の英数字・`_`・`-`・`.`）かどうか
+fn is_valid_channel_handle(value: &str) -> bool {
+    let Some(name) = value.strip_prefix('@') else {
+        return false;
+    };
+    (3..=30).contains(&name.len())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// BCP 47 の言語タグ（`ja`、`ja-JP`、`zh-Hant-TW` など）の形式かどうか
+fn is_valid_locale(value: &str) -> bool {
+    let mut subtags = value.split('-');
+    let language = subtags.next().unwrap_or_default();
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_alphabetic())
+        && subtags
+            .all(|s| (1..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// リクエストで指定された項目だけをユーザーに反映する
+async fn apply_profile(
+    repo: &dyn UserRepository,
+    user: &mut User,
+    req: UserProfileRequest,
+) -> Result<(), AppError> {
+    if let Some(display_name) = req.display_name {
+        if display_name.chars().count() > 50 {
+            return Err(AppError::Validation(
+                "表示名は50文字以内で入力してください".to_string(),
+            ));
+        }
+        user.display_name = display_name;
+    }
+
+    if let Some(channel_handle) = req.channel_handle {
+        // 空文字はハンドルの解除
+        if channel_handle.is_empty() {
+            user.channel_handle = None;
+        } else {
+            if !is_valid_channel_handle(&channel_handle) {
+                return Err(AppError::Validation(
+                    "チャンネルハンドルは@から始まる3〜30文字の英数字・_・-・.で入力してください"
+                        .to_string(),
+                ));
+            }
+            if let Some(owner) = repo.find_by_channel_handle(&channel_handle).await? {
+                if owner.user_id != user.user_id {
+                    return Err(AppError::Conflict(
+                        "チャンネルハンドルは既に使用されています".to_string(),
+                    ));
+                }
+            }
+            user.channel_handle = Some(channel_handle);
+        }
+    }
+
+    if let Some(time_zone) = req.time_zone {
+        if time_zone.parse::<Tz>().is_err() {
+            return Err(AppError::Validation(
+                "timeZoneはIANAタイムゾーン名で指定してください".to_string(),
+            ));
+        }
+        user.time_zone = time_zone;
+    }
+
+    if let Some(locale) = req.locale {
+        if !is_valid_locale(&locale) {
+            return Err(AppError::Validation(
+                "localeはBCP 47の言語タグで指定してください".to_string(),
+            ));
+        }
+        user.locale = locale;
+    }
+
+    Ok(())
+}
+
+fn parse_profile_request(body: &[u8]) -> Result<UserProfileRequest, AppError> {
+    serde_json::from_slice(body)
+        .map_err(|_| AppError::Validation("リクエストボディの形式が正しくありません".to_string()))
+}
+
+/// プロフィールは任意のため、ボディなしのリクエストも受け付ける
+///
+/// 以降のリクエストで使う初回のAPIキーを発行してレスポンスに含める。
+pub async fn create_user(
+    State(repo): State<Arc<dyn UserRepository>>,
+    State(api_key_repo): State<Arc<dyn ApiKeyRepository>>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<UserResponse>), AppError> {
+    let mut user = User::new(Uuid::new_v4());
+    if !body.is_empty() {
+        let req = parse_profile_request(&body)?;
+        apply_profile(repo.as_ref(), &mut user, req).await?;
+    }
+
+    let created = repo.create(&user).await?;
+    let (_, token) = api_key::issue(api_key_repo.as_ref(), created.user_id, String::new()).await?;
+
+    let response = UserResponse {
+        api_key: Some(token),
+        ..to_response(created)
+    };
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+pub async fn get_users(
+    State(repo): State<Arc<dyn UserRepository>>,
+    Query(query): Query<ListUsersQuery>,
+) -> Result<Json<UserListResponse>, AppError> {
+    if let Some(limit) = query.limit {
+        if limit > 100 {
+            return Err(AppError::Validation(
+                "limitは100以下で指定してください".to_string(),
+            ));
+        }
+        if limit < 1 {
+            return Err(AppError::Validation(
+                "limitは1以上で指定してください".to_string(),
+            ));
+        }
+    }
+
+    let limit = query.limit.unwrap_or(10);
+    let offset = query.offset.unwrap_or(0);
+    let (users, total) = repo.find_all(Some(limit), Some(offset)).await?;
+
+    let response = UserListResponse {
+        total: Some(total as i32),
+        limit: Some(limit),
+        offset: Some(offset),
+        items: Some(users.into_iter().map(to_response).collect()),
+    };
+
+    Ok(Json(response))
+}
+
+pub async fn get_user(
+    State(repo): State<Arc<dyn UserRepository>>,
+    Path(user_id): Path<String>,
+) -> Result<Json<UserResponse>, AppError> {
+    let id = parse_user_id(&user_id)?;
+
+    let user = repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+    Ok(Json(to_response(user)))
+}
+
+pub async fn update_user(
+    State(repo): State<Arc<dyn UserRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+    body: Bytes,
+) -> Result<Json<UserResponse>, AppError> {
+    let id = parse_user_id(&user_id)?;
+    auth.ensure_user(id)?;
+    let req = parse_profile_request(&body)?;
+
+    let mut user = repo
+        .find_by_id(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+    apply_profile(repo.as_ref(), &mut user, req).await?;
+
+    let updated = repo.update(&user).await?;
+
+    Ok(Json(to_response(updated)))
+}
+
+pub async fn delete_user(
+    State(repo): State<Arc<dyn UserRepository>>,
+    auth: AuthUser,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let id = parse_user_id(&user_id)?;
+    auth.ensure_user(id)?;
+
+    if !repo.exists(id).await? {
+        return Err(AppError::NotFound("User not found".to_string()));
+    }
+
+    repo.delete(id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::mock::{MockApiKeyRepository, MockUserRepository};
+
+    fn api_key_repo() -> State<Arc<dyn ApiKeyRepository>> {
+        State(Arc::new(MockApiKeyRepository::default()))
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get_user() {
+        let repo = Arc::new(MockUserRepository::default());
+
+        let (status, Json(created)) =
+            create_user(State(repo.clone()), api_key_repo(), Bytes::new())
+                .await
+                .unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created.display_name.as_deref(), Some(""));
+        assert!(created.channel_handle.is_none());
+        assert_eq!(created.time_zone.as_deref(), Some("Asia/Tokyo"));
+        assert_eq!(created.locale.as_deref(), Some("ja-JP"));
+        assert!(created.api_key.as_deref().unwrap().starts_with("sk_"));
+        let user_id = created.user_id.clone().unwrap();
+
+        // 初回のAPIキーは登録時のレスポンスにのみ含まれる
+        let Json(found) = get_user(State(repo.clone()), Path(user_id)).await.unwrap();
+        assert!(found.api_key.is_none());
+        assert_eq!(
+            found,
+            UserResponse {
+                api_key: None,
+                ..created
+            }
+        );
+
+        // 存在しないユーザー
+        let result = get_user(State(repo.clone()), Path(Uuid::new_v4().to_string())).await;
+        match result.unwrap_err() {
+            AppError::NotFound(msg) => assert_eq!(msg, "User not found"),
+            _ => panic!("Unexpected error type"),
+        }
+
+        // 不正なユーザーID
+        let result = get_user(State(repo), Path("not-a-uuid".to_string())).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "Invalid userId format"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_user_with_profile() {
+        let repo = Arc::new(MockUserRepository::default());
+        let body = Bytes::from_static(
+            br#"{"displayName":"Taro","channelHandle":"@taro.live","timeZone":"America/New_York","locale":"en-US"}"#,
+        );
+
+        let (_, Json(created)) = create_user(State(repo.clone()), api_key_repo(), body.clone())
+            .await
+            .unwrap();
+        assert_eq!(created.display_name.as_deref(), Some("Taro"));
+        assert_eq!(created.channel_handle.as_deref(), Some("@taro.live"));
+        assert_eq!(created.time_zone.as_deref(), Some("America/New_York"));
+        assert_eq!(created.locale.as_deref(), Some("en-US"));
+
+        // 大文字小文字だけが異なるハンドルも使用済み
+        let body = Bytes::from_static(br#"{"channelHandle":"@TARO.live"}"#);
+        let result = create_user(State(repo), api_key_repo(), body).await;
+        match result.unwrap_err() {
+            AppError::Conflict(msg) => assert_eq!(msg, "チャンネルハンドルは既に使用されています"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_profile_validation() {
+        let repo = Arc::new(MockUserRepository::default());
+        let cases: [(&[u8], &str); 7] = [
+            (
+                br#"{"displayName":"012345678901234567890123456789012345678901234567890"}"#,
+                "表示名は50文字以内で入力してください",
+            ),
+            (
+                br#"{"channelHandle":"taro"}"#,
+                "チャンネルハンドルは@から始まる3〜30文字の英数字・_・-・.で入力してください",
+            ),
+            (
+                br#"{"channelHandle":"@ab"}"#,
+                "チャンネルハンドルは@から始まる3〜30文字の英数字・_・-・.で入力してください",
+            ),
+            (
+                br#"{"channelHandle":"@taro live"}"#,
+                "チャンネルハンドルは@から始まる3〜30文字の英数字・_・-・.で入力してください",
+            ),
+            (
+                br#"{"timeZone":"JST+9"}"#,
+                "timeZoneはIANAタイムゾーン名で指定してください",
+            ),
+            (
+                br#"{"locale":"japanese_JP"}"#,
+                "localeはBCP 47の言語タグで指定してください",
+            ),
+            (
+                br#"{"displayName":"#,
+                "リクエストボディの形式が正しくありません",
+            ),
+        ];
+
+        for (body, expected) in cases {
+            let result = create_user(
+                State(repo.clone()),
+                api_key_repo(),
+                Bytes::from_static(body),
+            )
+            .await;
+            match result.unwrap_err() {
+                AppError::Validation(msg) => assert_eq!(msg, expected),
+                _ => panic!("Unexpected error type"),
+            }
+        }
+        assert!(repo.users.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_user() {
+        let user_id = Uuid::new_v4();
+        let repo = Arc::new(MockUserRepository::with_users(&[user_id]));
+        let auth = AuthUser {
+            user_id,
+            role: Role::Owner,
+        };
+
+        let body = Bytes::from(r#"{"displayName":"配信太郎","channelHandle":"@haishin_taro"}"#);
+        let Json(updated) = update_user(State(repo.clone()), auth, Path(user_id.to_string()), body)
+            .await
+            .unwrap();
+        assert_eq!(updated.display_name.as_deref(), Some("配信太郎"));
+        assert_eq!(updated.channel_handle.as_deref(), Some("@haishin_taro"));
+
+        // 自分のハンドルを再指定しても競合しない / 指定しない項目は変わらない
+        let body = Bytes::from_static(br#"{"channelHandle":"@haishin_taro","locale":"en"}"#);
+        let Json(updated) = update_user(State(repo.clone()), auth, Path(user_id.to_string()), body)
+            .await
+            .unwrap();
+        assert_eq!(updated.display_name.as_deref(), Some("配信太郎"));
+        assert_eq!(updated.locale.as_deref(), Some("en"));
+
+        // 空文字でハンドルを解除
+        let body = Bytes::from_static(br#"{"channelHandle":""}"#);
+        let Json(updated) = update_user(State(repo.clone()), auth, Path(user_id.to_string()), body)
+            .await
+            .unwrap();
+        assert!(updated.channel_handle.is_none());
+
+        // 他のユーザーのプロフィールは更新できない
+        let other_user_id = Uuid::new_v4();
+        let result = update_user(
+            State(repo.clone()),
+            auth,
+            Path(other_user_id.to_string()),
+            Bytes::from_static(b"{}"),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+
+        let result = update_user(
+            State(repo),
+            AuthUser {
+                user_id: other_user_id,
+                role: Role::Owner,
+            },
+            Path(other_user_id.to_string()),
+            Bytes::from_static(b"{}"),
+        )
+        .await;
+        match result.unwrap_err() {
+            AppError::NotFound(msg) => assert_eq!(msg, "User not found"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_users() {
+        let repo = Arc::new(MockUserRepository::with_users(&[
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ]));
+
+        let query = ListUsersQuery {
+            limit: Some(2),
+            offset: Some(1),
+        };
+        let Json(response) = get_users(State(repo.clone()), Query(query)).await.unwrap();
+        assert_eq!(response.total, Some(3));
+        assert_eq!(response.limit, Some(2));
+        assert_eq!(response.offset, Some(1));
+        assert_eq!(response.items.unwrap().len(), 2);
+
+        let query = ListUsersQuery {
+            limit: Some(101),
+            ..Default::default()
+        };
+        let result = get_users(State(repo), Query(query)).await;
+        match result.unwrap_err() {
+            AppError::Validation(msg) => assert_eq!(msg, "limitは100以下で指定してください"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_user() {
+        let user_id = Uuid::new_v4();
+        let repo = Arc::new(MockUserRepository::with_users(&[user_id]));
+        let auth = AuthUser {
+            user_id,
+            role: Role::Owner,
+        };
+
+        // 他のユーザーは削除できない
+        let result = delete_user(
+            State(repo.clone()),
+            AuthUser {
+                user_id: Uuid::new_v4(),
+                role: Role::Owner,
+            },
+            Path(user_id.to_string()),
+        )
+        .await;
+        assert!(matches!(result.unwrap_err(), AppError::Forbidden(_)));
+
+        let status = delete_user(State(repo.clone()), auth, Path(user_id.to_string()))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(!repo.exists(user_id).await.unwrap());
+
+        // 削除済みのユーザーは見つからない
+        let result = delete_user(State(repo), auth, Path(user_id.to_string())).await;
+        match result.unwrap_err() {
+            AppError::NotFound(msg) => assert_eq!(msg, "User not found"),
+            _ => panic!("Unexpected error type"),
+        }
+    }
+}
@@ -0,0 +1,387 @@
+//! `Idempotency-Key` ヘッダーによる POST の重複実行の防止
+//!
+//! 同じユーザーが同じキーで再送したリクエストには、処理をやり直さずに最初の応答を返す。
+//! 応答を保存するのは成功した場合のみで、失敗した場合は同じキーで再送できる。
+
+use crate::{
+    auth::AuthUser, config::IdempotencyConfig, error::AppError, model::IdempotencyKey,
+    repository::IdempotencyKeyRepository,
+};
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+/// リクエストごとに一意な値を指定するリクエストヘッダー
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+/// 保存した応答を返したことを示すレスポンスヘッダー
+pub const REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// 応答とともに保存し、再送時にも返すレスポンスヘッダー
+const STORED_HEADERS: [HeaderName; 3] = [header::CONTENT_TYPE, header::LOCATION, header::ETAG];
+
+const MAX_KEY_LENGTH: usize = 255;
+/// ハッシュを計算するために読み込むリクエストボディの上限（axum の `Json` の既定と同じ）
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+/// 予約したまま応答が保存されないキー（処理中にサーバーが停止した場合など）を置き換えるまでの時間
+const STALE_AFTER_SECONDS: i64 = 60;
+
+/// メソッド・パス・ボディが同じリクエストは同じハッシュになる
+fn hash_request(method: &Method, path: &str, body: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str());
+    hasher.update([0]);
+    hasher.update(path);
+    hasher.update([0]);
+    hasher.update(body);
+    hasher.finalize().to_vec()
+}
+
+fn parse_key(value: &HeaderValue) -> Result<String, AppError> {
+    value
+        .to_str()
+        .ok()
+        .filter(|v| !v.is_empty() && v.len() <= MAX_KEY_LENGTH)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            AppError::Validation(
+                "Idempotency-Keyは255文字以内の英数字・記号で指定してください".to_string(),
+            )
+        })
+}
+
+fn stored_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    STORED_HEADERS
+        .iter()
+        .flat_map(|name| {
+            headers
+                .get_all(name)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .map(|value| (name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn replay(status: i16, stored: &[(String, String)], body: Vec<u8>) -> Response {
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = StatusCode::from_u16(status as u16).unwrap_or(StatusCode::OK);
+    let headers = response.headers_mut();
+    for (name, value) in stored {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            headers.append(name, value);
+        }
+    }
+    // ヘッダーを保存する前に完了したキーの応答
+    if !headers.contains_key(header::CONTENT_TYPE) {
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+    }
+    headers.insert(REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+/// `Idempotency-Key` ヘッダーがある認証済みのリクエストについて、キーを予約してから処理する
+///
+/// ヘッダーがない・未認証の場合はそのまま通す（認証の要否はハンドラーに任せる）。
+/// 同じキーを内容の異なるリクエストで使った場合は 422、最初のリクエストの処理中は 409 を返す。
+pub async fn idempotency(
+    State(repo): State<Arc<dyn IdempotencyKeyRepository>>,
+    State(config): State<IdempotencyConfig>,
+    req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let (Some(value), Some(auth)) = (
+        req.headers().get(IDEMPOTENCY_KEY_HEADER),
+        req.extensions().get::<AuthUser>().copied(),
+    ) else {
+        return Ok(next.run(req).await);
+    };
+    let key = parse_key(value)?;
+
+    let (parts, body) = req.into_parts();
+    let body = to_bytes(body, MAX_BODY_BYTES).await.map_err(|_| {
+        AppError::Validation("リクエストボディの形式が正しくありません".to_string())
+    })?;
+    let now = Utc::now();
+    let record = IdempotencyKey {
+        user_id: auth.user_id,
+        idempotency_key: key.clone(),
+        request_hash: hash_request(&parts.method, parts.uri.path(), &body),
+        response_status: None,
+        response_body: None,
+        response_headers: None,
+        created_at: now,
+        expires_at: now + Duration::hours(config.ttl_hours),
+    };
+
+    let stale_before = now - Duration::seconds(STALE_AFTER_SECONDS);
+    if let Some(existing) = repo.reserve(&record, stale_before).await? {
+        if existing.request_hash != record.request_hash {
+            return Err(AppError::UnprocessableEntity(
+                "このIdempotency-Keyは内容の異なるリクエストで使用済みです".to_string(),
+            ));
+        }
+        let stored = existing.response_headers.map(|h| h.0).unwrap_or_default();
+        return match (existing.response_status, existing.response_body) {
+            (Some(status), Some(body)) => Ok(replay(status, &stored, body)),
+            _ => Err(AppError::Conflict(
+                "同じIdempotency-Keyのリクエストを処理中です".to_string(),
+            )),
+        };
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if !response.status().is_success() {
+        repo.release(auth.user_id, &key).await?;
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(e) => {
+            repo.release(auth.user_id, &key).await?;
+            return Err(AppError::Internal(anyhow::anyhow!(e)));
+        }
+    };
+    repo.complete(
+        auth.user_id,
+        &key,
+        parts.status.as_u16() as i16,
+        &stored_headers(&parts.headers),
+        &body,
+    )
+    .await?;
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Role;
+    use crate::repository::mock::MockRepositories;
+    use axum::{routing::post, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    /// 呼び出し回数を数え、リクエストボディの `fail` が true の場合は 400 を返すハンドラー
+    fn app(repos: &MockRepositories, calls: Arc<AtomicUsize>, auth: AuthUser) -> Router {
+        let handler = move |body: String| {
+            let count = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                if body.contains("fail") {
+                    return (
+                        StatusCode::BAD_REQUEST,
+                        HeaderMap::new(),
+                        "failed".to_string(),
+                    );
+                }
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    header::LOCATION,
+                    HeaderValue::from_str(&format!("/streams/{}", count)).unwrap(),
+                );
+                headers.insert(
+                    header::CONTENT_TYPE,
+                    HeaderValue::from_static("application/json"),
+                );
+                headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+                (
+                    StatusCode::CREATED,
+                    headers,
+                    format!(r#"{{"count":{}}}"#, count),
+                )
+            }
+        };
+        let state = repos.state();
+        Router::new()
+            .route("/streams", post(handler))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                idempotency,
+            ))
+            .layer(axum::Extension(auth))
+            .with_state(state)
+    }
+
+    async fn send(app: &Router, key: Option<&str>, body: &'static str) -> Response {
+        let mut req = Request::builder().method(Method::POST).uri("/streams");
+        if let Some(key) = key {
+            req = req.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+        app.clone()
+            .oneshot(req.body(Body::from(body)).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn body_text(response: Response) -> String {
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    fn user() -> AuthUser {
+        AuthUser {
+            user_id: Uuid::new_v4(),
+            role: Role::Owner,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replays_first_response() {
+        let repos = MockRepositories::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(&repos, calls.clone(), user());
+
+        let first = send(&app, Some("key-1"), r#"{"title":"a"}"#).await;
+        assert_eq!(first.status(), StatusCode::CREATED);
+        assert!(first.headers().get(REPLAYED_HEADER).is_none());
+        assert_eq!(body_text(first).await, r#"{"count":1}"#);
+
+        let retry = send(&app, Some("key-1"), r#"{"title":"a"}"#).await;
+        assert_eq!(retry.status(), StatusCode::CREATED);
+        assert_eq!(retry.headers()[REPLAYED_HEADER], "true");
+        // Location などの保存するヘッダーは最初の応答と同じ値を返す
+        assert_eq!(retry.headers()[header::LOCATION], "/streams/1");
+        assert_eq!(retry.headers()[header::CONTENT_TYPE], "application/json");
+        assert!(retry.headers().get(header::CACHE_CONTROL).is_none());
+        assert_eq!(body_text(retry).await, r#"{"count":1}"#);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // キーが異なる・キーがない場合は毎回処理する
+        send(&app, Some("key-2"), r#"{"title":"a"}"#).await;
+        send(&app, None, r#"{"title":"a"}"#).await;
+        send(&app, None, r#"{"title":"a"}"#).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_replays_created_stream() {
+        let repos = MockRepositories::default();
+        let state = repos.state();
+        let app = Router::new()
+            .route("/api/v2/streams", post(crate::handler::create_stream))
+            .layer(axum::middleware::from_fn_with_state(
+                state.clone(),
+                idempotency,
+            ))
+            .layer(axum::Extension(user()))
+            .with_state(state);
+        let send = || {
+            let req = Request::builder()
+                .method(Method::POST)
+                .uri("/api/v2/streams")
+                .header(header::CONTENT_TYPE, "application/json")
+                .header(IDEMPOTENCY_KEY_HEADER, "key")
+                .body(Body::from(r#"{"title":"a","description":""}"#))
+                .unwrap();
+            app.clone().oneshot(req)
+        };
+
+        let first = send().await.unwrap();
+        assert_eq!(first.status(), StatusCode::CREATED);
+        let location = first.headers()[header::LOCATION].clone();
+        let etag = first.headers()[header::ETAG].clone();
+        let body = body_text(first).await;
+
+        // 再送しても登録し直さず、Location・ETag を含めて最初の応答を返す
+        let retry = send().await.unwrap();
+        assert_eq!(retry.status(), StatusCode::CREATED);
+        assert_eq!(retry.headers()[REPLAYED_HEADER], "true");
+        assert_eq!(retry.headers()[header::LOCATION], location);
+        assert_eq!(retry.headers()[header::ETAG], etag);
+        assert_eq!(retry.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(body_text(retry).await, body);
+
+        let streams = repos.streams.streams.lock().unwrap();
+        assert_eq!(streams.len(), 1);
+        assert_eq!(
+            location,
+            format!("/api/v2/streams/{}", streams[0].stream_id)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keys_are_scoped_per_user() {
+        let repos = MockRepositories::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        for auth in [user(), user()] {
+            let response = send(&app(&repos, calls.clone(), auth), Some("key"), "{}").await;
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_reused_key_with_different_body() {
+        let repos = MockRepositories::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(&repos, calls.clone(), user());
+
+        send(&app, Some("key"), r#"{"title":"a"}"#).await;
+        let response = send(&app, Some("key"), r#"{"title":"b"}"#).await;
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_requests_can_be_retried() {
+        let repos = MockRepositories::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(&repos, calls.clone(), user());
+
+        let response = send(&app, Some("key"), r#"{"fail":true}"#).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = send(&app, Some("key"), r#"{"fail":true}"#).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(repos.idempotency_keys.keys.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_in_progress_and_invalid_keys() {
+        let repos = MockRepositories::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let auth = user();
+        let app = app(&repos, calls.clone(), auth);
+
+        // 最初のリクエストが処理中（応答が保存されていない）
+        let now = Utc::now();
+        repos
+            .idempotency_keys
+            .keys
+            .lock()
+            .unwrap()
+            .push(IdempotencyKey {
+                user_id: auth.user_id,
+                idempotency_key: "key".to_string(),
+                request_hash: hash_request(&Method::POST, "/streams", b"{}"),
+                response_status: None,
+                response_body: None,
+                response_headers: None,
+                created_at: now,
+                expires_at: now + Duration::hours(1),
+            });
+        let response = send(&app, Some("key"), "{}").await;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = send(&app, Some(&"a".repeat(256)), "{}").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+}
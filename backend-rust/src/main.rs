@@ -1,18 +1,28 @@
+mod auth;
+mod cli;
 mod config;
+mod custom_method;
 mod error;
 mod handler;
+mod idempotency;
+mod markdown;
 mod model;
 mod repository;
 mod schema;
+mod state;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use axum::{
-    routing::{delete, get, post},
-    Router,
+    extract::DefaultBodyLimit,
+    http::{header, HeaderName, HeaderValue, Method},
+    middleware,
+    routing::{delete, get, patch, post, put},
+    Router, ServiceExt,
 };
 use std::sync::Arc;
 use tokio::signal;
-use tower_http::cors::{Any, CorsLayer};
+use tower::Layer;
+use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[tokio::main]
@@ -26,6 +36,12 @@ async fn main() -> Result<()> {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // サブコマンド（取り込みなどの管理用のコマンド）はサーバーを起動せずに実行する
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(result) = cli::run(&args).await {
+        return result;
+    }
+
     // 設定の読み込み
     let config = config::Config::from_env()?;
     tracing::info!("Starting server with config: {:?}", config);
@@ -40,30 +56,164 @@ async fn main() -> Result<()> {
 
     // リポジトリの初期化
     let stream_repo: Arc<dyn repository::StreamRepository> =
-        Arc::new(repository::StreamRepositoryImpl::new(pool));
+        Arc::new(repository::StreamRepositoryImpl::new(pool.clone()));
+    let user_repo: Arc<dyn repository::UserRepository> =
+        Arc::new(repository::UserRepositoryImpl::new(pool.clone()));
+    let api_key_repo: Arc<dyn repository::ApiKeyRepository> =
+        Arc::new(repository::ApiKeyRepositoryImpl::new(pool.clone()));
+    let session_repo: Arc<dyn repository::SessionRepository> =
+        Arc::new(repository::SessionRepositoryImpl::new(pool.clone()));
+    let grant_repo: Arc<dyn repository::GrantRepository> =
+        Arc::new(repository::GrantRepositoryImpl::new(pool.clone()));
+    let share_link_repo: Arc<dyn repository::ShareLinkRepository> =
+        Arc::new(repository::ShareLinkRepositoryImpl::new(pool.clone()));
+    let idempotency_key_repo: Arc<dyn repository::IdempotencyKeyRepository> =
+        Arc::new(repository::IdempotencyKeyRepositoryImpl::new(pool));
+    // JWT認証（設定されている場合のみ JWKS を読み込む）
+    let jwt = match &config.jwt {
+        Some(jwt_config) => {
+            let verifier = auth::jwt::JwtVerifier::load(jwt_config).await?;
+            tracing::info!("JWT authentication enabled (issuer: {})", jwt_config.issuer);
+            Some(Arc::new(verifier))
+        }
+        None => None,
+    };
+    let state = state::AppState {
+        stream_repo,
+        user_repo,
+        api_key_repo,
+        session_repo,
+        grant_repo,
+        share_link_repo,
+        idempotency_key_repo,
+        session_config: config.session.clone(),
+        idempotency_config: config.idempotency.clone(),
+        precondition_config: config.precondition.clone(),
+        jwt,
+    };
 
-    // CORSの設定
+    // CORSの設定（セッション Cookie を送れるよう、許可するオリジンを列挙する）
+    let origins = config
+        .cors_allowed_origins
+        .iter()
+        .map(|origin| origin.parse::<HeaderValue>())
+        .collect::<Result<Vec<_>, _>>()
+        .context("CORS_ALLOWED_ORIGINS must be a comma-separated list of origins")?;
     let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_credentials(true)
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            header::IF_MATCH,
+            header::IF_NONE_MATCH,
+            HeaderName::from_static(auth::API_KEY_HEADER),
+            HeaderName::from_static(auth::session::CSRF_HEADER),
+            HeaderName::from_static(idempotency::IDEMPOTENCY_KEY_HEADER),
+        ])
+        .expose_headers([
+            header::LINK,
+            header::LOCATION,
+            header::ETAG,
+            header::CONTENT_DISPOSITION,
+            HeaderName::from_static(idempotency::REPLAYED_HEADER),
+        ]);
 
     // ルーターの設定
     let app = Router::new()
-        .route("/api/v2/streams", post(handler::create_stream))
+        .route(
+            "/api/v2/streams",
+            post(handler::create_stream).layer(middleware::from_fn_with_state(
+                state.clone(),
+                idempotency::idempotency,
+            )),
+        )
         .route("/api/v2/streams", get(handler::get_streams))
+        .route(
+            &custom_method::path("/api/v2/streams", "batch"),
+            post(handler::batch_create_streams).layer(middleware::from_fn_with_state(
+                state.clone(),
+                idempotency::idempotency,
+            )),
+        )
+        .route(
+            &custom_method::path("/api/v2/streams", "batchGet"),
+            post(handler::batch_get_streams),
+        )
+        .route(
+            &custom_method::path("/api/v2/streams", "batchDelete"),
+            post(handler::batch_delete_streams),
+        )
         .route("/api/v2/streams/:stream_id", get(handler::get_stream))
+        .route("/api/v2/streams/:stream_id", patch(handler::update_stream))
         .route("/api/v2/streams/:stream_id", delete(handler::delete_stream))
+        .route(
+            "/api/v2/streams/:stream_id/share",
+            post(handler::create_share_link),
+        )
+        .route(
+            "/api/v2/streams/:stream_id/share",
+            get(handler::get_share_links),
+        )
+        .route(
+            "/api/v2/streams/:stream_id/share/:link_id",
+            delete(handler::revoke_share_link),
+        )
+        .route("/api/v2/shared/:token", get(handler::get_shared_stream))
+        .route("/api/v2/users", post(handler::create_user))
+        .route("/api/v2/users", get(handler::get_users))
+        .route("/api/v2/users/:user_id", get(handler::get_user))
+        .route("/api/v2/users/:user_id", patch(handler::update_user))
+        .route("/api/v2/users/:user_id", delete(handler::delete_user))
+        .route("/api/v2/users/:user_id/streams", get(handler::get_user_streams))
+        .route(
+            "/api/v2/users/:user_id/export",
+            get(handler::export_user_streams),
+        )
+        .route(
+            "/api/v2/users/:user_id/import",
+            post(handler::import_user_streams).layer(DefaultBodyLimit::max(
+                handler::import::MAX_IMPORT_BYTES,
+            )),
+        )
+        .route("/api/v2/users/:user_id/api-keys", post(handler::issue_api_key))
+        .route("/api/v2/users/:user_id/api-keys", get(handler::get_api_keys))
+        .route(
+            "/api/v2/users/:user_id/api-keys/:key_id",
+            delete(handler::revoke_api_key),
+        )
+        .route("/api/v2/users/:user_id/grants", post(handler::grant_access))
+        .route("/api/v2/users/:user_id/grants", get(handler::get_grants))
+        .route(
+            "/api/v2/users/:user_id/grants/:grant_id",
+            delete(handler::revoke_grant),
+        )
+        .route("/api/v2/sessions", post(handler::login))
+        .route("/api/v2/sessions/current", get(handler::get_current_session))
+        .route("/api/v2/sessions/current", delete(handler::logout))
+        .route("/api/v2/admin/streams", get(handler::get_all_streams))
+        .route(
+            "/api/v2/admin/users/:user_id/role",
+            put(handler::update_user_role),
+        )
+        // APIキー・JWT・セッションの検証（認証済みユーザーを extensions に格納する）
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::authenticate,
+        ))
         .layer(cors)
         .layer(tower_http::trace::TraceLayer::new_for_http())
-        .with_state(stream_repo);
+        .with_state(state);
+    // `/api/v2/streams:batch` などのカスタムメソッドはルーティングの前にパスを書き換える
+    let app = middleware::map_request(custom_method::rewrite).layer(app);
 
     // サーバー起動
     let addr = format!("0.0.0.0:{}", config.port);
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     tracing::info!("Server listening on {}", addr);
 
-    axum::serve(listener, app)
+    axum::serve(listener, app.into_make_service())
         .with_graceful_shutdown(shutdown_signal())
         .await?;
 
@@ -0,0 +1,387 @@
+//! フロントマター付きの Markdown・テキストファイル
+//!
+//! 先頭の `---` で囲んだ部分を YAML、`+++` で囲んだ部分を TOML のフロントマターとして読み込み、
+//! 残りを本文（配信の概要欄）として扱う。書き出す際は YAML のフロントマターを付ける。
+
+use crate::model::{Stream, Visibility};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+use chrono_tz::Tz;
+use serde::Serialize;
+use uuid::Uuid;
+
+const YAML_DELIMITER: &str = "---";
+const TOML_DELIMITER: &str = "+++";
+
+/// フロントマターで指定できる項目（指定がない項目は `None`）
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct FrontMatter {
+    pub title: Option<String>,
+    pub category: Option<String>,
+    /// `date`（書き出したファイルの `created_at` も受け付ける）
+    pub date: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Document {
+    pub front_matter: FrontMatter,
+    pub body: String,
+}
+
+/// 書き出すフロントマター（項目の順序を固定し、同じ配信は常に同じ内容になるようにする）
+#[derive(Serialize)]
+struct ExportedFrontMatter<'a> {
+    stream_id: Uuid,
+    title: &'a str,
+    category: &'a str,
+    visibility: Visibility,
+    created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deleted_at: Option<String>,
+}
+
+/// 読み込んだフロントマター（キーと値の組）
+enum Table {
+    Yaml(serde_norway::Mapping),
+    Toml(toml::Table),
+}
+
+impl Table {
+    fn parse(delimiter: &str, text: &str) -> Result<Self, String> {
+        if delimiter == TOML_DELIMITER {
+            return text
+                .parse()
+                .map(Self::Toml)
+                .map_err(|e| format!("フロントマターを読み込めません（{}）", e.message()));
+        }
+        match serde_norway::from_str(text) {
+            Ok(serde_norway::Value::Mapping(mapping)) => Ok(Self::Yaml(mapping)),
+            Ok(serde_norway::Value::Null) => Ok(Self::Yaml(serde_norway::Mapping::new())),
+            Ok(_) => Err("フロントマターはキーと値の組で指定してください".to_string()),
+            Err(e) => Err(format!("フロントマターを読み込めません（{}）", e)),
+        }
+    }
+
+    /// 文字列・数値・真偽値・日時を文字列として取り出す（配列・表は受け付けない）
+    fn get(&self, key: &str) -> Result<Option<String>, String> {
+        let invalid = || format!("{}は文字列で指定してください", key);
+        let value = match self {
+            Self::Yaml(mapping) => match mapping.get(key) {
+                None | Some(serde_norway::Value::Null) => None,
+                Some(serde_norway::Value::String(s)) => Some(s.clone()),
+                Some(serde_norway::Value::Number(n)) => Some(n.to_string()),
+                Some(serde_norway::Value::Bool(b)) => Some(b.to_string()),
+                Some(_) => return Err(invalid()),
+            },
+            Self::Toml(table) => match table.get(key) {
+                None => None,
+                Some(toml::Value::String(s)) => Some(s.clone()),
+                Some(toml::Value::Integer(i)) => Some(i.to_string()),
+                Some(toml::Value::Float(f)) => Some(f.to_string()),
+                Some(toml::Value::Boolean(b)) => Some(b.to_string()),
+                Some(toml::Value::Datetime(d)) => Some(d.to_string()),
+                Some(toml::Value::Array(_) | toml::Value::Table(_)) => return Err(invalid()),
+            },
+        };
+        Ok(value)
+    }
+
+    fn front_matter(&self) -> Result<FrontMatter, String> {
+        Ok(FrontMatter {
+            title: self.get("title")?,
+            category: self.get("category")?,
+            date: match self.get("date")? {
+                Some(date) => Some(date),
+                None => self.get("created_at")?,
+            },
+        })
+    }
+}
+
+/// ファイルの内容をフロントマターと本文に分ける（フロントマターがなければ全体が本文）
+///
+/// 本文の改行は LF に揃え、前後の空行を取り除く。
+pub fn parse(text: &str) -> Result<Document, String> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut lines = text.split_inclusive('\n');
+    let (front_matter, body) = match lines.next() {
+        Some(first) if first.trim_end() == YAML_DELIMITER || first.trim_end() == TOML_DELIMITER => {
+            let delimiter = first.trim_end();
+            let mut start = first.len();
+            let mut front = None;
+            for line in lines {
+                let end = start + line.len();
+                if line.trim_end() == delimiter {
+                    front = Some((&text[first.len()..start], &text[end..]));
+                    break;
+                }
+                start = end;
+            }
+            let (front, body) =
+                front.ok_or_else(|| "フロントマターが閉じられていません".to_string())?;
+            (Table::parse(delimiter, front)?.front_matter()?, body)
+        }
+        _ => (FrontMatter::default(), text),
+    };
+
+    Ok(Document {
+        front_matter,
+        body: body
+            .replace("\r\n", "\n")
+            .trim_start_matches('\n')
+            .trim_end()
+            .to_string(),
+    })
+}
+
+/// フロントマターの日時を読み込む
+///
+/// RFC 3339 以外の形式（`2025-01-02`・`2025-01-02 19:00` など）はタイムゾーン `tz` の日時として扱う。
+pub fn parse_date(value: &str, tz: Tz) -> Result<DateTime<Utc>, String> {
+    let value = value.trim();
+    if let Ok(date) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date.with_timezone(&Utc));
+    }
+    ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .and_then(|date| tz.from_local_datetime(&date).earliest())
+        .map(|date| date.with_timezone(&Utc))
+        .ok_or_else(|| "dateはRFC 3339形式またはYYYY-MM-DD形式で指定してください".to_string())
+}
+
+/// 書き出す日時（UTC、秒未満は必要な桁数だけ）
+fn format_datetime(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+}
+
+/// 配信を書き出す際のパス（`YYYY/MM/YYYY-MM-DD-{streamId}.md`、日付は `tz` での作成日）
+///
+/// タイトルを変えてもパスが変わらないよう、ファイル名には配信 ID を使う。
+pub fn file_path(stream: &Stream, tz: Tz) -> String {
+    format!(
+        "{}-{}.md",
+        stream
+            .created_at
+            .with_timezone(&tz)
+            .format("%Y/%m/%Y-%m-%d"),
+        stream.stream_id
+    )
+}
+
+/// 配信を Markdown ファイルにする（フロントマターの後に概要欄を続ける）
+pub fn render(stream: &Stream) -> Result<String, serde_norway::Error> {
+    let front_matter = serde_norway::to_string(&ExportedFrontMatter {
+        stream_id: stream.stream_id,
+        title: &stream.title,
+        category: &stream.category,
+        visibility: stream.visibility,
+        created_at: format_datetime(stream.created_at),
+        deleted_at: stream.deleted_at.map(format_datetime),
+    })?;
+    let mut text = format!("{}\n{}{}\n", YAML_DELIMITER, front_matter, YAML_DELIMITER);
+    if !stream.description.is_empty() {
+        text.push('\n');
+        text.push_str(&stream.description);
+        text.push('\n');
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn front_matter(title: &str, category: Option<&str>, date: Option<&str>) -> FrontMatter {
+        FrontMatter {
+            title: Some(title.to_string()),
+            category: category.map(str::to_string),
+            date: date.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_parse_yaml() {
+        let document = parse(
+            "---\ntitle: 今日の配信\ncategory: game\ndate: 2025-01-02\ntags: [a, b]\n---\n\n本文\n\n2行目\n",
+        )
+        .unwrap();
+        assert_eq!(
+            document,
+            Document {
+                front_matter: front_matter("今日の配信", Some("game"), Some("2025-01-02")),
+                body: "本文\n\n2行目".to_string(),
+            }
+        );
+
+        // 書き出したファイルの created_at・数値のタイトル・CRLF
+        let document =
+            parse("---\r\ntitle: 2025\r\ncreated_at: 2025-01-02T03:04:05Z\r\n---\r\n本文\r\n")
+                .unwrap();
+        assert_eq!(
+            document.front_matter,
+            front_matter("2025", None, Some("2025-01-02T03:04:05Z"))
+        );
+        assert_eq!(document.body, "本文");
+
+        // 空のフロントマター
+        let document = parse("---\n---\n本文").unwrap();
+        assert_eq!(document.front_matter, FrontMatter::default());
+        assert_eq!(document.body, "本文");
+    }
+
+    #[test]
+    fn test_parse_toml() {
+        let document = parse(
+            "+++\ntitle = \"今日の配信\"\ncategory = \"chat\"\ndate = 2025-01-02T19:00:00+09:00\n+++\n本文\n",
+        )
+        .unwrap();
+        assert_eq!(
+            document,
+            Document {
+                front_matter: front_matter(
+                    "今日の配信",
+                    Some("chat"),
+                    Some("2025-01-02T19:00:00+09:00")
+                ),
+                body: "本文".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_without_front_matter() {
+        for text in ["\u{feff}本文\n---\n続き\n", "\n\n本文\n---\n続き"] {
+            let document = parse(text).unwrap();
+            assert_eq!(document.front_matter, FrontMatter::default());
+            assert_eq!(document.body, "本文\n---\n続き");
+        }
+        assert_eq!(parse("").unwrap().body, "");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for (text, message) in [
+            ("---\ntitle: a\n", "フロントマターが閉じられていません"),
+            (
+                "+++\ntitle = \"a\"\n---\n",
+                "フロントマターが閉じられていません",
+            ),
+            (
+                "---\n- a\n- b\n---\n",
+                "フロントマターはキーと値の組で指定してください",
+            ),
+            ("---\ntitle: [a]\n---\n", "titleは文字列で指定してください"),
+            (
+                "+++\ncategory = {}\n+++\n",
+                "categoryは文字列で指定してください",
+            ),
+        ] {
+            assert_eq!(parse(text).unwrap_err(), message, "{}", text);
+        }
+        assert!(parse("---\ntitle: [a\n---\n").is_err());
+        assert!(parse("+++\ntitle = \n+++\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let utc = |value: &str| value.parse::<DateTime<Utc>>().unwrap();
+
+        assert_eq!(
+            parse_date("2025-01-02T03:04:05Z", tokyo).unwrap(),
+            utc("2025-01-02T03:04:05Z")
+        );
+        assert_eq!(
+            parse_date("2025-01-02", tokyo).unwrap(),
+            utc("2025-01-01T15:00:00Z")
+        );
+        assert_eq!(
+            parse_date("2025-01-02 19:00", tokyo).unwrap(),
+            utc("2025-01-02T10:00:00Z")
+        );
+        assert_eq!(
+            parse_date("2025-01-02T19:00:00", Tz::UTC).unwrap(),
+            utc("2025-01-02T19:00:00Z")
+        );
+        assert!(parse_date("2025/01/02", tokyo).is_err());
+    }
+
+    fn stream(title: &str, description: &str) -> Stream {
+        Stream {
+            stream_id: Uuid::parse_str("6a781015-dea7-4fda-9a94-cef4d51055d8").unwrap(),
+            user_id: Uuid::new_v4(),
+            title: title.to_string(),
+            description: description.to_string(),
+            category: "game".to_string(),
+            visibility: Visibility::Unlisted,
+            version: 1,
+            created_at: "2025-01-02T03:04:05.120Z".parse().unwrap(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_file_path() {
+        assert_eq!(
+            file_path(&stream("a", ""), Tz::UTC),
+            "2025/01/2025-01-02-6a781015-dea7-4fda-9a94-cef4d51055d8.md"
+        );
+
+        // UTC では前日でも、タイムゾーンでの日付で分ける
+        let mut late = stream("a", "");
+        late.created_at = "2024-12-31T20:00:00Z".parse().unwrap();
+        assert_eq!(
+            file_path(&late, "Asia/Tokyo".parse().unwrap()),
+            "2025/01/2025-01-01-6a781015-dea7-4fda-9a94-cef4d51055d8.md"
+        );
+        assert_eq!(
+            file_path(&late, Tz::UTC),
+            "2024/12/2024-12-31-6a781015-dea7-4fda-9a94-cef4d51055d8.md"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let first = stream("今日の配信", "1行目\n\n---\n2行目");
+        assert_eq!(
+            render(&first).unwrap(),
+            "---
+stream_id: 6a781015-dea7-4fda-9a94-cef4d51055d8
+title: 今日の配信
+category: game
+visibility: unlisted
+created_at: 2025-01-02T03:04:05.120Z
+---
+
+1行目
+
+---
+2行目
+"
+        );
+
+        // 書き出したファイルは取り込みでそのまま読み込める
+        let document = parse(&render(&first).unwrap()).unwrap();
+        assert_eq!(document.front_matter.title.as_deref(), Some("今日の配信"));
+        assert_eq!(
+            parse_date(document.front_matter.date.as_deref().unwrap(), Tz::UTC).unwrap(),
+            first.created_at
+        );
+        assert_eq!(document.body, first.description);
+
+        // YAML として特別な意味を持つタイトル・削除済みの配信・空の概要欄
+        let mut other = stream("2025: \"a\"", "");
+        other.deleted_at = Some("2025-02-01T00:00:00Z".parse().unwrap());
+        let text = render(&other).unwrap();
+        assert!(text.ends_with("deleted_at: 2025-02-01T00:00:00Z\n---\n"));
+        assert_eq!(
+            parse(&text).unwrap().front_matter.title.as_deref(),
+            Some("2025: \"a\"")
+        );
+    }
+}
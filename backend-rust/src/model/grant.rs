@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 委任する操作の範囲（上位の範囲は下位の範囲を含む）
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type,
+)]
+#[sqlx(type_name = "grant_scope", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum GrantScope {
+    /// 非公開・下書きの配信の閲覧
+    Read,
+    /// 閲覧と更新
+    Write,
+    /// 閲覧・更新・削除
+    Delete,
+}
+
+impl GrantScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GrantScope::Read => "read",
+            GrantScope::Write => "write",
+            GrantScope::Delete => "delete",
+        }
+    }
+}
+
+/// 配信者（`owner_id`）が自分のすべての配信の操作を他のユーザーに委任する権限
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Grant {
+    pub grant_id: Uuid,
+    pub owner_id: Uuid,
+    pub grantee_id: Uuid,
+    pub scope: GrantScope,
+    pub created_at: DateTime<Utc>,
+    /// 未指定の場合は取り消すまで有効
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Grant {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|e| e > now)
+    }
+}
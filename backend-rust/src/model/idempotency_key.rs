@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use uuid::Uuid;
+
+/// `Idempotency-Key` を付けたリクエストと、その応答（キーはユーザーごとに一意）
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct IdempotencyKey {
+    pub user_id: Uuid,
+    pub idempotency_key: String,
+    /// メソッド・パス・リクエストボディの SHA-256 ハッシュ
+    pub request_hash: Vec<u8>,
+    /// 最初のリクエストを処理中の間は `None`
+    pub response_status: Option<i16>,
+    pub response_body: Option<Vec<u8>>,
+    /// 再送時にも返すレスポンスヘッダー（名前と値の組）
+    pub response_headers: Option<Json<Vec<(String, String)>>>,
+    pub created_at: DateTime<Utc>,
+    /// 期限を過ぎたキーは新しいリクエストとして扱う
+    pub expires_at: DateTime<Utc>,
+}
@@ -1,3 +1,18 @@
+pub mod api_key;
+pub mod grant;
+pub mod idempotency_key;
+pub mod session;
+pub mod share_link;
 pub mod stream;
+pub mod user;
 
-pub use stream::Stream;
+pub use api_key::ApiKey;
+pub use grant::{Grant, GrantScope};
+pub use idempotency_key::IdempotencyKey;
+pub use session::Session;
+pub use share_link::ShareLink;
+pub use stream::{
+    PrivateScope, SortOrder, Stream, StreamColumns, StreamCursor, StreamFilter, StreamSort,
+    StreamSortKey, Visibility, YoutubeVideo,
+};
+pub use user::{Role, User};
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Cookie によるログインセッション（セッショントークンはハッシュのみを保持する）
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Session {
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    #[serde(skip)]
+    pub token_hash: Vec<u8>,
+    #[serde(skip)]
+    pub csrf_token: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
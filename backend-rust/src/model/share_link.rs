@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 配信1件を未ログインの相手に読み取り専用で共有するリンク（トークンはハッシュのみを保持する）
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ShareLink {
+    pub link_id: Uuid,
+    pub stream_id: Uuid,
+    /// リンクを発行したユーザー
+    pub created_by: Uuid,
+    #[serde(skip)]
+    pub token_hash: Vec<u8>,
+    pub created_at: DateTime<Utc>,
+    /// 未指定の場合は取り消すまで有効
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// 共有リンクから配信を取得した回数
+    pub access_count: i64,
+    pub last_accessed_at: Option<DateTime<Utc>>,
+}
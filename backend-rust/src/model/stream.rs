@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -9,6 +10,191 @@ pub struct Stream {
     pub title: String,
     pub description: String,
     pub category: String,
+    pub visibility: Visibility,
+    /// 更新のたびに1つ進める版（ETag として楽観的排他制御に使う）
+    pub version: i64,
     pub created_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
+
+impl Stream {
+    /// タイトル・概要欄・カテゴリから求めるハッシュ（インポート時の重複の判定に使う）
+    pub fn content_hash(&self) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        for field in [&self.title, &self.description, &self.category] {
+            // 区切り文字を含む値でも衝突しないよう、長さを前に付ける
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field);
+        }
+        hasher.finalize().to_vec()
+    }
+}
+
+/// 配信の公開状態
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "stream_visibility", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    /// 下書き（タイトルが空でも保存でき、明示しない限り一覧に含めない）
+    Draft,
+    /// 配信者本人と委任されたユーザー、モデレーター・管理者のみ閲覧できる
+    Private,
+    /// URL を知っていれば誰でも閲覧できるが、他のユーザーの一覧には表示しない
+    Unlisted,
+    #[default]
+    Published,
+}
+
+impl Visibility {
+    pub const ALL: [Visibility; 4] = [
+        Visibility::Draft,
+        Visibility::Private,
+        Visibility::Unlisted,
+        Visibility::Published,
+    ];
+
+    pub fn from_param(value: &str) -> Option<Self> {
+        match value {
+            "draft" => Some(Self::Draft),
+            "private" => Some(Self::Private),
+            "unlisted" => Some(Self::Unlisted),
+            "published" => Some(Self::Published),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Private => "private",
+            Self::Unlisted => "unlisted",
+            Self::Published => "published",
+        }
+    }
+
+    /// URL を知っていれば誰でも閲覧できる
+    pub fn is_viewable_by_anyone(&self) -> bool {
+        matches!(self, Self::Unlisted | Self::Published)
+    }
+}
+
+/// 一覧に含める公開（`published`）以外の配信の範囲
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrivateScope {
+    /// 公開の配信のみ
+    #[default]
+    None,
+    /// 指定したユーザーと、そのユーザーに委任した配信者の公開以外の配信を含める
+    OwnedBy(Uuid),
+    /// すべての配信を含める（モデレーター・管理者）
+    All,
+}
+
+/// 一覧の絞り込み条件（空の条件は絞り込まない）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamFilter {
+    /// いずれかに一致するカテゴリ
+    pub categories: Vec<String>,
+    /// 除外するカテゴリ
+    pub exclude_categories: Vec<String>,
+    pub user_id: Option<Uuid>,
+    /// 作成日時の下限（この日時を含む）
+    pub created_from: Option<DateTime<Utc>>,
+    /// 作成日時の上限（この日時を含まない）
+    pub created_to: Option<DateTime<Utc>>,
+    /// いずれかに一致する公開状態（空の場合は下書き以外）
+    pub visibilities: Vec<Visibility>,
+    /// 公開以外の配信を含める範囲
+    pub private_scope: PrivateScope,
+    /// 論理削除した配信も含める（管理者向けの一覧）
+    pub include_deleted: bool,
+}
+
+/// 一覧で取得する列（取得しない列は空文字として返す。ID・公開状態・日時は常に取得する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamColumns {
+    pub title: bool,
+    pub description: bool,
+    pub category: bool,
+}
+
+impl StreamColumns {
+    pub const ALL: Self = Self {
+        title: true,
+        description: true,
+        category: true,
+    };
+}
+
+/// 配信の元になった YouTube の動画（Google Takeout などからの取り込み時に記録する）
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct YoutubeVideo {
+    pub stream_id: Uuid,
+    pub video_id: String,
+}
+
+/// キーセットページネーションの位置（`created_at, stream_id` の並び順上の最後の要素）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamCursor {
+    pub created_at: DateTime<Utc>,
+    pub stream_id: Uuid,
+}
+
+/// 一覧の並び替えキー（SQLに埋め込む列名はここで定義したものに限定する）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StreamSortKey {
+    #[default]
+    CreatedAt,
+    Title,
+    Category,
+}
+
+impl StreamSortKey {
+    pub fn from_param(value: &str) -> Option<Self> {
+        match value {
+            "created_at" => Some(Self::CreatedAt),
+            "title" => Some(Self::Title),
+            "category" => Some(Self::Category),
+            _ => None,
+        }
+    }
+
+    pub fn column(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at",
+            Self::Title => "title",
+            Self::Category => "category",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl SortOrder {
+    pub fn from_param(value: &str) -> Option<Self> {
+        match value {
+            "asc" => Some(Self::Asc),
+            "desc" => Some(Self::Desc),
+            _ => None,
+        }
+    }
+
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Asc => "ASC",
+            Self::Desc => "DESC",
+        }
+    }
+}
+
+/// 一覧の並び順（同じ値の行は `stream_id` で順序を固定する）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamSort {
+    pub key: StreamSortKey,
+    pub order: SortOrder,
+}
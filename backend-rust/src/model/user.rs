@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const DEFAULT_TIME_ZONE: &str = "Asia/Tokyo";
+pub const DEFAULT_LOCALE: &str = "ja-JP";
+
+/// ユーザーの役割（権限の詳細は `auth::permission` を参照）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "user_role", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// 自分の配信のみを管理する配信者
+    #[default]
+    Owner,
+    /// 配信者のサポート担当（全ユーザーの配信を閲覧・編集できる）
+    Moderator,
+    /// 管理者（全ユーザーの配信を閲覧・編集・削除できる）
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Owner => "owner",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct User {
+    pub user_id: Uuid,
+    pub display_name: String,
+    /// YouTube のチャンネルハンドル（`@` を含む）
+    pub channel_handle: Option<String>,
+    /// IANA タイムゾーン名
+    pub time_zone: String,
+    /// BCP 47 の言語タグ
+    pub locale: String,
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl User {
+    /// プロフィール未設定（既定値）のユーザーを作成する
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            display_name: String::new(),
+            channel_handle: None,
+            time_zone: DEFAULT_TIME_ZONE.to_string(),
+            locale: DEFAULT_LOCALE.to_string(),
+            role: Role::Owner,
+            created_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+}
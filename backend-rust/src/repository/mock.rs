@@ -0,0 +1,690 @@
+//! ハンドラーのテストで使うインメモリのリポジトリ
+
+use super::{
+    ApiKeyRepository, GrantRepository, IdempotencyKeyRepository, SessionRepository,
+    ShareLinkRepository, StreamRepository, UserRepository,
+};
+use crate::config::{IdempotencyConfig, PreconditionConfig, SessionConfig};
+use crate::model::{
+    ApiKey, Grant, GrantScope, IdempotencyKey, PrivateScope, Role, Session, ShareLink, Stream,
+    StreamColumns, StreamCursor, StreamFilter, StreamSort, User, Visibility, YoutubeVideo,
+};
+use crate::state::AppState;
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+use uuid::Uuid;
+
+/// ルーター全体のテスト用に、各リポジトリのモックをまとめて保持する
+#[derive(Clone)]
+pub struct MockRepositories {
+    pub streams: Arc<MockStreamRepository>,
+    pub users: Arc<MockUserRepository>,
+    pub api_keys: Arc<MockApiKeyRepository>,
+    pub sessions: Arc<MockSessionRepository>,
+    pub grants: Arc<MockGrantRepository>,
+    pub share_links: Arc<MockShareLinkRepository>,
+    pub idempotency_keys: Arc<MockIdempotencyKeyRepository>,
+}
+
+impl Default for MockRepositories {
+    fn default() -> Self {
+        let streams = Arc::new(MockStreamRepository::default());
+        let users = Arc::new(MockUserRepository::default());
+        let grants = Arc::new(MockGrantRepository::default());
+        Self {
+            share_links: Arc::new(MockShareLinkRepository::new(
+                streams.clone(),
+                users.clone(),
+                grants.clone(),
+            )),
+            streams,
+            users,
+            api_keys: Default::default(),
+            sessions: Default::default(),
+            grants,
+            idempotency_keys: Default::default(),
+        }
+    }
+}
+
+impl MockRepositories {
+    pub fn state(&self) -> AppState {
+        AppState {
+            stream_repo: self.streams.clone(),
+            user_repo: self.users.clone(),
+            api_key_repo: self.api_keys.clone(),
+            session_repo: self.sessions.clone(),
+            grant_repo: self.grants.clone(),
+            share_link_repo: self.share_links.clone(),
+            idempotency_key_repo: self.idempotency_keys.clone(),
+            session_config: SessionConfig::default(),
+            idempotency_config: IdempotencyConfig::default(),
+            precondition_config: PreconditionConfig::default(),
+            jwt: None,
+        }
+    }
+}
+
+/// 絞り込みはユーザー・公開状態・公開以外の範囲・削除済みの有無のみ対応し、並び順は登録順のまま返す
+///
+/// 委任による非公開の配信の閲覧は一覧には反映しない。
+#[derive(Default)]
+pub struct MockStreamRepository {
+    pub streams: Mutex<Vec<Stream>>,
+    pub videos: Mutex<Vec<YoutubeVideo>>,
+    /// 読み込んだ直後に他のユーザーが更新した状態を再現する（`find_by_id` の後に版を進める）
+    pub concurrent_update: AtomicBool,
+}
+
+#[async_trait]
+impl StreamRepository for MockStreamRepository {
+    async fn create(&self, stream: &Stream) -> Result<Stream> {
+        self.streams.lock().unwrap().push(stream.clone());
+        Ok(stream.clone())
+    }
+
+    async fn create_many(&self, streams: &[Stream]) -> Result<Vec<Stream>> {
+        self.streams.lock().unwrap().extend_from_slice(streams);
+        Ok(streams.to_vec())
+    }
+
+    async fn create_many_with_videos(
+        &self,
+        streams: &[Stream],
+        videos: &[YoutubeVideo],
+    ) -> Result<Vec<Stream>> {
+        self.videos.lock().unwrap().extend_from_slice(videos);
+        self.create_many(streams).await
+    }
+
+    async fn find_youtube_videos(&self, user_id: Uuid) -> Result<Vec<YoutubeVideo>> {
+        let streams = self.streams.lock().unwrap();
+        Ok(self
+            .videos
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|v| {
+                streams.iter().any(|s| {
+                    s.stream_id == v.stream_id && s.user_id == user_id && s.deleted_at.is_none()
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_id(&self, stream_id: Uuid) -> Result<Option<Stream>> {
+        let mut streams = self.streams.lock().unwrap();
+        let Some(stream) = streams
+            .iter_mut()
+            .find(|s| s.stream_id == stream_id && s.deleted_at.is_none())
+        else {
+            return Ok(None);
+        };
+        let found = stream.clone();
+        if self.concurrent_update.load(Ordering::SeqCst) {
+            stream.version += 1;
+        }
+        Ok(Some(found))
+    }
+
+    async fn find_by_ids(&self, stream_ids: &[Uuid]) -> Result<Vec<Stream>> {
+        let streams = self.streams.lock().unwrap();
+        Ok(stream_ids
+            .iter()
+            .filter_map(|id| {
+                streams
+                    .iter()
+                    .find(|s| s.stream_id == *id && s.deleted_at.is_none())
+                    .cloned()
+            })
+            .collect())
+    }
+
+    async fn find_all(
+        &self,
+        filter: &StreamFilter,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        _cursor: Option<StreamCursor>,
+        _sort: StreamSort,
+        columns: StreamColumns,
+    ) -> Result<(Vec<Stream>, i64)> {
+        let streams: Vec<Stream> = self
+            .streams
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| filter.include_deleted || s.deleted_at.is_none())
+            .filter(|s| filter.user_id.is_none_or(|user_id| s.user_id == user_id))
+            .filter(|s| {
+                if filter.visibilities.is_empty() {
+                    s.visibility != Visibility::Draft
+                } else {
+                    filter.visibilities.contains(&s.visibility)
+                }
+            })
+            .filter(|s| match filter.private_scope {
+                PrivateScope::None => s.visibility == Visibility::Published,
+                PrivateScope::OwnedBy(user_id) => {
+                    s.visibility == Visibility::Published || s.user_id == user_id
+                }
+                PrivateScope::All => true,
+            })
+            .cloned()
+            .collect();
+        let total = streams.len() as i64;
+        let items = streams
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(10) as usize)
+            .map(|s| Stream {
+                title: if columns.title {
+                    s.title
+                } else {
+                    String::new()
+                },
+                description: if columns.description {
+                    s.description
+                } else {
+                    String::new()
+                },
+                category: if columns.category {
+                    s.category
+                } else {
+                    String::new()
+                },
+                ..s
+            })
+            .collect();
+        Ok((items, total))
+    }
+
+    async fn find_by_user_after(
+        &self,
+        user_id: Uuid,
+        include_deleted: bool,
+        after: Option<StreamCursor>,
+        limit: i64,
+    ) -> Result<Vec<Stream>> {
+        let mut streams: Vec<Stream> = self
+            .streams
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| s.user_id == user_id && (include_deleted || s.deleted_at.is_none()))
+            .filter(|s| {
+                after.is_none_or(|c| (s.created_at, s.stream_id) > (c.created_at, c.stream_id))
+            })
+            .cloned()
+            .collect();
+        streams.sort_by_key(|s| (s.created_at, s.stream_id));
+        streams.truncate(limit as usize);
+        Ok(streams)
+    }
+
+    async fn update(&self, stream: &Stream) -> Result<Option<Stream>> {
+        let mut streams = self.streams.lock().unwrap();
+        let stored = streams
+            .iter_mut()
+            .find(|s| s.stream_id == stream.stream_id && s.deleted_at.is_none())
+            .ok_or_else(|| anyhow::anyhow!("stream not found"))?;
+        if stored.version != stream.version {
+            return Ok(None);
+        }
+        *stored = Stream {
+            version: stream.version + 1,
+            ..stream.clone()
+        };
+        Ok(Some(stored.clone()))
+    }
+
+    async fn delete(&self, stream_id: Uuid, version: i64) -> Result<bool> {
+        for stream in self.streams.lock().unwrap().iter_mut() {
+            if stream.stream_id == stream_id
+                && stream.version == version
+                && stream.deleted_at.is_none()
+            {
+                stream.deleted_at = Some(Utc::now());
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    async fn delete_many(
+        &self,
+        streams: &[(Uuid, i64)],
+        all_or_nothing: bool,
+    ) -> Result<Vec<Uuid>> {
+        let mut stored = self.streams.lock().unwrap();
+        let matched: Vec<Uuid> = streams
+            .iter()
+            .filter(|(stream_id, version)| {
+                stored.iter().any(|s| {
+                    s.stream_id == *stream_id && s.version == *version && s.deleted_at.is_none()
+                })
+            })
+            .map(|(stream_id, _)| *stream_id)
+            .collect();
+        if !all_or_nothing || matched.len() == streams.len() {
+            for stream in stored.iter_mut() {
+                if matched.contains(&stream.stream_id) {
+                    stream.deleted_at = Some(Utc::now());
+                }
+            }
+        }
+        Ok(matched)
+    }
+}
+
+#[derive(Default)]
+pub struct MockUserRepository {
+    pub users: Mutex<Vec<User>>,
+}
+
+impl MockUserRepository {
+    /// 指定したIDのユーザーが登録済みの状態で作成する
+    pub fn with_users(user_ids: &[Uuid]) -> Self {
+        let users = user_ids.iter().map(|&user_id| User::new(user_id)).collect();
+        Self {
+            users: Mutex::new(users),
+        }
+    }
+}
+
+#[async_trait]
+impl UserRepository for MockUserRepository {
+    async fn create(&self, user: &User) -> Result<User> {
+        self.users.lock().unwrap().push(user.clone());
+        Ok(user.clone())
+    }
+
+    async fn find_by_id(&self, user_id: Uuid) -> Result<Option<User>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| u.user_id == user_id && u.deleted_at.is_none())
+            .cloned())
+    }
+
+    async fn find_by_channel_handle(&self, channel_handle: &str) -> Result<Option<User>> {
+        Ok(self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|u| {
+                u.deleted_at.is_none()
+                    && u.channel_handle
+                        .as_deref()
+                        .is_some_and(|h| h.eq_ignore_ascii_case(channel_handle))
+            })
+            .cloned())
+    }
+
+    async fn find_all(&self, limit: Option<i32>, offset: Option<i32>) -> Result<(Vec<User>, i64)> {
+        let users: Vec<User> = self
+            .users
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|u| u.deleted_at.is_none())
+            .cloned()
+            .collect();
+        let total = users.len() as i64;
+        let items = users
+            .into_iter()
+            .skip(offset.unwrap_or(0) as usize)
+            .take(limit.unwrap_or(10) as usize)
+            .collect();
+        Ok((items, total))
+    }
+
+    async fn update(&self, user: &User) -> Result<User> {
+        let mut users = self.users.lock().unwrap();
+        let stored = users
+            .iter_mut()
+            .find(|u| u.user_id == user.user_id && u.deleted_at.is_none())
+            .ok_or_else(|| anyhow::anyhow!("user not found"))?;
+        *stored = user.clone();
+        Ok(stored.clone())
+    }
+
+    async fn update_role(&self, user_id: Uuid, role: Role) -> Result<Option<User>> {
+        let mut users = self.users.lock().unwrap();
+        Ok(users
+            .iter_mut()
+            .find(|u| u.user_id == user_id && u.deleted_at.is_none())
+            .map(|user| {
+                user.role = role;
+                user.clone()
+            }))
+    }
+
+    async fn delete(&self, user_id: Uuid) -> Result<()> {
+        for user in self.users.lock().unwrap().iter_mut() {
+            if user.user_id == user_id && user.deleted_at.is_none() {
+                user.deleted_at = Some(Utc::now());
+            }
+        }
+        Ok(())
+    }
+
+    async fn exists(&self, user_id: Uuid) -> Result<bool> {
+        Ok(self.find_by_id(user_id).await?.is_some())
+    }
+}
+
+#[derive(Default)]
+pub struct MockApiKeyRepository {
+    pub api_keys: Mutex<Vec<ApiKey>>,
+}
+
+#[async_trait]
+impl ApiKeyRepository for MockApiKeyRepository {
+    async fn create(&self, api_key: &ApiKey) -> Result<ApiKey> {
+        self.api_keys.lock().unwrap().push(api_key.clone());
+        Ok(api_key.clone())
+    }
+
+    async fn find_active_by_id(&self, key_id: Uuid) -> Result<Option<ApiKey>> {
+        Ok(self
+            .api_keys
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|k| k.key_id == key_id && k.revoked_at.is_none())
+            .cloned())
+    }
+
+    async fn find_active_by_user(&self, user_id: Uuid) -> Result<Vec<ApiKey>> {
+        Ok(self
+            .api_keys
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|k| k.user_id == user_id && k.revoked_at.is_none())
+            .cloned()
+            .collect())
+    }
+
+    async fn revoke(&self, user_id: Uuid, key_id: Uuid) -> Result<bool> {
+        let mut api_keys = self.api_keys.lock().unwrap();
+        match api_keys
+            .iter_mut()
+            .find(|k| k.key_id == key_id && k.user_id == user_id && k.revoked_at.is_none())
+        {
+            Some(api_key) => {
+                api_key.revoked_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct MockSessionRepository {
+    pub sessions: Mutex<Vec<Session>>,
+}
+
+#[async_trait]
+impl SessionRepository for MockSessionRepository {
+    async fn create(&self, session: &Session) -> Result<Session> {
+        self.sessions.lock().unwrap().push(session.clone());
+        Ok(session.clone())
+    }
+
+    async fn find_active_by_token_hash(&self, token_hash: &[u8]) -> Result<Option<Session>> {
+        Ok(self
+            .sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|s| s.token_hash == token_hash && s.expires_at > Utc::now())
+            .cloned())
+    }
+
+    async fn delete(&self, session_id: Uuid) -> Result<()> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .retain(|s| s.session_id != session_id);
+        Ok(())
+    }
+}
+
+/// 委任先のユーザーの削除は考慮しない
+#[derive(Default)]
+pub struct MockGrantRepository {
+    pub grants: Mutex<Vec<Grant>>,
+}
+
+#[async_trait]
+impl GrantRepository for MockGrantRepository {
+    async fn create(&self, grant: &Grant) -> Result<Grant> {
+        let mut grants = self.grants.lock().unwrap();
+        if let Some(stored) = grants.iter_mut().find(|g| {
+            g.owner_id == grant.owner_id
+                && g.grantee_id == grant.grantee_id
+                && g.revoked_at.is_none()
+        }) {
+            stored.scope = grant.scope;
+            stored.created_at = grant.created_at;
+            stored.expires_at = grant.expires_at;
+            return Ok(stored.clone());
+        }
+        grants.push(grant.clone());
+        Ok(grant.clone())
+    }
+
+    async fn find_active(&self, owner_id: Uuid, grantee_id: Uuid) -> Result<Option<Grant>> {
+        Ok(self
+            .grants
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|g| {
+                g.owner_id == owner_id && g.grantee_id == grantee_id && g.is_active(Utc::now())
+            })
+            .cloned())
+    }
+
+    async fn find_active_by_owner(&self, owner_id: Uuid) -> Result<Vec<Grant>> {
+        Ok(self
+            .grants
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|g| g.owner_id == owner_id && g.is_active(Utc::now()))
+            .cloned()
+            .collect())
+    }
+
+    async fn revoke(&self, owner_id: Uuid, grant_id: Uuid) -> Result<bool> {
+        let mut grants = self.grants.lock().unwrap();
+        match grants
+            .iter_mut()
+            .find(|g| g.grant_id == grant_id && g.owner_id == owner_id && g.revoked_at.is_none())
+        {
+            Some(grant) => {
+                grant.revoked_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+/// 共有した配信と、リンクを発行したユーザー・委任は同じリポジトリのモックから取得する
+pub struct MockShareLinkRepository {
+    pub links: Mutex<Vec<ShareLink>>,
+    streams: Arc<MockStreamRepository>,
+    users: Arc<MockUserRepository>,
+    grants: Arc<MockGrantRepository>,
+}
+
+impl MockShareLinkRepository {
+    pub fn new(
+        streams: Arc<MockStreamRepository>,
+        users: Arc<MockUserRepository>,
+        grants: Arc<MockGrantRepository>,
+    ) -> Self {
+        Self {
+            links: Mutex::new(Vec::new()),
+            streams,
+            users,
+            grants,
+        }
+    }
+
+    /// 発行したユーザーが今も共有できるか（配信者本人・モデレーター・管理者・更新を委任されたユーザー）
+    async fn can_share(&self, created_by: Uuid, stream: &Stream) -> Result<bool> {
+        if created_by == stream.user_id {
+            return Ok(true);
+        }
+        let staff = self.users.find_by_id(created_by).await?.is_some_and(|u| {
+            u.deleted_at.is_none() && matches!(u.role, Role::Moderator | Role::Admin)
+        });
+        let grant = self.grants.find_active(stream.user_id, created_by).await?;
+        Ok(staff || grant.is_some_and(|g| g.scope >= GrantScope::Write))
+    }
+}
+
+fn is_active_link(link: &ShareLink) -> bool {
+    link.revoked_at.is_none() && link.expires_at.is_none_or(|e| e > Utc::now())
+}
+
+#[async_trait]
+impl ShareLinkRepository for MockShareLinkRepository {
+    async fn create(&self, link: &ShareLink) -> Result<ShareLink> {
+        self.links.lock().unwrap().push(link.clone());
+        Ok(link.clone())
+    }
+
+    async fn find_active_by_stream(&self, stream_id: Uuid) -> Result<Vec<ShareLink>> {
+        Ok(self
+            .links
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|l| l.stream_id == stream_id && is_active_link(l))
+            .cloned()
+            .collect())
+    }
+
+    async fn revoke(&self, stream_id: Uuid, link_id: Uuid) -> Result<bool> {
+        let mut links = self.links.lock().unwrap();
+        match links
+            .iter_mut()
+            .find(|l| l.link_id == link_id && l.stream_id == stream_id && l.revoked_at.is_none())
+        {
+            Some(link) => {
+                link.revoked_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn access(&self, token_hash: &[u8]) -> Result<Option<Stream>> {
+        let (stream_id, created_by) = {
+            let links = self.links.lock().unwrap();
+            match links
+                .iter()
+                .find(|l| l.token_hash == token_hash && is_active_link(l))
+            {
+                Some(link) => (link.stream_id, link.created_by),
+                None => return Ok(None),
+            }
+        };
+        let stream = match self.streams.find_by_id(stream_id).await? {
+            Some(stream) if self.can_share(created_by, &stream).await? => Some(stream),
+            _ => None,
+        };
+        if stream.is_some() {
+            for link in self.links.lock().unwrap().iter_mut() {
+                if link.token_hash == token_hash {
+                    link.access_count += 1;
+                    link.last_accessed_at = Some(Utc::now());
+                }
+            }
+        }
+        Ok(stream)
+    }
+}
+
+#[derive(Default)]
+pub struct MockIdempotencyKeyRepository {
+    pub keys: Mutex<Vec<IdempotencyKey>>,
+}
+
+#[async_trait]
+impl IdempotencyKeyRepository for MockIdempotencyKeyRepository {
+    async fn reserve(
+        &self,
+        key: &IdempotencyKey,
+        stale_before: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyKey>> {
+        let mut keys = self.keys.lock().unwrap();
+        let position = keys
+            .iter()
+            .position(|k| k.user_id == key.user_id && k.idempotency_key == key.idempotency_key);
+        match position {
+            Some(i)
+                if keys[i].expires_at > Utc::now()
+                    && (keys[i].response_status.is_some()
+                        || keys[i].created_at >= stale_before) =>
+            {
+                Ok(Some(keys[i].clone()))
+            }
+            Some(i) => {
+                keys[i] = key.clone();
+                Ok(None)
+            }
+            None => {
+                keys.push(key.clone());
+                Ok(None)
+            }
+        }
+    }
+
+    async fn complete(
+        &self,
+        user_id: Uuid,
+        idempotency_key: &str,
+        status: i16,
+        headers: &[(String, String)],
+        body: &[u8],
+    ) -> Result<()> {
+        if let Some(k) = self
+            .keys
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|k| k.user_id == user_id && k.idempotency_key == idempotency_key)
+        {
+            k.response_status = Some(status);
+            k.response_headers = Some(sqlx::types::Json(headers.to_vec()));
+            k.response_body = Some(body.to_vec());
+        }
+        Ok(())
+    }
+
+    async fn release(&self, user_id: Uuid, idempotency_key: &str) -> Result<()> {
+        self.keys.lock().unwrap().retain(|k| {
+            k.user_id != user_id
+                || k.idempotency_key != idempotency_key
+                || k.response_status.is_some()
+        });
+        Ok(())
+    }
+}
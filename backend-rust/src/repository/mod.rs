@@ -1,4 +1,18 @@
+pub mod api_key;
 pub mod db;
+pub mod grant;
+pub mod idempotency_key;
+#[cfg(test)]
+pub mod mock;
+pub mod session;
+pub mod share_link;
 pub mod stream;
+pub mod user;
 
+pub use api_key::{ApiKeyRepository, ApiKeyRepositoryImpl};
+pub use grant::{GrantRepository, GrantRepositoryImpl};
+pub use idempotency_key::{IdempotencyKeyRepository, IdempotencyKeyRepositoryImpl};
+pub use session::{SessionRepository, SessionRepositoryImpl};
+pub use share_link::{ShareLinkRepository, ShareLinkRepositoryImpl};
 pub use stream::{StreamRepository, StreamRepositoryImpl};
+pub use user::{UserRepository, UserRepositoryImpl};
@@ -1,20 +1,61 @@
-use crate::model::Stream;
+use crate::model::{
+    PrivateScope, SortOrder, Stream, StreamColumns, StreamCursor, StreamFilter, StreamSort,
+    Visibility, YoutubeVideo,
+};
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
 #[async_trait]
 pub trait StreamRepository: Send + Sync {
     async fn create(&self, stream: &Stream) -> Result<Stream>;
+    /// 1つのトランザクションでまとめて登録する（1件でも失敗した場合は何も登録しない）
+    async fn create_many(&self, streams: &[Stream]) -> Result<Vec<Stream>>;
+    /// `create_many` と同じトランザクションで、取り込んだ配信の元の動画も記録する
+    async fn create_many_with_videos(
+        &self,
+        streams: &[Stream],
+        videos: &[YoutubeVideo],
+    ) -> Result<Vec<Stream>>;
+    /// ユーザーの削除されていない配信の元になった動画
+    async fn find_youtube_videos(&self, user_id: Uuid) -> Result<Vec<YoutubeVideo>>;
     async fn find_by_id(&self, stream_id: Uuid) -> Result<Option<Stream>>;
+    /// 指定したIDの配信を `stream_ids` の順に返す（存在しない・削除済みの配信は含めない）
+    async fn find_by_ids(&self, stream_ids: &[Uuid]) -> Result<Vec<Stream>>;
+    /// `cursor` を指定した場合はその位置より後ろの行を返す（件数 `total` はカーソルの影響を受けない）
+    ///
+    /// `cursor` は `created_at` での並び替え時のみ有効。
+    /// `columns` で取得しない列は空文字として返す。
     async fn find_all(
         &self,
-        category: Option<String>,
+        filter: &StreamFilter,
         limit: Option<i32>,
         offset: Option<i32>,
+        cursor: Option<StreamCursor>,
+        sort: StreamSort,
+        columns: StreamColumns,
     ) -> Result<(Vec<Stream>, i64)>;
-    async fn delete(&self, stream_id: Uuid) -> Result<()>;
+    /// ユーザーの配信を `created_at, stream_id` の昇順に `after` より後ろから `limit` 件返す
+    ///
+    /// 公開状態にかかわらずすべての配信を返す（エクスポートで少しずつ読み込むために使う）。
+    async fn find_by_user_after(
+        &self,
+        user_id: Uuid,
+        include_deleted: bool,
+        after: Option<StreamCursor>,
+        limit: i64,
+    ) -> Result<Vec<Stream>>;
+    /// タイトル・概要欄・カテゴリ・公開状態を更新し、版を1つ進める
+    ///
+    /// `stream.version` が現在の版と一致しない（他の更新が先に行われた）場合は更新せずに `None` を返す。
+    async fn update(&self, stream: &Stream) -> Result<Option<Stream>>;
+    /// 版が `version` の配信を論理削除する（版が一致しない・削除済みの場合は `false`）
+    async fn delete(&self, stream_id: Uuid, version: i64) -> Result<bool>;
+    /// 版が一致する配信をまとめて論理削除し、版が一致した配信のIDを返す
+    ///
+    /// `all_or_nothing` の場合は1件でも版が一致しなければ何も削除しない。
+    async fn delete_many(&self, streams: &[(Uuid, i64)], all_or_nothing: bool) -> Result<Vec<Uuid>>;
 }
 
 pub struct StreamRepositoryImpl {
@@ -27,21 +68,80 @@ impl StreamRepositoryImpl {
     }
 }
 
+/// 一覧と件数で共通の絞り込み条件（論理削除済みの行は常に除外する）
+fn push_filter(builder: &mut QueryBuilder<'_, Postgres>, filter: &StreamFilter) {
+    builder.push(" WHERE TRUE");
+    if !filter.include_deleted {
+        builder.push(" AND deleted_at IS NULL");
+    }
+    if !filter.categories.is_empty() {
+        builder
+            .push(" AND category = ANY(")
+            .push_bind(filter.categories.clone())
+            .push(")");
+    }
+    if !filter.exclude_categories.is_empty() {
+        builder
+            .push(" AND category <> ALL(")
+            .push_bind(filter.exclude_categories.clone())
+            .push(")");
+    }
+    if let Some(user_id) = filter.user_id {
+        builder.push(" AND user_id = ").push_bind(user_id);
+    }
+    if let Some(created_from) = filter.created_from {
+        builder.push(" AND created_at >= ").push_bind(created_from);
+    }
+    if let Some(created_to) = filter.created_to {
+        builder.push(" AND created_at < ").push_bind(created_to);
+    }
+    if filter.visibilities.is_empty() {
+        builder.push(" AND visibility <> 'draft'");
+    } else {
+        builder
+            .push(" AND visibility = ANY(")
+            .push_bind(filter.visibilities.clone())
+            .push(")");
+    }
+    match filter.private_scope {
+        PrivateScope::None => {
+            builder.push(" AND visibility = 'published'");
+        }
+        PrivateScope::OwnedBy(user_id) => {
+            // 閲覧を委任した配信者の公開以外の配信も含める（委任の範囲はいずれも閲覧を含む）
+            builder
+                .push(" AND (visibility = 'published' OR user_id = ")
+                .push_bind(user_id)
+                .push(
+                    " OR user_id IN (SELECT g.owner_id FROM stream_grants g \
+                     JOIN users u ON u.user_id = g.grantee_id \
+                     WHERE g.revoked_at IS NULL AND (g.expires_at IS NULL OR g.expires_at > NOW()) \
+                     AND u.deleted_at IS NULL AND g.grantee_id = ",
+                )
+                .push_bind(user_id)
+                .push("))");
+        }
+        PrivateScope::All => {}
+    }
+}
+
 #[async_trait]
 impl StreamRepository for StreamRepositoryImpl {
     async fn create(&self, stream: &Stream) -> Result<Stream> {
         let created_stream = sqlx::query_as!(
             Stream,
             r#"
-            INSERT INTO streams (stream_id, user_id, title, description, category, created_at, deleted_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING stream_id, user_id, title, description, category, created_at, deleted_at
+            INSERT INTO streams (stream_id, user_id, title, description, category, visibility, version, created_at, deleted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING stream_id, user_id, title, description, category, visibility as "visibility: Visibility", version, created_at, deleted_at
             "#,
             stream.stream_id,
             stream.user_id,
             stream.title,
             stream.description,
             stream.category,
+            stream.visibility as Visibility,
+            stream.version,
             stream.created_at,
             stream.deleted_at,
         )
@@ -51,11 +151,81 @@ impl StreamRepository for StreamRepositoryImpl {
         Ok(created_stream)
     }
 
+    async fn create_many(&self, streams: &[Stream]) -> Result<Vec<Stream>> {
+        self.create_many_with_videos(streams, &[]).await
+    }
+
+    async fn create_many_with_videos(
+        &self,
+        streams: &[Stream],
+        videos: &[YoutubeVideo],
+    ) -> Result<Vec<Stream>> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut created_streams = Vec::with_capacity(streams.len());
+        for stream in streams {
+            let created_stream = sqlx::query_as!(
+                Stream,
+                r#"
+                INSERT INTO streams (stream_id, user_id, title, description, category, visibility, version, created_at, deleted_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                RETURNING stream_id, user_id, title, description, category, visibility as "visibility: Visibility", version, created_at, deleted_at
+                "#,
+                stream.stream_id,
+                stream.user_id,
+                stream.title,
+                stream.description,
+                stream.category,
+                stream.visibility as Visibility,
+                stream.version,
+                stream.created_at,
+                stream.deleted_at,
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            created_streams.push(created_stream);
+        }
+
+        for video in videos {
+            sqlx::query!(
+                r#"
+                INSERT INTO stream_youtube_videos (stream_id, user_id, video_id)
+                SELECT stream_id, user_id, $2 FROM streams WHERE stream_id = $1
+                "#,
+                video.stream_id,
+                video.video_id,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(created_streams)
+    }
+
+    async fn find_youtube_videos(&self, user_id: Uuid) -> Result<Vec<YoutubeVideo>> {
+        let videos = sqlx::query_as!(
+            YoutubeVideo,
+            r#"
+            SELECT v.stream_id, v.video_id
+            FROM stream_youtube_videos v
+            JOIN streams s ON s.stream_id = v.stream_id
+            WHERE v.user_id = $1 AND s.deleted_at IS NULL
+            "#,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(videos)
+    }
+
     async fn find_by_id(&self, stream_id: Uuid) -> Result<Option<Stream>> {
         let stream = sqlx::query_as!(
             Stream,
             r#"
-            SELECT stream_id, user_id, title, description, category, created_at, deleted_at
+            SELECT stream_id, user_id, title, description, category, visibility as "visibility: Visibility", version, created_at, deleted_at
             FROM streams
             WHERE stream_id = $1 AND deleted_at IS NULL
             "#,
@@ -67,69 +237,203 @@ impl StreamRepository for StreamRepositoryImpl {
         Ok(stream)
     }
 
+    async fn find_by_ids(&self, stream_ids: &[Uuid]) -> Result<Vec<Stream>> {
+        let streams = sqlx::query_as!(
+            Stream,
+            r#"
+            SELECT stream_id, user_id, title, description, category, visibility as "visibility: Visibility", version, created_at, deleted_at
+            FROM streams
+            WHERE stream_id = ANY($1) AND deleted_at IS NULL
+            ORDER BY array_position($1, stream_id)
+            "#,
+            stream_ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(streams)
+    }
+
     async fn find_all(
         &self,
-        category: Option<String>,
+        filter: &StreamFilter,
         limit: Option<i32>,
         offset: Option<i32>,
+        cursor: Option<StreamCursor>,
+        sort: StreamSort,
+        columns: StreamColumns,
     ) -> Result<(Vec<Stream>, i64)> {
         let limit = limit.unwrap_or(10) as i64;
         let offset = offset.unwrap_or(0) as i64;
+        let order = sort.order.as_sql();
+
+        // 取得しない列は同じ名前の空文字に置き換え、Stream にそのまま読み込めるようにする
+        let column = |name: &str, selected: bool| {
+            if selected {
+                name.to_string()
+            } else {
+                format!("''::TEXT AS {}", name)
+            }
+        };
+        // ORDER BY の列はバインドできないため、列名・方向は StreamSort の固定値だけを埋め込む
+        let mut builder = QueryBuilder::<Postgres>::new(format!(
+            "SELECT stream_id, user_id, {}, {}, {}, visibility, version, created_at, deleted_at FROM streams",
+            column("title", columns.title),
+            column("description", columns.description),
+            column("category", columns.category),
+        ));
+        push_filter(&mut builder, filter);
+        if let Some(cursor) = cursor {
+            let comparison = match sort.order {
+                SortOrder::Asc => ">",
+                SortOrder::Desc => "<",
+            };
+            builder
+                .push(format!(" AND (created_at, stream_id) {} (", comparison))
+                .push_bind(cursor.created_at)
+                .push(", ")
+                .push_bind(cursor.stream_id)
+                .push(")");
+        }
+        // 置き換えた列の別名ではなくテーブルの列で並び替える
+        builder.push(format!(
+            " ORDER BY streams.{} {}, stream_id {}",
+            sort.key.column(),
+            order,
+            order
+        ));
+        builder
+            .push(" LIMIT ")
+            .push_bind(limit)
+            .push(" OFFSET ")
+            .push_bind(offset);
+
+        let streams = builder
+            .build_query_as::<Stream>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        // 件数はカーソルの影響を受けないよう、絞り込み条件だけで数える
+        let mut count_builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM streams");
+        push_filter(&mut count_builder, filter);
+        let total = count_builder
+            .build_query_scalar::<i64>()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok((streams, total))
+    }
 
+    async fn find_by_user_after(
+        &self,
+        user_id: Uuid,
+        include_deleted: bool,
+        after: Option<StreamCursor>,
+        limit: i64,
+    ) -> Result<Vec<Stream>> {
+        let (after_created_at, after_stream_id) = after
+            .map(|cursor| (cursor.created_at, cursor.stream_id))
+            .unzip();
         let streams = sqlx::query_as!(
             Stream,
             r#"
-            SELECT stream_id, user_id, title, description, category, created_at, deleted_at
+            SELECT stream_id, user_id, title, description, category, visibility as "visibility: Visibility", version, created_at, deleted_at
             FROM streams
-            WHERE deleted_at IS NULL
-            AND ($1::text IS NULL OR category = $1)
-            ORDER BY created_at DESC
-            LIMIT $2 OFFSET $3
+            WHERE user_id = $1
+              AND ($2 OR deleted_at IS NULL)
+              AND ($3::TIMESTAMPTZ IS NULL OR (created_at, stream_id) > ($3, $4))
+            ORDER BY created_at, stream_id
+            LIMIT $5
             "#,
-            category,
-            limit,
-            offset
+            user_id,
+            include_deleted,
+            after_created_at,
+            after_stream_id,
+            limit
         )
         .fetch_all(&self.pool)
         .await?;
 
-        let total = sqlx::query!(
+        Ok(streams)
+    }
+
+    async fn update(&self, stream: &Stream) -> Result<Option<Stream>> {
+        let updated_stream = sqlx::query_as!(
+            Stream,
             r#"
-            SELECT COUNT(*) as count
-            FROM streams
-            WHERE deleted_at IS NULL
-            AND ($1::text IS NULL OR category = $1)
+            UPDATE streams
+            SET title = $2, description = $3, category = $4, visibility = $5, version = version + 1
+            WHERE stream_id = $1 AND deleted_at IS NULL AND version = $6
+            RETURNING stream_id, user_id, title, description, category, visibility as "visibility: Visibility", version, created_at, deleted_at
             "#,
-            category
+            stream.stream_id,
+            stream.title,
+            stream.description,
+            stream.category,
+            stream.visibility as Visibility,
+            stream.version,
         )
-        .fetch_one(&self.pool)
-        .await?
-        .count
-        .unwrap_or(0);
+        .fetch_optional(&self.pool)
+        .await?;
 
-        Ok((streams, total))
+        Ok(updated_stream)
     }
 
-    async fn delete(&self, stream_id: Uuid) -> Result<()> {
-        sqlx::query!(
+    async fn delete(&self, stream_id: Uuid, version: i64) -> Result<bool> {
+        let result = sqlx::query!(
             r#"
             UPDATE streams
             SET deleted_at = NOW()
-            WHERE stream_id = $1
+            WHERE stream_id = $1 AND version = $2 AND deleted_at IS NULL
             "#,
-            stream_id
+            stream_id,
+            version
         )
         .execute(&self.pool)
         .await?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_many(
+        &self,
+        streams: &[(Uuid, i64)],
+        all_or_nothing: bool,
+    ) -> Result<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+
+        let mut deleted = Vec::with_capacity(streams.len());
+        for &(stream_id, version) in streams {
+            let result = sqlx::query!(
+                r#"
+                UPDATE streams
+                SET deleted_at = NOW()
+                WHERE stream_id = $1 AND version = $2 AND deleted_at IS NULL
+                "#,
+                stream_id,
+                version
+            )
+            .execute(&mut *tx)
+            .await?;
+            if result.rows_affected() > 0 {
+                deleted.push(stream_id);
+            }
+        }
+
+        // 版が一致しない配信があればロールバックする（トランザクションを破棄する）
+        if !all_or_nothing || deleted.len() == streams.len() {
+            tx.commit().await?;
+        }
+        Ok(deleted)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
+    use crate::model::{StreamSortKey, User};
+    use crate::repository::{UserRepository, UserRepositoryImpl};
+    use chrono::{DateTime, Utc};
 
     async fn setup_test_pool() -> PgPool {
         let database_url = std::env::var("DATABASE_URL")
@@ -144,21 +448,31 @@ mod tests {
             .expect("Failed to connect to test database")
     }
 
+    async fn create_test_user(pool: &PgPool) -> Uuid {
+        let user = User::new(Uuid::new_v4());
+        UserRepositoryImpl::new(pool.clone())
+            .create(&user)
+            .await
+            .expect("Failed to create test user")
+            .user_id
+    }
+
     #[tokio::test]
     async fn test_create_and_find_by_id() {
         let pool = setup_test_pool().await;
-        let repo = StreamRepositoryImpl::new(pool);
+        let repo = StreamRepositoryImpl::new(pool.clone());
 
-        // 既存のユーザーIDを使用（テストデータで作成済み）
-        let existing_user_id = Uuid::parse_str("11111111-1111-1111-1111-111111111111")
-            .expect("Invalid UUID");
+        // テスト用のユーザーを作成
+        let test_user_id = create_test_user(&pool).await;
 
         let stream = Stream {
             stream_id: Uuid::new_v4(),
-            user_id: existing_user_id,
+            user_id: test_user_id,
             title: "Test Stream".to_string(),
             description: "Test Description".to_string(),
             category: "".to_string(),
+            visibility: Visibility::Published,
+            version: 1,
             created_at: Utc::now(),
             deleted_at: None,
         };
@@ -178,34 +492,100 @@ mod tests {
         assert_eq!(found.title, stream.title);
 
         // Cleanup
-        repo.delete(stream.stream_id).await.expect("Failed to delete stream");
+        repo.delete(stream.stream_id, stream.version).await.expect("Failed to delete stream");
+    }
+
+    #[tokio::test]
+    async fn test_update_checks_version() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+        let test_user_id = create_test_user(&pool).await;
+
+        let stream = repo
+            .create(&Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: test_user_id,
+                title: "Before".to_string(),
+                description: "".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Published,
+                version: 1,
+                created_at: Utc::now(),
+                deleted_at: None,
+            })
+            .await
+            .expect("Failed to create stream");
+
+        let updated = repo
+            .update(&Stream {
+                title: "After".to_string(),
+                ..stream.clone()
+            })
+            .await
+            .expect("Failed to update stream")
+            .expect("Stream not updated");
+        assert_eq!(updated.title, "After");
+        assert_eq!(updated.version, 2);
+
+        // 古い版での更新は反映しない
+        let result = repo
+            .update(&Stream {
+                title: "Stale".to_string(),
+                ..stream.clone()
+            })
+            .await
+            .expect("Failed to update stream");
+        assert!(result.is_none());
+        let found = repo
+            .find_by_id(stream.stream_id)
+            .await
+            .expect("Failed to find stream")
+            .expect("Stream not found");
+        assert_eq!(found.title, "After");
+
+        // 古い版での削除も反映しない
+        assert!(!repo
+            .delete(stream.stream_id, stream.version)
+            .await
+            .expect("Failed to delete stream"));
+        assert!(repo
+            .delete(stream.stream_id, updated.version)
+            .await
+            .expect("Failed to delete stream"));
+        assert!(!repo
+            .delete(stream.stream_id, updated.version)
+            .await
+            .expect("Failed to delete stream"));
     }
 
     #[tokio::test]
     async fn test_find_all() {
         let pool = setup_test_pool().await;
-        let repo = StreamRepositoryImpl::new(pool);
+        let repo = StreamRepositoryImpl::new(pool.clone());
 
-        // 既存のユーザーIDを使用
-        let existing_user_id = Uuid::parse_str("11111111-1111-1111-1111-111111111111")
-            .expect("Invalid UUID");
+        // テスト用のユーザーを作成
+        let test_user_id = create_test_user(&pool).await;
 
         let stream1 = Stream {
             stream_id: Uuid::new_v4(),
-            user_id: existing_user_id,
+            user_id: test_user_id,
             title: "Test Stream 1".to_string(),
             description: "Description 1".to_string(),
             category: "".to_string(),
+            visibility: Visibility::Published,
+            version: 1,
             created_at: Utc::now(),
             deleted_at: None,
         };
 
         let stream2 = Stream {
             stream_id: Uuid::new_v4(),
-            user_id: existing_user_id,
+            user_id: test_user_id,
             title: "Test Stream 2".to_string(),
             description: "Description 2".to_string(),
             category: "".to_string(),
+            visibility: Visibility::Published,
+            version: 1,
             created_at: Utc::now(),
             deleted_at: None,
         };
@@ -215,29 +595,495 @@ mod tests {
         repo.create(&stream2).await.expect("Failed to create stream2");
 
         // Find all
-        let (streams, _) = repo.find_all(None, None, None).await.expect("Failed to find all streams");
+        let (streams, _) = repo.find_all(&StreamFilter::default(), None, None, None, StreamSort::default(), StreamColumns::ALL).await.expect("Failed to find all streams");
         assert!(streams.len() >= 2);
 
         // Cleanup
-        repo.delete(stream1.stream_id).await.expect("Failed to delete stream1");
-        repo.delete(stream2.stream_id).await.expect("Failed to delete stream2");
+        repo.delete(stream1.stream_id, stream1.version).await.expect("Failed to delete stream1");
+        repo.delete(stream2.stream_id, stream2.version).await.expect("Failed to delete stream2");
+    }
+
+    #[tokio::test]
+    async fn test_find_all_with_cursor() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+
+        // テスト用のユーザーを作成
+        let test_user_id = create_test_user(&pool).await;
+
+        // 他のテストのデータと混ざらないようにテスト専用のカテゴリを使う
+        let category = format!("cursor-test-{}", Uuid::new_v4());
+        let now = Utc::now();
+        let streams: Vec<Stream> = (0..3)
+            .map(|i| Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: test_user_id,
+                title: format!("Cursor Stream {}", i),
+                description: "Cursor paging".to_string(),
+                category: category.clone(),
+                visibility: Visibility::Published,
+                version: 1,
+                created_at: now - chrono::Duration::seconds(i),
+                deleted_at: None,
+            })
+            .collect();
+        for stream in &streams {
+            repo.create(stream).await.expect("Failed to create stream");
+        }
+        let filter = StreamFilter {
+            categories: vec![category.clone()],
+            ..Default::default()
+        };
+
+        // 1ページ目: 新しい順に2件
+        let (first_page, total) = repo
+            .find_all(
+                &filter,
+                Some(2),
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find first page");
+        assert_eq!(total, 3);
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(first_page[0].stream_id, streams[0].stream_id);
+        assert_eq!(first_page[1].stream_id, streams[1].stream_id);
+
+        // 2ページ目: 1ページ目の最後の要素の続きから
+        let last = first_page.last().unwrap();
+        let cursor = StreamCursor {
+            created_at: last.created_at,
+            stream_id: last.stream_id,
+        };
+        let (second_page, total) = repo
+            .find_all(
+                &filter,
+                Some(2),
+                None,
+                Some(cursor),
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find second page");
+        assert_eq!(total, 3);
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].stream_id, streams[2].stream_id);
+
+        // Cleanup
+        for stream in &streams {
+            repo.delete(stream.stream_id, stream.version).await.expect("Failed to delete stream");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_all_sorted_by_title() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+
+        // テスト用のユーザーを作成
+        let test_user_id = create_test_user(&pool).await;
+
+        let category = format!("sort-test-{}", Uuid::new_v4());
+        let now = Utc::now();
+        let streams: Vec<Stream> = ["b", "c", "a"]
+            .iter()
+            .enumerate()
+            .map(|(i, title)| Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: test_user_id,
+                title: title.to_string(),
+                description: "Sorting".to_string(),
+                category: category.clone(),
+                visibility: Visibility::Published,
+                version: 1,
+                created_at: now - chrono::Duration::seconds(i as i64),
+                deleted_at: None,
+            })
+            .collect();
+        for stream in &streams {
+            repo.create(stream).await.expect("Failed to create stream");
+        }
+        let filter = StreamFilter {
+            categories: vec![category.clone()],
+            ..Default::default()
+        };
+
+        let sort = StreamSort {
+            key: StreamSortKey::Title,
+            order: SortOrder::Asc,
+        };
+        let (found, _) = repo
+            .find_all(&filter, None, None, None, sort, StreamColumns::ALL)
+            .await
+            .expect("Failed to find streams");
+        let titles: Vec<&str> = found.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["a", "b", "c"]);
+
+        // 取得しない列は空文字になり、並び替えには置き換える前の値を使う
+        let columns = StreamColumns {
+            title: false,
+            description: false,
+            category: true,
+        };
+        let (found, _) = repo
+            .find_all(&filter, None, None, None, sort, columns)
+            .await
+            .expect("Failed to find streams");
+        let ids: Vec<Uuid> = found.iter().map(|s| s.stream_id).collect();
+        assert_eq!(
+            ids,
+            vec![
+                streams[2].stream_id,
+                streams[0].stream_id,
+                streams[1].stream_id
+            ]
+        );
+        assert!(found
+            .iter()
+            .all(|s| s.title.is_empty() && s.description.is_empty() && s.category == category));
+
+        // Cleanup
+        for stream in &streams {
+            repo.delete(stream.stream_id, stream.version).await.expect("Failed to delete stream");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_all_with_filter() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+
+        // テスト用のユーザーを作成
+        let test_user_id = create_test_user(&pool).await;
+
+        let game = format!("filter-game-{}", Uuid::new_v4());
+        let chat = format!("filter-chat-{}", Uuid::new_v4());
+        let cooking = format!("filter-cooking-{}", Uuid::new_v4());
+        let march = DateTime::parse_from_rfc3339("2025-03-15T20:00:00+09:00")
+            .unwrap()
+            .with_timezone(&Utc);
+        let april = DateTime::parse_from_rfc3339("2025-04-02T20:00:00+09:00")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let streams: Vec<Stream> = [
+            (&game, march),
+            (&chat, march),
+            (&cooking, march),
+            (&game, april),
+        ]
+        .into_iter()
+        .map(|(category, created_at)| Stream {
+            stream_id: Uuid::new_v4(),
+            user_id: test_user_id,
+            title: "Filter Stream".to_string(),
+            description: "Filtering".to_string(),
+            category: category.clone(),
+            visibility: Visibility::Published,
+            version: 1,
+            created_at,
+            deleted_at: None,
+        })
+        .collect();
+        for stream in &streams {
+            repo.create(stream).await.expect("Failed to create stream");
+        }
+
+        // ゲームと雑談の3月分
+        let filter = StreamFilter {
+            categories: vec![game.clone(), chat.clone()],
+            user_id: Some(test_user_id),
+            created_from: Some(
+                DateTime::parse_from_rfc3339("2025-03-01T00:00:00+09:00")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            ),
+            created_to: Some(
+                DateTime::parse_from_rfc3339("2025-04-01T00:00:00+09:00")
+                    .unwrap()
+                    .with_timezone(&Utc),
+            ),
+            ..Default::default()
+        };
+        let (found, total) = repo
+            .find_all(
+                &filter,
+                None,
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find streams");
+        assert_eq!(total, 2);
+        let ids: Vec<Uuid> = found.iter().map(|s| s.stream_id).collect();
+        assert!(ids.contains(&streams[0].stream_id));
+        assert!(ids.contains(&streams[1].stream_id));
+
+        // 除外カテゴリ
+        let filter = StreamFilter {
+            categories: vec![game.clone(), chat.clone(), cooking.clone()],
+            exclude_categories: vec![game.clone()],
+            ..Default::default()
+        };
+        let (found, total) = repo
+            .find_all(
+                &filter,
+                None,
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find streams");
+        assert_eq!(total, 2);
+        assert!(found.iter().all(|s| s.category != game));
+
+        // 論理削除した行は件数にも含まれない
+        repo.delete(streams[1].stream_id, streams[1].version).await.expect("Failed to delete stream");
+        let filter = StreamFilter {
+            categories: vec![chat.clone()],
+            ..Default::default()
+        };
+        let (found, total) = repo
+            .find_all(
+                &filter,
+                None,
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find streams");
+        assert_eq!(total, 0);
+        assert!(found.is_empty());
+
+        // Cleanup
+        for stream in &streams {
+            repo.delete(stream.stream_id, stream.version).await.expect("Failed to delete stream");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_all_includes_granted_private_streams() {
+        use crate::model::{Grant, GrantScope};
+        use crate::repository::{GrantRepository, GrantRepositoryImpl};
+
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+        let grant_repo = GrantRepositoryImpl::new(pool.clone());
+        let owner_id = create_test_user(&pool).await;
+        let grantee_id = create_test_user(&pool).await;
+
+        let stream = Stream {
+            stream_id: Uuid::new_v4(),
+            user_id: owner_id,
+            title: "Private Stream".to_string(),
+            description: "Granted".to_string(),
+            category: "".to_string(),
+            visibility: Visibility::Private,
+            version: 1,
+            created_at: Utc::now(),
+            deleted_at: None,
+        };
+        repo.create(&stream).await.expect("Failed to create stream");
+
+        let filter = StreamFilter {
+            user_id: Some(owner_id),
+            private_scope: PrivateScope::OwnedBy(grantee_id),
+            ..Default::default()
+        };
+        let (_, total) = repo
+            .find_all(
+                &filter,
+                None,
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find streams");
+        assert_eq!(total, 0);
+
+        let grant = grant_repo
+            .create(&Grant {
+                grant_id: Uuid::new_v4(),
+                owner_id,
+                grantee_id,
+                scope: GrantScope::Read,
+                created_at: Utc::now(),
+                expires_at: None,
+                revoked_at: None,
+            })
+            .await
+            .expect("Failed to create grant");
+        let (found, total) = repo
+            .find_all(
+                &filter,
+                None,
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find streams");
+        assert_eq!(total, 1);
+        assert_eq!(found[0].stream_id, stream.stream_id);
+
+        // 取り消した後は含めない
+        grant_repo
+            .revoke(owner_id, grant.grant_id)
+            .await
+            .expect("Failed to revoke grant");
+        let (_, total) = repo
+            .find_all(
+                &filter,
+                None,
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find streams");
+        assert_eq!(total, 0);
+
+        // 委任されたユーザーが削除された場合も含めない
+        let deleted_grantee = create_test_user(&pool).await;
+        grant_repo
+            .create(&Grant {
+                grant_id: Uuid::new_v4(),
+                grantee_id: deleted_grantee,
+                ..grant
+            })
+            .await
+            .expect("Failed to create grant");
+        UserRepositoryImpl::new(pool.clone())
+            .delete(deleted_grantee)
+            .await
+            .expect("Failed to delete user");
+        let filter = StreamFilter {
+            user_id: Some(owner_id),
+            private_scope: PrivateScope::OwnedBy(deleted_grantee),
+            ..Default::default()
+        };
+        let (_, total) = repo
+            .find_all(
+                &filter,
+                None,
+                None,
+                None,
+                StreamSort::default(),
+                StreamColumns::ALL,
+            )
+            .await
+            .expect("Failed to find streams");
+        assert_eq!(total, 0);
+
+        // Cleanup
+        repo.delete(stream.stream_id, stream.version).await.expect("Failed to delete stream");
+    }
+
+    #[tokio::test]
+    async fn test_find_all_filters_by_visibility() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+        let owner_id = create_test_user(&pool).await;
+
+        let mut streams = Vec::new();
+        for visibility in Visibility::ALL {
+            let stream = Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: owner_id,
+                title: "".to_string(),
+                description: visibility.as_str().to_string(),
+                category: "".to_string(),
+                visibility,
+                version: 1,
+                created_at: Utc::now(),
+                deleted_at: None,
+            };
+            streams.push(repo.create(&stream).await.expect("Failed to create stream"));
+        }
+        let find = |visibilities: Vec<Visibility>, private_scope| {
+            let filter = StreamFilter {
+                user_id: Some(owner_id),
+                visibilities,
+                private_scope,
+                ..Default::default()
+            };
+            let repo = &repo;
+            async move {
+                let (found, _) = repo
+                    .find_all(
+                        &filter,
+                        None,
+                        None,
+                        None,
+                        StreamSort::default(),
+                        StreamColumns::ALL,
+                    )
+                    .await
+                    .expect("Failed to find streams");
+                let mut found: Vec<_> = found.into_iter().map(|s| s.visibility).collect();
+                found.sort_by_key(|v| v.as_str());
+                found
+            }
+        };
+
+        // 他のユーザーには公開の配信のみ、本人には下書き以外を返す
+        assert_eq!(
+            find(vec![], PrivateScope::OwnedBy(Uuid::new_v4())).await,
+            vec![Visibility::Published]
+        );
+        assert_eq!(
+            find(vec![], PrivateScope::OwnedBy(owner_id)).await,
+            vec![
+                Visibility::Private,
+                Visibility::Published,
+                Visibility::Unlisted
+            ]
+        );
+        // 下書きは明示した場合のみ
+        assert_eq!(
+            find(vec![Visibility::Draft], PrivateScope::OwnedBy(owner_id)).await,
+            vec![Visibility::Draft]
+        );
+        assert!(find(vec![Visibility::Draft], PrivateScope::None)
+            .await
+            .is_empty());
+
+        // Cleanup
+        for stream in &streams {
+            repo.delete(stream.stream_id, stream.version).await.expect("Failed to delete stream");
+        }
     }
 
     #[tokio::test]
     async fn test_delete() {
         let pool = setup_test_pool().await;
-        let repo = StreamRepositoryImpl::new(pool);
+        let repo = StreamRepositoryImpl::new(pool.clone());
 
-        // 既存のユーザーIDを使用
-        let existing_user_id = Uuid::parse_str("11111111-1111-1111-1111-111111111111")
-            .expect("Invalid UUID");
+        // テスト用のユーザーを作成
+        let test_user_id = create_test_user(&pool).await;
 
         let stream = Stream {
             stream_id: Uuid::new_v4(),
-            user_id: existing_user_id,
+            user_id: test_user_id,
             title: "Test Stream to Delete".to_string(),
             description: "Will be deleted".to_string(),
             category: "".to_string(),
+            visibility: Visibility::Published,
+            version: 1,
             created_at: Utc::now(),
             deleted_at: None,
         };
@@ -246,7 +1092,7 @@ mod tests {
         repo.create(&stream).await.expect("Failed to create stream");
 
         // Delete
-        repo.delete(stream.stream_id).await.expect("Failed to delete stream");
+        repo.delete(stream.stream_id, stream.version).await.expect("Failed to delete stream");
 
         // Verify deletion
         let found = repo
@@ -256,6 +1102,196 @@ mod tests {
         assert!(found.is_none());
     }
 
+    #[tokio::test]
+    async fn test_create_many_and_delete_many() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+        let test_user_id = create_test_user(&pool).await;
+
+        let new_stream = |title: &str| Stream {
+            stream_id: Uuid::new_v4(),
+            user_id: test_user_id,
+            title: title.to_string(),
+            description: "".to_string(),
+            category: "".to_string(),
+            visibility: Visibility::Published,
+            version: 1,
+            created_at: Utc::now(),
+            deleted_at: None,
+        };
+        let streams = vec![new_stream("first"), new_stream("second")];
+        let created = repo
+            .create_many(&streams)
+            .await
+            .expect("Failed to create streams");
+        assert_eq!(created.len(), 2);
+        assert_eq!(created[1].title, "second");
+
+        // 1件でも失敗した場合は何も登録しない
+        let duplicate = vec![new_stream("third"), streams[0].clone()];
+        assert!(repo.create_many(&duplicate).await.is_err());
+        assert!(repo
+            .find_by_id(duplicate[0].stream_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        // 版が一致しない配信があれば何も削除しない
+        let stale = vec![(created[0].stream_id, 1), (created[1].stream_id, 2)];
+        let deleted = repo
+            .delete_many(&stale, true)
+            .await
+            .expect("Failed to delete streams");
+        assert_eq!(deleted, vec![created[0].stream_id]);
+        assert!(repo
+            .find_by_id(created[0].stream_id)
+            .await
+            .unwrap()
+            .is_some());
+
+        let targets: Vec<(Uuid, i64)> = created.iter().map(|s| (s.stream_id, s.version)).collect();
+        let deleted = repo
+            .delete_many(&targets, true)
+            .await
+            .expect("Failed to delete streams");
+        assert_eq!(deleted.len(), 2);
+        for (id, _) in targets {
+            assert!(repo.find_by_id(id).await.unwrap().is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_many_with_videos() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+        let test_user_id = create_test_user(&pool).await;
+
+        let streams: Vec<Stream> = ["first", "second"]
+            .into_iter()
+            .map(|title| Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: test_user_id,
+                title: title.to_string(),
+                description: "".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Published,
+                version: 1,
+                created_at: Utc::now(),
+                deleted_at: None,
+            })
+            .collect();
+        let videos = vec![YoutubeVideo {
+            stream_id: streams[0].stream_id,
+            video_id: "dQw4w9WgXcQ".to_string(),
+        }];
+        repo.create_many_with_videos(&streams, &videos)
+            .await
+            .expect("Failed to create streams");
+
+        let found = repo
+            .find_youtube_videos(test_user_id)
+            .await
+            .expect("Failed to query");
+        assert_eq!(found, videos);
+
+        // 削除した配信の動画は含めない
+        repo.delete(streams[0].stream_id, streams[0].version)
+            .await
+            .expect("Failed to delete stream");
+        let found = repo
+            .find_youtube_videos(test_user_id)
+            .await
+            .expect("Failed to query");
+        assert!(found.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_ids_keeps_request_order() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+        let test_user_id = create_test_user(&pool).await;
+
+        let mut ids = Vec::new();
+        for title in ["first", "second", "deleted"] {
+            let stream = Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: test_user_id,
+                title: title.to_string(),
+                description: "".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Published,
+                version: 1,
+                created_at: Utc::now(),
+                deleted_at: None,
+            };
+            repo.create(&stream).await.expect("Failed to create stream");
+            ids.push(stream.stream_id);
+        }
+        repo.delete(ids[2], 1).await.expect("Failed to delete stream");
+
+        let requested = vec![ids[1], Uuid::new_v4(), ids[2], ids[0]];
+        let found = repo
+            .find_by_ids(&requested)
+            .await
+            .expect("Failed to query");
+        let titles: Vec<&str> = found.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["second", "first"]);
+    }
+
+    #[tokio::test]
+    async fn test_find_by_user_after() {
+        let pool = setup_test_pool().await;
+        let repo = StreamRepositoryImpl::new(pool.clone());
+        let test_user_id = create_test_user(&pool).await;
+
+        let base = Utc::now();
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let stream = Stream {
+                stream_id: Uuid::new_v4(),
+                user_id: test_user_id,
+                title: format!("title {}", i),
+                description: "".to_string(),
+                category: "".to_string(),
+                visibility: Visibility::Draft,
+                version: 1,
+                created_at: base + chrono::Duration::seconds(i),
+                deleted_at: None,
+            };
+            repo.create(&stream).await.expect("Failed to create stream");
+            ids.push(stream.stream_id);
+        }
+        repo.delete(ids[1], 1).await.expect("Failed to delete stream");
+
+        // 下書きも含めて古い順に返し、削除済みの配信は指定した場合のみ含める
+        let found = repo
+            .find_by_user_after(test_user_id, false, None, 10)
+            .await
+            .expect("Failed to query");
+        let found_ids: Vec<Uuid> = found.iter().map(|s| s.stream_id).collect();
+        assert_eq!(found_ids, vec![ids[0], ids[2]]);
+
+        let first = repo
+            .find_by_user_after(test_user_id, true, None, 2)
+            .await
+            .expect("Failed to query");
+        let found_ids: Vec<Uuid> = first.iter().map(|s| s.stream_id).collect();
+        assert_eq!(found_ids, vec![ids[0], ids[1]]);
+        assert!(first[1].deleted_at.is_some());
+
+        let last = first.last().unwrap();
+        let cursor = StreamCursor {
+            created_at: last.created_at,
+            stream_id: last.stream_id,
+        };
+        let rest = repo
+            .find_by_user_after(test_user_id, true, Some(cursor), 2)
+            .await
+            .expect("Failed to query");
+        let found_ids: Vec<Uuid> = rest.iter().map(|s| s.stream_id).collect();
+        assert_eq!(found_ids, vec![ids[2]]);
+    }
+
     #[tokio::test]
     async fn test_find_by_id_not_found() {
         let pool = setup_test_pool().await;
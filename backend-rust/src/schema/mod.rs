@@ -1,4 +1,15 @@
 pub use openapi_types::models::{
-    CreateStreamRequest, Stream as StreamResponse, StreamListResponse,
-    StreamSummary as StreamSummaryResponse, ValidationError, ServerError,
+    grant::Scope as GrantScopeResponse, grant_request::Scope as GrantScopeRequest,
+    update_user_role_request::Role as RoleRequest, user::Role as RoleResponse,
+};
+pub use openapi_types::models::{
+    ApiKey as ApiKeyResponse, ApiKeyListResponse, BatchCreateStreamsRequest, BatchDeleteItem,
+    BatchDeleteStreamsRequest, BatchGetStreamsRequest, BatchGetStreamsResponse, BatchMode,
+    BatchStreamResult, BatchStreamsResponse, CreateShareLinkRequest, CreateStreamRequest,
+    ExportedStream, Grant as GrantResponse, GrantListResponse, GrantRequest, ImportStreamResult,
+    ImportStreamRow, ImportStreamsResponse, IssueApiKeyRequest, PaginationLinks,
+    ServerError as ServerErrorResponse, Session as SessionResponse, ShareLink as ShareLinkResponse,
+    ShareLinkListResponse, Stream as StreamResponse, StreamListResponse,
+    StreamSummary as StreamSummaryResponse, UpdateStreamRequest, UpdateUserRoleRequest,
+    User as UserResponse, UserListResponse, UserProfileRequest, Visibility as VisibilitySchema,
 };
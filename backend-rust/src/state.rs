@@ -0,0 +1,93 @@
+use crate::auth::jwt::JwtVerifier;
+use crate::config::{IdempotencyConfig, PreconditionConfig, SessionConfig};
+use crate::repository::{
+    ApiKeyRepository, GrantRepository, IdempotencyKeyRepository, SessionRepository,
+    ShareLinkRepository, StreamRepository, UserRepository,
+};
+use axum::extract::FromRef;
+use std::sync::Arc;
+
+/// ルーター全体で共有する状態
+///
+/// ハンドラーは `State<Arc<dyn StreamRepository>>` のように必要なリポジトリだけを取り出す。
+#[derive(Clone)]
+pub struct AppState {
+    pub stream_repo: Arc<dyn StreamRepository>,
+    pub user_repo: Arc<dyn UserRepository>,
+    pub api_key_repo: Arc<dyn ApiKeyRepository>,
+    pub session_repo: Arc<dyn SessionRepository>,
+    pub grant_repo: Arc<dyn GrantRepository>,
+    pub share_link_repo: Arc<dyn ShareLinkRepository>,
+    pub idempotency_key_repo: Arc<dyn IdempotencyKeyRepository>,
+    pub session_config: SessionConfig,
+    pub idempotency_config: IdempotencyConfig,
+    pub precondition_config: PreconditionConfig,
+    /// JWT認証を設定していない場合は `None`
+    pub jwt: Option<Arc<JwtVerifier>>,
+}
+
+impl FromRef<AppState> for Arc<dyn StreamRepository> {
+    fn from_ref(state: &AppState) -> Self {
+        state.stream_repo.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn UserRepository> {
+    fn from_ref(state: &AppState) -> Self {
+        state.user_repo.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn ApiKeyRepository> {
+    fn from_ref(state: &AppState) -> Self {
+        state.api_key_repo.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn SessionRepository> {
+    fn from_ref(state: &AppState) -> Self {
+        state.session_repo.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn GrantRepository> {
+    fn from_ref(state: &AppState) -> Self {
+        state.grant_repo.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn ShareLinkRepository> {
+    fn from_ref(state: &AppState) -> Self {
+        state.share_link_repo.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn IdempotencyKeyRepository> {
+    fn from_ref(state: &AppState) -> Self {
+        state.idempotency_key_repo.clone()
+    }
+}
+
+impl FromRef<AppState> for SessionConfig {
+    fn from_ref(state: &AppState) -> Self {
+        state.session_config.clone()
+    }
+}
+
+impl FromRef<AppState> for IdempotencyConfig {
+    fn from_ref(state: &AppState) -> Self {
+        state.idempotency_config.clone()
+    }
+}
+
+impl FromRef<AppState> for PreconditionConfig {
+    fn from_ref(state: &AppState) -> Self {
+        state.precondition_config.clone()
+    }
+}
+
+impl FromRef<AppState> for Option<Arc<JwtVerifier>> {
+    fn from_ref(state: &AppState) -> Self {
+        state.jwt.clone()
+    }
+}
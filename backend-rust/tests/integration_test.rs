@@ -10,6 +10,7 @@
 // 実際のHTTPリクエストのテストは、cargo runによる手動テストで確認済みです。
 
 #[test]
+#[allow(clippy::assertions_on_constants)]
 fn integration_tests_placeholder() {
     // プレースホルダーテスト
     // 将来的にE2Eテストを追加する場合はここに実装します
@@ -0,0 +1,3 @@
+-- Add created_at and deleted_at columns to users table
+ALTER TABLE users ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP;
+ALTER TABLE users ADD COLUMN deleted_at TIMESTAMPTZ;
@@ -0,0 +1,9 @@
+-- Add profile columns to users table
+ALTER TABLE users ADD COLUMN display_name TEXT NOT NULL DEFAULT '';
+ALTER TABLE users ADD COLUMN channel_handle TEXT;
+ALTER TABLE users ADD COLUMN time_zone TEXT NOT NULL DEFAULT 'Asia/Tokyo';
+ALTER TABLE users ADD COLUMN locale TEXT NOT NULL DEFAULT 'ja-JP';
+
+-- Channel handles are unique among active users (case-insensitive, like YouTube)
+CREATE UNIQUE INDEX users_channel_handle_key ON users (LOWER(channel_handle))
+  WHERE channel_handle IS NOT NULL AND deleted_at IS NULL;
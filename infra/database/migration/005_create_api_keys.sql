@@ -0,0 +1,13 @@
+-- Create api_keys table (only a salted SHA-256 hash of the secret is stored)
+CREATE TABLE api_keys (
+  key_id UUID PRIMARY KEY,
+  user_id UUID NOT NULL,
+  FOREIGN KEY (user_id) REFERENCES users(user_id),
+  name TEXT NOT NULL DEFAULT '',
+  salt BYTEA NOT NULL,
+  key_hash BYTEA NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX api_keys_user_id_idx ON api_keys (user_id);
@@ -0,0 +1,5 @@
+-- Add is_private column to streams table (private streams are visible only to the owner and admins)
+ALTER TABLE streams ADD COLUMN is_private BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Add is_admin column to users table
+ALTER TABLE users ADD COLUMN is_admin BOOLEAN NOT NULL DEFAULT FALSE;
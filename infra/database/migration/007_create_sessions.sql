@@ -0,0 +1,12 @@
+-- Create sessions table for cookie-based login (only a SHA-256 hash of the session token is stored)
+CREATE TABLE sessions (
+  session_id UUID PRIMARY KEY,
+  user_id UUID NOT NULL,
+  FOREIGN KEY (user_id) REFERENCES users(user_id),
+  token_hash BYTEA NOT NULL UNIQUE,
+  csrf_token TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX sessions_user_id_idx ON sessions (user_id);
@@ -0,0 +1,6 @@
+-- Replace users.is_admin with a role (owner: regular streamer, moderator: support staff, admin)
+CREATE TYPE user_role AS ENUM ('owner', 'moderator', 'admin');
+
+ALTER TABLE users ADD COLUMN role user_role NOT NULL DEFAULT 'owner';
+UPDATE users SET role = 'admin' WHERE is_admin;
+ALTER TABLE users DROP COLUMN is_admin;
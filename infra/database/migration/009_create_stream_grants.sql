@@ -0,0 +1,21 @@
+-- Create stream_grants table (a user delegates access to all of their streams to another user)
+-- Scopes are cumulative: write includes read, delete includes read and write
+CREATE TYPE grant_scope AS ENUM ('read', 'write', 'delete');
+
+CREATE TABLE stream_grants (
+  grant_id UUID PRIMARY KEY,
+  owner_id UUID NOT NULL,
+  FOREIGN KEY (owner_id) REFERENCES users(user_id),
+  grantee_id UUID NOT NULL,
+  FOREIGN KEY (grantee_id) REFERENCES users(user_id),
+  scope grant_scope NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  expires_at TIMESTAMPTZ,
+  revoked_at TIMESTAMPTZ,
+  CHECK (owner_id <> grantee_id)
+);
+
+-- At most one live grant per owner and grantee (granting again replaces it)
+CREATE UNIQUE INDEX stream_grants_owner_grantee_key ON stream_grants (owner_id, grantee_id)
+  WHERE revoked_at IS NULL;
+CREATE INDEX stream_grants_grantee_id_idx ON stream_grants (grantee_id);
@@ -0,0 +1,17 @@
+-- Create share_links table (read-only links to a single stream for people without an account)
+-- Only a SHA-256 hash of the token is stored
+CREATE TABLE share_links (
+  link_id UUID PRIMARY KEY,
+  stream_id UUID NOT NULL,
+  FOREIGN KEY (stream_id) REFERENCES streams(stream_id),
+  created_by UUID NOT NULL,
+  FOREIGN KEY (created_by) REFERENCES users(user_id),
+  token_hash BYTEA NOT NULL UNIQUE,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  expires_at TIMESTAMPTZ,
+  revoked_at TIMESTAMPTZ,
+  access_count BIGINT NOT NULL DEFAULT 0,
+  last_accessed_at TIMESTAMPTZ
+);
+
+CREATE INDEX share_links_stream_id_idx ON share_links (stream_id);
@@ -0,0 +1,7 @@
+-- Replace streams.is_private with a visibility state
+-- (draft: work in progress, private: owner and staff only, unlisted: reachable by URL but not listed, published)
+CREATE TYPE stream_visibility AS ENUM ('draft', 'private', 'unlisted', 'published');
+
+ALTER TABLE streams ADD COLUMN visibility stream_visibility NOT NULL DEFAULT 'published';
+UPDATE streams SET visibility = 'private' WHERE is_private;
+ALTER TABLE streams DROP COLUMN is_private;
@@ -0,0 +1,15 @@
+-- Create idempotency_keys table (responses of POST requests sent with an Idempotency-Key header)
+-- Keys are scoped per user; response_status is NULL while the first request is still being processed
+CREATE TABLE idempotency_keys (
+  user_id UUID NOT NULL,
+  FOREIGN KEY (user_id) REFERENCES users(user_id),
+  idempotency_key TEXT NOT NULL,
+  request_hash BYTEA NOT NULL,
+  response_status SMALLINT,
+  response_body BYTEA,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  expires_at TIMESTAMPTZ NOT NULL,
+  PRIMARY KEY (user_id, idempotency_key)
+);
+
+CREATE INDEX idempotency_keys_expires_at_idx ON idempotency_keys (expires_at);
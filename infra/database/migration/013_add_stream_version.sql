@@ -0,0 +1,2 @@
+-- Add version column to streams table (incremented on every update; exposed as the ETag for optimistic concurrency)
+ALTER TABLE streams ADD COLUMN version BIGINT NOT NULL DEFAULT 1;
@@ -0,0 +1,2 @@
+-- Index for listing and exporting a user's streams in created_at order (keyset pagination on created_at, stream_id)
+CREATE INDEX streams_user_id_created_at_idx ON streams (user_id, created_at, stream_id);
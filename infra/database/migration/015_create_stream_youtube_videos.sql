@@ -0,0 +1,12 @@
+-- Create stream_youtube_videos table (YouTube video a stream was imported from, e.g. via a Google Takeout export)
+-- The same video may be imported again after the stream is deleted, so (user_id, video_id) is not unique
+CREATE TABLE stream_youtube_videos (
+  stream_id UUID PRIMARY KEY,
+  FOREIGN KEY (stream_id) REFERENCES streams(stream_id),
+  user_id UUID NOT NULL,
+  FOREIGN KEY (user_id) REFERENCES users(user_id),
+  video_id TEXT NOT NULL,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX stream_youtube_videos_user_id_video_id_idx ON stream_youtube_videos (user_id, video_id);
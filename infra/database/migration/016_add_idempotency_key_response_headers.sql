@@ -0,0 +1,3 @@
+-- Add response_headers column to idempotency_keys table (headers such as Location and ETag restored when the response is replayed)
+-- Stored as a JSON array of [name, value] pairs; NULL for keys completed before this column existed
+ALTER TABLE idempotency_keys ADD COLUMN response_headers JSONB;